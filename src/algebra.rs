@@ -0,0 +1,566 @@
+// Support for the algebra! macro, which generates a Clifford algebra Cl(p, q, r)
+// with p basis vectors squaring to 1, then q squaring to -1 and r squaring to 0.
+// Blades are indexed by bitmask, bit i set for e(i + 1).
+
+// The squares of the basis vectors of Cl(p, q, r), in order
+pub const fn metric<const N: usize>(p: usize, q: usize) -> [i8; N] {
+    let mut metric = [0; N];
+    let mut i = 0;
+    while i < N {
+        metric[i] = if i < p {
+            1
+        } else if i < p + q {
+            -1
+        } else {
+            0
+        };
+        i += 1;
+    }
+    metric
+}
+
+// The sign from reordering the product of two basis blades into a single blade
+pub const fn reorder_sign(a: usize, b: usize) -> i8 {
+    let mut swaps = 0;
+    let mut shifted = a >> 1;
+    while shifted != 0 {
+        swaps += (shifted & b).count_ones();
+        shifted >>= 1;
+    }
+
+    if swaps % 2 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+// The sign of the product of two basis blades, zero when a degenerate vector squares
+pub const fn product_sign(a: usize, b: usize, metric: &[i8]) -> i8 {
+    let mut sign = reorder_sign(a, b);
+    let mut i = 0;
+    while i < metric.len() {
+        if (a & b) & (1 << i) != 0 {
+            sign *= metric[i];
+        }
+        i += 1;
+    }
+    sign
+}
+
+pub const fn sign_table<const N: usize>(metric: &[i8]) -> [[i8; N]; N] {
+    let mut table = [[0; N]; N];
+    let mut a = 0;
+    while a < N {
+        let mut b = 0;
+        while b < N {
+            table[a][b] = product_sign(a, b, metric);
+            b += 1;
+        }
+        a += 1;
+    }
+    table
+}
+
+pub const fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    let mut result = 1;
+    let mut i = 0;
+    while i < k {
+        result = result * (n - i) / (i + 1);
+        i += 1;
+    }
+    result
+}
+
+// The bitmasks of the grade k blades in an algebra of dim basis vectors, in increasing order
+pub const fn grade_blades<const N: usize>(dim: usize, k: usize) -> [usize; N] {
+    let mut blades = [0; N];
+    let mut found = 0;
+    let mut blade: usize = 0;
+    while blade < (1 << dim) && found < N {
+        if blade.count_ones() as usize == k {
+            blades[found] = blade;
+            found += 1;
+        }
+        blade += 1;
+    }
+    blades
+}
+
+// Generates a module with a full multivector and grade specific structs for
+// Cl(p, q, r), such as algebra!(pub Sta, 1, 3, 0). Grade<T, K> holds the grade
+// K part for any K up to DIM.
+#[macro_export]
+macro_rules! algebra {
+    ($vis:vis $name:ident, $p:expr, $q:expr, $r:expr) => {
+        #[allow(non_snake_case, dead_code)]
+        $vis mod $name {
+            use $crate::Scalar;
+            use std::ops::*;
+
+            pub const P: usize = $p;
+            pub const Q: usize = $q;
+            pub const R: usize = $r;
+            pub const DIM: usize = P + Q + R;
+            pub const LEN: usize = 1 << DIM;
+            pub const METRIC: [i8; DIM] = $crate::algebra::metric::<DIM>(P, Q);
+
+            // Large algebras take a while to tabulate, which is not an infinite loop
+            #[allow(long_running_const_eval)]
+            const SIGNS: [[i8; LEN]; LEN] = $crate::algebra::sign_table::<LEN>(&METRIC);
+
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #[repr(C)]
+            pub struct Multivector<T> {
+                pub c: [T; LEN],
+            }
+
+            impl<T: Scalar> Multivector<T> {
+                #[inline]
+                pub fn new(c: [T; LEN]) -> Self {
+                    Self { c }
+                }
+
+                #[inline]
+                pub fn zero() -> Self {
+                    Self::new([T::zero(); LEN])
+                }
+
+                #[inline]
+                pub fn scalar(s: T) -> Self {
+                    Self::blade(0) * s
+                }
+
+                // The basis vector e(i + 1)
+                #[inline]
+                pub fn basis(i: usize) -> Self {
+                    Self::blade(1 << i)
+                }
+
+                #[inline]
+                pub fn blade(blade: usize) -> Self {
+                    let mut m = Self::zero();
+                    m.c[blade] = T::one();
+                    m
+                }
+
+                #[inline]
+                pub fn pseudoscalar() -> Self {
+                    Self::blade(LEN - 1)
+                }
+
+                #[inline]
+                pub fn grade(&self, k: usize) -> Self {
+                    let mut m = Self::zero();
+                    for (blade, c) in m.c.iter_mut().enumerate() {
+                        if blade.count_ones() as usize == k {
+                            *c = self.c[blade];
+                        }
+                    }
+                    m
+                }
+
+                #[inline]
+                pub fn reverse(&mut self) {
+                    for (blade, c) in self.c.iter_mut().enumerate() {
+                        if blade.count_ones() % 4 >= 2 {
+                            *c = -*c;
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn reversed(&self) -> Self {
+                    let mut m = *self;
+                    m.reverse();
+                    m
+                }
+
+                #[inline]
+                pub fn involute(&mut self) {
+                    for (blade, c) in self.c.iter_mut().enumerate() {
+                        if blade.count_ones() % 2 == 1 {
+                            *c = -*c;
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn involuted(&self) -> Self {
+                    let mut m = *self;
+                    m.involute();
+                    m
+                }
+
+                #[inline]
+                pub fn conjugate(&mut self) {
+                    self.reverse();
+                    self.involute();
+                }
+
+                #[inline]
+                pub fn conjugated(&self) -> Self {
+                    let mut m = *self;
+                    m.conjugate();
+                    m
+                }
+
+                // The complement of each blade, so that blade * blade.dual() is the
+                // pseudoscalar when the metric is ignored. Works in degenerate algebras.
+                #[inline]
+                pub fn dual(&self) -> Self {
+                    let mut m = Self::zero();
+                    for (blade, &c) in self.c.iter().enumerate() {
+                        let complement = (LEN - 1) ^ blade;
+                        let sign = $crate::algebra::reorder_sign(blade, complement);
+                        m.c[complement] = if sign > 0 { c } else { -c };
+                    }
+                    m
+                }
+
+                #[inline]
+                pub fn undual(&self) -> Self {
+                    let mut m = Self::zero();
+                    for (complement, &c) in self.c.iter().enumerate() {
+                        let blade = (LEN - 1) ^ complement;
+                        let sign = $crate::algebra::reorder_sign(blade, complement);
+                        m.c[blade] = if sign > 0 { c } else { -c };
+                    }
+                    m
+                }
+
+                fn product(&self, other: Self, keep: impl Fn(usize, usize) -> bool) -> Self {
+                    let mut m = Self::zero();
+                    for (a, &ca) in self.c.iter().enumerate() {
+                        for (b, &cb) in other.c.iter().enumerate() {
+                            if !keep(a, b) {
+                                continue;
+                            }
+
+                            match SIGNS[a][b] {
+                                1 => m.c[a ^ b] += ca * cb,
+                                -1 => m.c[a ^ b] -= ca * cb,
+                                _ => {}
+                            }
+                        }
+                    }
+                    m
+                }
+
+                #[inline]
+                pub fn wedge(&self, other: Self) -> Self {
+                    self.product(other, |a, b| a & b == 0)
+                }
+
+                // The left contraction, self ⌋ other
+                #[inline]
+                pub fn inner(&self, other: Self) -> Self {
+                    self.product(other, |a, b| a & b == a)
+                }
+
+                #[inline]
+                pub fn regressive(&self, other: Self) -> Self {
+                    self.dual().wedge(other.dual()).undual()
+                }
+
+                #[inline]
+                pub fn scalar_product(&self, other: Self) -> T {
+                    self.product(other, |a, b| a == b).c[0]
+                }
+
+                #[inline]
+                pub fn mag_sq(&self) -> T {
+                    self.scalar_product(self.reversed())
+                }
+
+                #[inline]
+                pub fn mag(&self) -> T {
+                    self.mag_sq().abs().sqrt()
+                }
+
+                // The inverse of a versor or blade
+                #[inline]
+                pub fn inverse(&self) -> Self {
+                    let reversed = self.reversed();
+                    reversed / self.scalar_product(reversed)
+                }
+
+                // Applies this versor to x, self * x * self.inverse()
+                #[inline]
+                pub fn transform(&self, x: Self) -> Self {
+                    *self * x * self.inverse()
+                }
+            }
+
+            impl<T: Scalar> Default for Multivector<T> {
+                #[inline]
+                fn default() -> Self {
+                    Self::zero()
+                }
+            }
+
+            impl<T: Scalar> Add for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn add(mut self, rhs: Self) -> Self {
+                    self += rhs;
+                    self
+                }
+            }
+
+            impl<T: Scalar> AddAssign for Multivector<T> {
+                #[inline]
+                fn add_assign(&mut self, rhs: Self) {
+                    for (c, r) in self.c.iter_mut().zip(rhs.c.iter()) {
+                        *c += *r;
+                    }
+                }
+            }
+
+            impl<T: Scalar> Sub for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(mut self, rhs: Self) -> Self {
+                    self -= rhs;
+                    self
+                }
+            }
+
+            impl<T: Scalar> SubAssign for Multivector<T> {
+                #[inline]
+                fn sub_assign(&mut self, rhs: Self) {
+                    for (c, r) in self.c.iter_mut().zip(rhs.c.iter()) {
+                        *c -= *r;
+                    }
+                }
+            }
+
+            impl<T: Scalar> Neg for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    self * -T::one()
+                }
+            }
+
+            impl<T: Scalar> Mul for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Self {
+                    self.product(rhs, |_, _| true)
+                }
+            }
+
+            impl<T: Scalar> MulAssign for Multivector<T> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: Self) {
+                    *self = *self * rhs;
+                }
+            }
+
+            impl<T: Scalar> Mul<T> for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(mut self, rhs: T) -> Self {
+                    self *= rhs;
+                    self
+                }
+            }
+
+            impl<T: Scalar> MulAssign<T> for Multivector<T> {
+                #[inline]
+                fn mul_assign(&mut self, rhs: T) {
+                    for c in self.c.iter_mut() {
+                        *c *= rhs;
+                    }
+                }
+            }
+
+            impl<T: Scalar> Div<T> for Multivector<T> {
+                type Output = Self;
+
+                #[inline]
+                fn div(mut self, rhs: T) -> Self {
+                    self /= rhs;
+                    self
+                }
+            }
+
+            impl<T: Scalar> DivAssign<T> for Multivector<T> {
+                #[inline]
+                fn div_assign(&mut self, rhs: T) {
+                    for c in self.c.iter_mut() {
+                        *c /= rhs;
+                    }
+                }
+            }
+
+            // The most blades any single grade has, the size of Grade storage
+            pub const GRADE_LEN: usize = $crate::algebra::binomial(DIM, DIM / 2);
+
+            // The grade K part of a multivector. Every grade is stored in
+            // GRADE_LEN components, only the first Self::LEN are used and the
+            // rest stay zero. K above DIM fails to compile.
+            #[derive(Clone, Copy, Debug, PartialEq)]
+            #[repr(C)]
+            pub struct Grade<T, const K: usize> {
+                c: [T; GRADE_LEN],
+            }
+
+            pub type Vector<T> = Grade<T, 1>;
+            pub type Bivector<T> = Grade<T, 2>;
+            pub type Trivector<T> = Grade<T, 3>;
+            pub type Pseudoscalar<T> = Grade<T, DIM>;
+
+            impl<T: Scalar, const K: usize> Grade<T, K> {
+                // The number of blades of grade K
+                pub const LEN: usize = {
+                    assert!(K <= DIM, "grade is above the dimension of the algebra");
+                    $crate::algebra::binomial(DIM, K)
+                };
+
+                // The bitmask of the blade for each component, the first Self::LEN are used
+                pub const BLADES: [usize; GRADE_LEN] =
+                    $crate::algebra::grade_blades::<GRADE_LEN>(DIM, K);
+
+                #[inline]
+                pub fn new<const N: usize>(c: [T; N]) -> Self {
+                    const { assert!(N == $crate::algebra::binomial(DIM, K), "wrong number of components for the grade") };
+                    let mut g = Self::zero();
+                    g.c[..N].copy_from_slice(&c);
+                    g
+                }
+
+                #[inline]
+                pub fn zero() -> Self {
+                    // Evaluating LEN rejects K above DIM at compile time
+                    let _ = Self::LEN;
+                    Self { c: [T::zero(); GRADE_LEN] }
+                }
+
+                #[inline]
+                pub fn components(&self) -> &[T] {
+                    &self.c[..Self::LEN]
+                }
+
+                #[inline]
+                pub fn components_mut(&mut self) -> &mut [T] {
+                    &mut self.c[..Self::LEN]
+                }
+
+                // The grade K part of m
+                #[inline]
+                pub fn from_multivector(m: Multivector<T>) -> Self {
+                    let mut g = Self::zero();
+                    for (c, &blade) in g.components_mut().iter_mut().zip(Self::BLADES.iter()) {
+                        *c = m.c[blade];
+                    }
+                    g
+                }
+
+                #[inline]
+                pub fn wedge(&self, other: Self) -> Multivector<T> {
+                    Multivector::from(*self).wedge(Multivector::from(other))
+                }
+
+                #[inline]
+                pub fn inner(&self, other: Self) -> Multivector<T> {
+                    Multivector::from(*self).inner(Multivector::from(other))
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Default for Grade<T, K> {
+                #[inline]
+                fn default() -> Self {
+                    Self::zero()
+                }
+            }
+
+            impl<T: Scalar, const K: usize> From<Grade<T, K>> for Multivector<T> {
+                #[inline]
+                fn from(g: Grade<T, K>) -> Self {
+                    let mut m = Self::zero();
+                    for (&c, &blade) in g.components().iter().zip(Grade::<T, K>::BLADES.iter()) {
+                        m.c[blade] = c;
+                    }
+                    m
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Add for Grade<T, K> {
+                type Output = Self;
+
+                #[inline]
+                fn add(mut self, rhs: Self) -> Self {
+                    for (c, r) in self.components_mut().iter_mut().zip(rhs.components()) {
+                        *c += *r;
+                    }
+                    self
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Sub for Grade<T, K> {
+                type Output = Self;
+
+                #[inline]
+                fn sub(mut self, rhs: Self) -> Self {
+                    for (c, r) in self.components_mut().iter_mut().zip(rhs.components()) {
+                        *c -= *r;
+                    }
+                    self
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Neg for Grade<T, K> {
+                type Output = Self;
+
+                #[inline]
+                fn neg(self) -> Self {
+                    self * -T::one()
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Mul for Grade<T, K> {
+                type Output = Multivector<T>;
+
+                #[inline]
+                fn mul(self, rhs: Self) -> Multivector<T> {
+                    Multivector::from(self) * Multivector::from(rhs)
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Mul<T> for Grade<T, K> {
+                type Output = Self;
+
+                #[inline]
+                fn mul(mut self, rhs: T) -> Self {
+                    for c in self.components_mut().iter_mut() {
+                        *c *= rhs;
+                    }
+                    self
+                }
+            }
+
+            impl<T: Scalar, const K: usize> Div<T> for Grade<T, K> {
+                type Output = Self;
+
+                #[inline]
+                fn div(mut self, rhs: T) -> Self {
+                    for c in self.components_mut().iter_mut() {
+                        *c /= rhs;
+                    }
+                    self
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,416 @@
+use crate::cga3d;
+use crate::pga2d::*;
+use crate::pga3d::*;
+use crate::sta::*;
+use crate::*;
+use std::ops::Neg;
+
+// Float comparison with absolute, relative and ULP tolerances. The methods are
+// prefixed so they don't clash with the traits of the approx crate.
+pub trait ApproxEq {
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+
+    fn default_max_relative() -> Self::Epsilon;
+
+    fn default_max_ulps() -> u32;
+
+    fn approx_eq_abs(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+
+    // Equal within epsilon, or within max_relative of the larger magnitude
+    fn approx_eq_rel(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool;
+
+    // Equal within epsilon, or at most max_ulps representable floats apart
+    fn approx_eq_ulps(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+
+    #[inline]
+    fn approx_eq(&self, other: &Self) -> bool {
+        self.approx_eq_rel(other, Self::default_epsilon(), Self::default_max_relative())
+    }
+}
+
+macro_rules! impl_approx_eq_float {
+    [$($t:ident: $bits:ident), +] => {
+        $(
+            impl ApproxEq for $t {
+                type Epsilon = $t;
+
+                #[inline]
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                #[inline]
+                fn approx_eq_abs(&self, other: &Self, epsilon: $t) -> bool {
+                    (self - other).abs() <= epsilon
+                }
+
+                #[inline]
+                fn approx_eq_rel(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    if self == other {
+                        return true;
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false;
+                    }
+
+                    let diff = (self - other).abs();
+                    if diff <= epsilon {
+                        return true;
+                    }
+
+                    diff <= self.abs().max(other.abs()) * max_relative
+                }
+
+                #[inline]
+                fn approx_eq_ulps(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    if self.approx_eq_abs(other, epsilon) {
+                        return true;
+                    }
+                    // NaN has no neighbours, whatever its bits are
+                    if self.is_nan() || other.is_nan() {
+                        return false;
+                    }
+                    if self.is_sign_positive() != other.is_sign_positive() {
+                        return false;
+                    }
+
+                    let diff = (self.to_bits() as $bits).wrapping_sub(other.to_bits() as $bits);
+                    diff.unsigned_abs() <= max_ulps.into()
+                }
+            }
+        )+
+    };
+}
+
+impl_approx_eq_float![f32: i32, f64: i64];
+
+impl<A: ApproxEq, const N: usize> ApproxEq for [A; N] {
+    type Epsilon = A::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> A::Epsilon {
+        A::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> A::Epsilon {
+        A::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        A::default_max_ulps()
+    }
+
+    #[inline]
+    fn approx_eq_abs(&self, other: &Self, epsilon: A::Epsilon) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| a.approx_eq_abs(b, epsilon))
+    }
+
+    #[inline]
+    fn approx_eq_rel(&self, other: &Self, epsilon: A::Epsilon, max_relative: A::Epsilon) -> bool {
+        let mut pairs = self.iter().zip(other.iter());
+        pairs.all(|(a, b)| a.approx_eq_rel(b, epsilon, max_relative))
+    }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Self, epsilon: A::Epsilon, max_ulps: u32) -> bool {
+        let mut pairs = self.iter().zip(other.iter());
+        pairs.all(|(a, b)| a.approx_eq_ulps(b, epsilon, max_ulps))
+    }
+}
+
+// Compares rotors as the rotations they perform, so r and -r are equal
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AsRotation<R>(pub R);
+
+impl<R: ApproxEq + Neg<Output = R> + Copy> ApproxEq for AsRotation<R> {
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> R::Epsilon {
+        R::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> R::Epsilon {
+        R::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+
+    #[inline]
+    fn approx_eq_abs(&self, other: &Self, epsilon: R::Epsilon) -> bool {
+        self.0.approx_eq_abs(&other.0, epsilon) || self.0.approx_eq_abs(&-other.0, epsilon)
+    }
+
+    #[inline]
+    fn approx_eq_rel(&self, other: &Self, epsilon: R::Epsilon, max_relative: R::Epsilon) -> bool {
+        self.0.approx_eq_rel(&other.0, epsilon, max_relative)
+            || self.0.approx_eq_rel(&-other.0, epsilon, max_relative)
+    }
+
+    #[inline]
+    fn approx_eq_ulps(&self, other: &Self, epsilon: R::Epsilon, max_ulps: u32) -> bool {
+        self.0.approx_eq_ulps(&other.0, epsilon, max_ulps)
+            || self.0.approx_eq_ulps(&-other.0, epsilon, max_ulps)
+    }
+}
+
+macro_rules! impl_approx_eq {
+    [$($ty:ident)::+ $(<$n:ident>)?: $($f:ident), +] => {
+        impl<T: ApproxEq<Epsilon = T> + Copy $(, const $n: usize)?> ApproxEq for $($ty)::+<T $(, $n)?> {
+            type Epsilon = T;
+
+            #[inline]
+            fn default_epsilon() -> T {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn default_max_relative() -> T {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn approx_eq_abs(&self, other: &Self, epsilon: T) -> bool {
+                $(self.$f.approx_eq_abs(&other.$f, epsilon))&&+
+            }
+
+            #[inline]
+            fn approx_eq_rel(&self, other: &Self, epsilon: T, max_relative: T) -> bool {
+                $(self.$f.approx_eq_rel(&other.$f, epsilon, max_relative))&&+
+            }
+
+            #[inline]
+            fn approx_eq_ulps(&self, other: &Self, epsilon: T, max_ulps: u32) -> bool {
+                $(self.$f.approx_eq_ulps(&other.$f, epsilon, max_ulps))&&+
+            }
+        }
+    };
+}
+
+impl_approx_eq![Vector2: x, y];
+impl_approx_eq![Vector3: x, y, z];
+impl_approx_eq![Vector4: x, y, z, w];
+impl_approx_eq![Bivector2: xy];
+impl_approx_eq![Bivector3: xy, xz, yz];
+impl_approx_eq![Bivector4: xy, xz, xw, yz, yw, zw];
+impl_approx_eq![Trivector3: xyz];
+impl_approx_eq![Rotor2: s, bv];
+impl_approx_eq![Rotor3: s, bv];
+impl_approx_eq![Rotor4: s, bv, xyzw];
+impl_approx_eq![Multivector3: s, v, bv, tv];
+impl_approx_eq![Matrix2: cols];
+impl_approx_eq![Matrix3: cols];
+impl_approx_eq![Matrix4: cols];
+impl_approx_eq![Transformation2: translation, rotation, scale];
+impl_approx_eq![Transformation3: translation, rotation, scale];
+impl_approx_eq![Point2: e20, e01, e12];
+impl_approx_eq![Line2: e1, e2, e0];
+impl_approx_eq![Motor2: s, e12, e20, e01];
+impl_approx_eq![Plane3: e1, e2, e3, e0];
+impl_approx_eq![Line3: e01, e02, e03, e12, e31, e23];
+impl_approx_eq![Point3: e032, e013, e021, e123];
+impl_approx_eq![Motor3: s, e23, e31, e12, e01, e02, e03, e0123];
+impl_approx_eq![cga3d::Multivector: c];
+impl_approx_eq![FourVector: t, x, y, z];
+impl_approx_eq![SpacetimeBivector: e, b];
+impl_approx_eq![LorentzRotor: s, bv, ps];
+impl_approx_eq![WideVector3<N>: x, y, z];
+impl_approx_eq![WideBivector3<N>: xy, xz, yz];
+impl_approx_eq![WideRotor3<N>: s, bv];
+
+// The aligned types compare like the scalar types they convert to
+#[cfg(feature = "simd")]
+macro_rules! impl_approx_eq_simd {
+    [$($ty:ident: $scalar:ident), +] => {
+        $(
+            impl ApproxEq for $ty {
+                type Epsilon = f32;
+
+                #[inline]
+                fn default_epsilon() -> f32 {
+                    f32::default_epsilon()
+                }
+
+                #[inline]
+                fn default_max_relative() -> f32 {
+                    f32::default_max_relative()
+                }
+
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    f32::default_max_ulps()
+                }
+
+                #[inline]
+                fn approx_eq_abs(&self, other: &Self, epsilon: f32) -> bool {
+                    $scalar::from(*self).approx_eq_abs(&(*other).into(), epsilon)
+                }
+
+                #[inline]
+                fn approx_eq_rel(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+                    $scalar::from(*self).approx_eq_rel(&(*other).into(), epsilon, max_relative)
+                }
+
+                #[inline]
+                fn approx_eq_ulps(&self, other: &Self, epsilon: f32, max_ulps: u32) -> bool {
+                    $scalar::from(*self).approx_eq_ulps(&(*other).into(), epsilon, max_ulps)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "simd")]
+impl_approx_eq_simd![Vec3A: Vec3, Vec4A: Vec4, Rot3A: Rot3];
+
+#[doc(hidden)]
+#[inline]
+pub fn __default_epsilon<A: ApproxEq>(_: &A) -> A::Epsilon {
+    A::default_epsilon()
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_approx {
+    ($eq:literal, $op:literal, $left:expr, $right:expr, $l:ident, $r:ident, $cond:expr) => {
+        match (&$left, &$right) {
+            ($l, $r) => {
+                if $cond != $eq {
+                    panic!(
+                        "assertion failed: `(left {} right)`\n  left: `{:?}`,\n right: `{:?}`",
+                        $op, $l, $r
+                    );
+                }
+            }
+        }
+    };
+}
+
+// Panics unless both sides are approximately equal, by default with the
+// tolerances of ApproxEq::approx_eq. A tolerance can be given explicitly as
+// `epsilon = e`, `max_relative = r` or `max_ulps = u`.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx!(
+            true,
+            "≈",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq(l, r)
+        )
+    };
+    ($left:expr, $right:expr, epsilon = $eps:expr $(,)?) => {
+        $crate::__assert_approx!(
+            true,
+            "≈",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_abs(l, r, $eps)
+        )
+    };
+    ($left:expr, $right:expr, max_relative = $rel:expr $(,)?) => {
+        $crate::__assert_approx!(
+            true,
+            "≈",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_rel(l, r, $crate::__default_epsilon(l), $rel)
+        )
+    };
+    ($left:expr, $right:expr, max_ulps = $ulps:expr $(,)?) => {
+        $crate::__assert_approx!(
+            true,
+            "≈",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_ulps(l, r, $crate::__default_epsilon(l), $ulps)
+        )
+    };
+}
+
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::__assert_approx!(
+            false,
+            "≉",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq(l, r)
+        )
+    };
+    ($left:expr, $right:expr, epsilon = $eps:expr $(,)?) => {
+        $crate::__assert_approx!(
+            false,
+            "≉",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_abs(l, r, $eps)
+        )
+    };
+    ($left:expr, $right:expr, max_relative = $rel:expr $(,)?) => {
+        $crate::__assert_approx!(
+            false,
+            "≉",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_rel(l, r, $crate::__default_epsilon(l), $rel)
+        )
+    };
+    ($left:expr, $right:expr, max_ulps = $ulps:expr $(,)?) => {
+        $crate::__assert_approx!(
+            false,
+            "≉",
+            $left,
+            $right,
+            l,
+            r,
+            $crate::ApproxEq::approx_eq_ulps(l, r, $crate::__default_epsilon(l), $ulps)
+        )
+    };
+}
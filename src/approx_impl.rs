@@ -0,0 +1,134 @@
+use crate::cga3d;
+use crate::pga2d::*;
+use crate::pga3d::*;
+use crate::sta::*;
+use crate::*;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use std::ops::Neg;
+
+// Each impl defers to ApproxEq, so both APIs agree on every type
+macro_rules! impl_approx {
+    [$($ty:ident)::+ $(<$n:ident>)?] => {
+        impl_approx!(
+            @impl [T: ApproxEq<Epsilon = T> + Copy + PartialEq $(, const $n: usize)?]
+            $($ty)::+<T $(, $n)?>, T
+        );
+    };
+    [$ty:ident: $t:ident] => {
+        impl_approx!(@impl [] $ty, $t);
+    };
+    (@impl [$($generics:tt)*] $ty:ty, $t:ty) => {
+        impl<$($generics)*> AbsDiffEq for $ty {
+            type Epsilon = $t;
+
+            #[inline]
+            fn default_epsilon() -> $t {
+                <Self as ApproxEq>::default_epsilon()
+            }
+
+            #[inline]
+            fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                self.approx_eq_abs(other, epsilon)
+            }
+        }
+
+        impl<$($generics)*> RelativeEq for $ty {
+            #[inline]
+            fn default_max_relative() -> $t {
+                <Self as ApproxEq>::default_max_relative()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                self.approx_eq_rel(other, epsilon, max_relative)
+            }
+        }
+
+        impl<$($generics)*> UlpsEq for $ty {
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                <Self as ApproxEq>::default_max_ulps()
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                self.approx_eq_ulps(other, epsilon, max_ulps)
+            }
+        }
+    };
+}
+
+impl_approx![Vector2];
+impl_approx![Vector3];
+impl_approx![Vector4];
+impl_approx![Bivector2];
+impl_approx![Bivector3];
+impl_approx![Bivector4];
+impl_approx![Trivector3];
+impl_approx![Rotor2];
+impl_approx![Rotor3];
+impl_approx![Rotor4];
+impl_approx![Multivector3];
+impl_approx![Matrix2];
+impl_approx![Matrix3];
+impl_approx![Matrix4];
+impl_approx![Transformation2];
+impl_approx![Transformation3];
+impl_approx![Point2];
+impl_approx![Line2];
+impl_approx![Motor2];
+impl_approx![Plane3];
+impl_approx![Line3];
+impl_approx![Point3];
+impl_approx![Motor3];
+impl_approx![cga3d::Multivector];
+impl_approx![FourVector];
+impl_approx![SpacetimeBivector];
+impl_approx![LorentzRotor];
+impl_approx![WideVector3<N>];
+impl_approx![WideBivector3<N>];
+impl_approx![WideRotor3<N>];
+#[cfg(feature = "simd")]
+impl_approx![Vec3A: f32];
+#[cfg(feature = "simd")]
+impl_approx![Vec4A: f32];
+#[cfg(feature = "simd")]
+impl_approx![Rot3A: f32];
+
+impl<R: ApproxEq + Neg<Output = R> + Copy + PartialEq> AbsDiffEq for AsRotation<R> {
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> R::Epsilon {
+        <Self as ApproxEq>::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: R::Epsilon) -> bool {
+        self.approx_eq_abs(other, epsilon)
+    }
+}
+
+impl<R: ApproxEq + Neg<Output = R> + Copy + PartialEq> RelativeEq for AsRotation<R> {
+    #[inline]
+    fn default_max_relative() -> R::Epsilon {
+        <Self as ApproxEq>::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Self, epsilon: R::Epsilon, max_relative: R::Epsilon) -> bool {
+        self.approx_eq_rel(other, epsilon, max_relative)
+    }
+}
+
+impl<R: ApproxEq + Neg<Output = R> + Copy + PartialEq> UlpsEq for AsRotation<R> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        <Self as ApproxEq>::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: R::Epsilon, max_ulps: u32) -> bool {
+        self.approx_eq_ulps(other, epsilon, max_ulps)
+    }
+}
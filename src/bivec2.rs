@@ -1,189 +1,219 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_bivec2 {
-    [$(($t:ident, $nam:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub xy: $t
-            }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Bivector2<T> {
+    pub xy: T,
+}
 
-            impl $nam {
-                pub fn new(xy: $t) -> Self {
-                    Self {xy}
-                }
+pub type Bivec2 = Bivector2<f32>;
+pub type DBivec2 = Bivector2<f64>;
 
-                #[inline]
-                pub fn zero() -> Self {
-                    Self::new(0.0)
-                }
+impl<T: Scalar> Bivector2<T> {
+    pub fn new(xy: T) -> Self {
+        Self { xy }
+    }
 
-                #[inline]
-                pub fn one() -> Self {
-                    Self::new(1.0)
-                }
-            }
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero())
+    }
 
-            impl Bivector for Bivec2 {
-                type Decimal = $t;
-
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.xy.abs()
-                }
+    #[inline]
+    pub fn one() -> Self {
+        Self::new(T::one())
+    }
+}
 
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    self.xy * self.xy
-                }
+impl<T: Scalar> Bivector for Bivector2<T> {
+    type Decimal = T;
+    type Rotor = Rotor2<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.xy.abs()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        self.xy * self.xy
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.xy * other.xy
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn exp(&self) -> Self::Rotor {
+        let (sin, cos) = self.xy.sin_cos();
+        Rotor2::new(cos, Self::new(sin))
+    }
+}
 
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    self.xy * other.xy
-                }
+impl<T: Scalar> Add for Bivector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    *self /= mag;
-                }
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.xy + rhs.xy)
+    }
+}
 
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut v = self.clone();
-                    v.normalize();
-                    v
-                }
+impl<T: Scalar> AddAssign for Bivector2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.xy += rhs.xy;
+    }
+}
 
-            }
+impl<T: Scalar> Sub for Bivector2<T> {
+    type Output = Self;
 
-            impl Add for $nam {
-                type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.xy - rhs.xy)
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy + rhs.xy)
-                }
-            }
+impl<T: Scalar> SubAssign for Bivector2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.xy -= rhs.xy;
+    }
+}
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: $nam) {
-                    self.xy += rhs.xy;
-                }
-            }
+impl<T: Scalar> Mul for Bivector2<T> {
+    type Output = Self;
 
-            impl Sub for $nam {
-                type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.xy * rhs.xy)
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy - rhs.xy)
-                }
-            }
+impl<T: Scalar> Mul<T> for Bivector2<T> {
+    type Output = Self;
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: $nam) {
-                    self.xy -= rhs.xy;
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.xy * rhs)
+    }
+}
 
-            impl Mul for $nam {
-                type Output = Self;
+impl<T: Scalar> MulAssign for Bivector2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.xy *= rhs.xy;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy * rhs.xy)
-                }
-            }
+impl<T: Scalar> MulAssign<T> for Bivector2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.xy *= rhs;
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> Div for Bivector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> $nam {
-                    $nam::new(self * rhs.xy)
-                }
-            }
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.xy / rhs.xy)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = Self;
+impl<T: Scalar> Div<T> for Bivector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self {
-                    $nam::new(self.xy * rhs)
-                }
-            }
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.xy / rhs)
+    }
+}
 
-            impl MulAssign for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: Self) {
-                    self.xy *= rhs.xy;
-                }
-            }
+impl<T: Scalar> DivAssign for Bivector2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.xy /= rhs.xy;
+    }
+}
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.xy *= rhs;
-                }
-            }
+impl<T: Scalar> DivAssign<T> for Bivector2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.xy /= rhs;
+    }
+}
 
-            impl Div for $nam {
-                type Output = Self;
+impl<T: Scalar> Neg for Bivector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn div(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy / rhs.xy)
-                }
-            }
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.xy = -self.xy;
+        self
+    }
+}
 
-            impl Div<$nam> for $t {
-                type Output = $nam;
+macro_rules! impl_scalar_bivec2 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Bivector2<$t>> for $t {
+                type Output = Bivector2<$t>;
 
                 #[inline]
-                fn div(self, rhs: $nam) -> $nam {
-                    $nam::new(self / rhs.xy)
+                fn mul(self, rhs: Bivector2<$t>) -> Bivector2<$t> {
+                    Bivector2::new(self * rhs.xy)
                 }
             }
 
-            impl Div<$t> for $nam {
-                type Output = $nam;
+            impl Div<Bivector2<$t>> for $t {
+                type Output = Bivector2<$t>;
 
                 #[inline]
-                fn div(self, rhs: $t) -> $nam {
-                    $nam::new(self.xy / rhs)
+                fn div(self, rhs: Bivector2<$t>) -> Bivector2<$t> {
+                    Bivector2::new(self / rhs.xy)
                 }
             }
+        )+
+    };
+}
 
-            impl DivAssign for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $nam) {
-                    self.xy /= rhs.xy;
-                }
-            }
+impl_scalar_bivec2![f32, f64];
 
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.xy /= rhs;
-                }
-            }
-
-            impl Neg for $nam {
-                type Output = Self;
+impl From<Bivec2> for DBivec2 {
+    #[inline]
+    fn from(bv: Bivec2) -> Self {
+        Self::new(f64::from(bv.xy))
+    }
+}
 
-                #[inline]
-                fn neg(mut self) -> Self {
-                    self.xy = -self.xy;
-                    self
-                }
-            }
-        )+
-    };
+impl Bivec2 {
+    #[inline]
+    pub fn as_dbivec2(&self) -> DBivec2 {
+        DBivec2::from(*self)
+    }
 }
 
-impl_bivec2![(f32, Bivec2)];
+impl DBivec2 {
+    #[inline]
+    pub fn as_bivec2(&self) -> Bivec2 {
+        Bivec2::new(self.xy as f32)
+    }
+}
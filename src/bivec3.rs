@@ -1,205 +1,252 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_bivec3 {
-    [$(($t:ident, $nam:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub xy: $t,
-                pub xz: $t,
-                pub yz: $t,
-            }
-
-            impl $nam {
-                pub fn new(xy: $t, xz: $t, yz: $t) -> Self {
-                    Self {xy, xz, yz}
-                }
-
-                #[inline]
-                pub fn zero() -> Self {
-                    Self::new(0.0, 0.0, 0.0)
-                }
-
-                #[inline]
-                pub fn one() -> Self {
-                    Self::new(1.0, 1.0, 1.0)
-                }
-            }
-
-            impl Bivector for Bivec3 {
-                type Decimal = $t;
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Bivector3<T> {
+    pub xy: T,
+    pub xz: T,
+    pub yz: T,
+}
 
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.mag_sq().sqrt()
-                }
+pub type Bivec3 = Bivector3<f32>;
+pub type DBivec3 = Bivector3<f64>;
+
+impl<T: Scalar> Bivector3<T> {
+    pub fn new(xy: T, xz: T, yz: T) -> Self {
+        Self { xy, xz, yz }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::new(T::one(), T::one(), T::one())
+    }
+
+    // The normal of this plane, the inverse of Vector3::dual
+    #[inline]
+    pub fn dual(&self) -> Vector3<T> {
+        Vector3::new(self.yz, -self.xz, self.xy)
+    }
+
+    #[inline]
+    pub fn wedge_vec(&self, v: Vector3<T>) -> Trivector3<T> {
+        v.wedge_bivec(*self)
+    }
+}
 
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    (self.xy * self.xy) + (self.xz * self.xz) + (self.yz * self.yz)
-                }
+impl<T: Scalar> Bivector for Bivector3<T> {
+    type Decimal = T;
+    type Rotor = Rotor3<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        (self.xy * self.xy) + (self.xz * self.xz) + (self.yz * self.yz)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        (self.xy * other.xy) + (self.xz * other.xz) + (self.yz * other.yz)
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn exp(&self) -> Self::Rotor {
+        let angle = self.mag();
+        if angle <= T::epsilon() {
+            // sin(angle) / angle tends to one
+            return Rotor3::new(angle.cos(), *self);
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        Rotor3::new(cos, *self * (sin / angle))
+    }
+}
 
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    (self.xy * other.xy) + (self.xz * other.xz) + (self.yz * other.yz)
-                }
+impl<T: Scalar> Add for Bivector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    *self /= mag;
-                }
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.xy + rhs.xy, self.xz + rhs.xz, self.yz + rhs.yz)
+    }
+}
 
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut v = self.clone();
-                    v.normalize();
-                    v
-                }
+impl<T: Scalar> AddAssign for Bivector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.xy += rhs.xy;
+        self.xz += rhs.xz;
+        self.yz += rhs.yz;
+    }
+}
 
-            }
+impl<T: Scalar> Sub for Bivector3<T> {
+    type Output = Self;
 
-            impl Add for $nam {
-                type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.xy - rhs.xy, self.xz - rhs.xz, self.yz - rhs.yz)
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy + rhs.xy, self.xz + rhs.xz, self.yz + rhs.yz)
-                }
-            }
+impl<T: Scalar> SubAssign for Bivector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.xy -= rhs.xy;
+        self.xz -= rhs.xz;
+        self.yz -= rhs.yz;
+    }
+}
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: $nam) {
-                    self.xy += rhs.xy;
-                    self.xz += rhs.xz;
-                    self.yz += rhs.yz;
-                }
-            }
+impl<T: Scalar> Mul for Bivector3<T> {
+    type Output = Self;
 
-            impl Sub for $nam {
-                type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(self.xy * rhs.xy, self.xz * rhs.xz, self.yz * rhs.yz)
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy - rhs.xy, self.xz - rhs.xz, self.yz - rhs.yz)
-                }
-            }
+impl<T: Scalar> Mul<T> for Bivector3<T> {
+    type Output = Self;
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: $nam) {
-                    self.xy -= rhs.xy;
-                    self.xz -= rhs.xz;
-                    self.yz -= rhs.yz;
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.xy * rhs, self.xz * rhs, self.yz * rhs)
+    }
+}
 
-            impl Mul for $nam {
-                type Output = Self;
+impl<T: Scalar> MulAssign for Bivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.xy *= rhs.xy;
+        self.xz *= rhs.xz;
+        self.yz *= rhs.yz;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy * rhs.xy, self.xz * rhs.xz, self.yz * rhs.yz)
-                }
-            }
+impl<T: Scalar> MulAssign<T> for Bivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.xy *= rhs;
+        self.xz *= rhs;
+        self.yz *= rhs;
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> Div for Bivector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> $nam {
-                    $nam::new(self * rhs.xy, self * rhs.xz, self * rhs.yz)
-                }
-            }
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::new(self.xy / rhs.xy, self.xz / rhs.xz, self.yz / rhs.yz)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = Self;
+impl<T: Scalar> Div<T> for Bivector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self {
-                    $nam::new(self.xy * rhs, self.xz * rhs, self.yz * rhs)
-                }
-            }
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.xy / rhs, self.xz / rhs, self.yz / rhs)
+    }
+}
 
-            impl MulAssign for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: Self) {
-                    self.xy *= rhs.xy;
-                    self.xz *= rhs.xz;
-                    self.yz *= rhs.yz;
-                }
-            }
+impl<T: Scalar> DivAssign for Bivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        self.xy /= rhs.xy;
+        self.xz /= rhs.xz;
+        self.yz /= rhs.yz;
+    }
+}
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.xy *= rhs;
-                    self.xz *= rhs;
-                    self.yz *= rhs;
-                }
-            }
+impl<T: Scalar> DivAssign<T> for Bivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.xy /= rhs;
+        self.xz /= rhs;
+        self.yz /= rhs;
+    }
+}
 
-            impl Div for $nam {
-                type Output = Self;
+impl<T: Scalar> Neg for Bivector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn div(self, rhs: $nam) -> Self {
-                    $nam::new(self.xy / rhs.xy, self.xz / rhs.xz, self.yz / rhs.yz)
-                }
-            }
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.xy = -self.xy;
+        self.xz = -self.xz;
+        self.yz = -self.yz;
+        self
+    }
+}
 
-            impl Div<$nam> for $t {
-                type Output = $nam;
+macro_rules! impl_scalar_bivec3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Bivector3<$t>> for $t {
+                type Output = Bivector3<$t>;
 
                 #[inline]
-                fn div(self, rhs: $nam) -> $nam {
-                    $nam::new(self / rhs.xy, self / rhs.xz, self / rhs.yz)
+                fn mul(self, rhs: Bivector3<$t>) -> Bivector3<$t> {
+                    Bivector3::new(self * rhs.xy, self * rhs.xz, self * rhs.yz)
                 }
             }
 
-            impl Div<$t> for $nam {
-                type Output = $nam;
+            impl Div<Bivector3<$t>> for $t {
+                type Output = Bivector3<$t>;
 
                 #[inline]
-                fn div(self, rhs: $t) -> $nam {
-                    $nam::new(self.xy / rhs, self.xz / rhs, self.yz / rhs)
+                fn div(self, rhs: Bivector3<$t>) -> Bivector3<$t> {
+                    Bivector3::new(self / rhs.xy, self / rhs.xz, self / rhs.yz)
                 }
             }
+        )+
+    };
+}
 
-            impl DivAssign for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $nam) {
-                    self.xy /= rhs.xy;
-                    self.xz /= rhs.xz;
-                    self.yz /= rhs.yz;
-                }
-            }
+impl_scalar_bivec3![f32, f64];
 
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.xy /= rhs;
-                    self.xz /= rhs;
-                    self.yz /= rhs;
-                }
-            }
-
-            impl Neg for $nam {
-                type Output = Self;
+impl From<Bivec3> for DBivec3 {
+    #[inline]
+    fn from(bv: Bivec3) -> Self {
+        Self::new(f64::from(bv.xy), f64::from(bv.xz), f64::from(bv.yz))
+    }
+}
 
-                #[inline]
-                fn neg(mut self) -> Self {
-                    self.xy = -self.xy;
-                    self.xz = -self.xz;
-                    self.yz = -self.yz;
-                    self
-                }
-            }
-        )+
-    };
+impl Bivec3 {
+    #[inline]
+    pub fn as_dbivec3(&self) -> DBivec3 {
+        DBivec3::from(*self)
+    }
 }
 
-impl_bivec3![(f32, Bivec3)];
+impl DBivec3 {
+    #[inline]
+    pub fn as_bivec3(&self) -> Bivec3 {
+        Bivec3::new(self.xy as f32, self.xz as f32, self.yz as f32)
+    }
+}
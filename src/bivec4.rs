@@ -0,0 +1,298 @@
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Bivector4<T> {
+    pub xy: T,
+    pub xz: T,
+    pub xw: T,
+    pub yz: T,
+    pub yw: T,
+    pub zw: T,
+}
+
+pub type Bivec4 = Bivector4<f32>;
+pub type DBivec4 = Bivector4<f64>;
+
+impl<T: Scalar> Bivector4<T> {
+    #[inline]
+    pub fn new(xy: T, xz: T, xw: T, yz: T, yw: T, zw: T) -> Self {
+        Self {
+            xy,
+            xz,
+            xw,
+            yz,
+            yw,
+            zw,
+        }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::new(T::one(), T::one(), T::one(), T::one(), T::one(), T::one())
+    }
+
+    // The plane orthogonal to this one, self * xyzw
+    #[inline]
+    pub fn dual(&self) -> Self {
+        Self::new(-self.zw, self.yw, -self.yz, -self.xw, self.xz, -self.xy)
+    }
+
+    // The xyzw component of self ^ other, zero for two planes sharing a line
+    #[inline]
+    pub fn wedge(&self, other: Self) -> T {
+        (self.xy * other.zw) - (self.xz * other.yw) + (self.xw * other.yz) + (self.yz * other.xw)
+            - (self.yw * other.xz)
+            + (self.zw * other.xy)
+    }
+
+    #[inline]
+    pub fn is_simple(&self) -> bool {
+        self.wedge(*self).abs() <= T::epsilon() * self.mag_sq()
+    }
+
+    // Splits into the parts with dual() == part and dual() == -part. These
+    // commute, and each rotates by the same angle in two orthogonal planes.
+    #[inline]
+    pub fn split_isoclinic(&self) -> (Self, Self) {
+        let dual = self.dual();
+        ((*self + dual) * T::half(), (*self - dual) * T::half())
+    }
+}
+
+impl<T: Scalar> Bivector for Bivector4<T> {
+    type Decimal = T;
+    type Rotor = Rotor4<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        self.dot(*self)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        (self.xy * other.xy)
+            + (self.xz * other.xz)
+            + (self.xw * other.xw)
+            + (self.yz * other.yz)
+            + (self.yw * other.yw)
+            + (self.zw * other.zw)
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn exp(&self) -> Self::Rotor {
+        // An isoclinic part b squares to -2|b|^2 (1 +- xyzw) / 2, where (1 +- xyzw) / 2
+        // is idempotent and acts as one on b, so each part exponentiates like a
+        // simple bivector of magnitude sqrt(2)|b|.
+        let (plus, minus) = self.split_isoclinic();
+        let (cos_plus, sinc_plus) = cos_sinc(T::two().sqrt() * plus.mag());
+        let (cos_minus, sinc_minus) = cos_sinc(T::two().sqrt() * minus.mag());
+        Rotor4::new(
+            (cos_plus + cos_minus) * T::half(),
+            plus * sinc_plus + minus * sinc_minus,
+            (cos_plus - cos_minus) * T::half(),
+        )
+    }
+}
+
+#[inline]
+fn cos_sinc<T: Scalar>(angle: T) -> (T, T) {
+    if angle <= T::epsilon() {
+        // sin(angle) / angle tends to one
+        return (angle.cos(), T::one());
+    }
+
+    let (sin, cos) = angle.sin_cos();
+    (cos, sin / angle)
+}
+
+impl<T: Scalar> Add for Bivector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.xy + rhs.xy,
+            self.xz + rhs.xz,
+            self.xw + rhs.xw,
+            self.yz + rhs.yz,
+            self.yw + rhs.yw,
+            self.zw + rhs.zw,
+        )
+    }
+}
+
+impl<T: Scalar> AddAssign for Bivector4<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar> Sub for Bivector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.xy - rhs.xy,
+            self.xz - rhs.xz,
+            self.xw - rhs.xw,
+            self.yz - rhs.yz,
+            self.yw - rhs.yw,
+            self.zw - rhs.zw,
+        )
+    }
+}
+
+impl<T: Scalar> SubAssign for Bivector4<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Bivector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(
+            self.xy * rhs,
+            self.xz * rhs,
+            self.xw * rhs,
+            self.yz * rhs,
+            self.yw * rhs,
+            self.zw * rhs,
+        )
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Bivector4<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Bivector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(
+            self.xy / rhs,
+            self.xz / rhs,
+            self.xw / rhs,
+            self.yz / rhs,
+            self.yw / rhs,
+            self.zw / rhs,
+        )
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Bivector4<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar> Neg for Bivector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.xy, -self.xz, -self.xw, -self.yz, -self.yw, -self.zw)
+    }
+}
+
+impl<T: Scalar> From<Bivector3<T>> for Bivector4<T> {
+    #[inline]
+    fn from(bv: Bivector3<T>) -> Self {
+        Self::new(bv.xy, bv.xz, T::zero(), bv.yz, T::zero(), T::zero())
+    }
+}
+
+macro_rules! impl_scalar_bivec4 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Bivector4<$t>> for $t {
+                type Output = Bivector4<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Bivector4<$t>) -> Bivector4<$t> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_bivec4![f32, f64];
+
+impl From<Bivec4> for DBivec4 {
+    #[inline]
+    fn from(bv: Bivec4) -> Self {
+        Self::new(
+            f64::from(bv.xy),
+            f64::from(bv.xz),
+            f64::from(bv.xw),
+            f64::from(bv.yz),
+            f64::from(bv.yw),
+            f64::from(bv.zw),
+        )
+    }
+}
+
+impl Bivec4 {
+    #[inline]
+    pub fn as_dbivec4(&self) -> DBivec4 {
+        DBivec4::from(*self)
+    }
+}
+
+impl DBivec4 {
+    #[inline]
+    pub fn as_bivec4(&self) -> Bivec4 {
+        Bivec4::new(
+            self.xy as f32,
+            self.xz as f32,
+            self.xw as f32,
+            self.yz as f32,
+            self.yw as f32,
+            self.zw as f32,
+        )
+    }
+}
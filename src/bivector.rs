@@ -1,5 +1,6 @@
 pub trait Bivector {
     type Decimal;
+    type Rotor;
 
     fn mag(&self) -> Self::Decimal;
 
@@ -10,4 +11,6 @@ pub trait Bivector {
     fn normalize(&mut self);
 
     fn normalized(&self) -> Self;
+
+    fn exp(&self) -> Self::Rotor;
 }
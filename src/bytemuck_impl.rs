@@ -0,0 +1,85 @@
+// Every type is #[repr(C)] and made of nothing but T, so it has no padding and
+// is Pod whenever T is. The assertions check that layout at compile time. The
+// wide types are checked for the lane counts that have aliases, and the
+// aligned SIMD types are four f32 lanes in one 16 byte register.
+use crate::cga3d;
+use crate::pga2d::*;
+use crate::pga3d::*;
+use crate::sta::*;
+use crate::*;
+use bytemuck::{Pod, Zeroable};
+use std::mem::{align_of, size_of};
+
+macro_rules! impl_pod {
+    [$($ty:ident)::+: $len:expr] => {
+        unsafe impl<T: Zeroable> Zeroable for $($ty)::+<T> {}
+
+        unsafe impl<T: Pod> Pod for $($ty)::+<T> {}
+
+        const _: () = assert!(size_of::<$($ty)::+<f32>>() == $len * size_of::<f32>());
+        const _: () = assert!(size_of::<$($ty)::+<f64>>() == $len * size_of::<f64>());
+        const _: () = assert!(align_of::<$($ty)::+<f32>>() == align_of::<f32>());
+        const _: () = assert!(align_of::<$($ty)::+<f64>>() == align_of::<f64>());
+    };
+}
+
+macro_rules! impl_pod_wide {
+    [$($ty:ident: $len:expr), +] => {
+        $(
+            unsafe impl<T: Zeroable, const N: usize> Zeroable for $ty<T, N> {}
+
+            unsafe impl<T: Pod, const N: usize> Pod for $ty<T, N> {}
+
+            const _: () = assert!(size_of::<$ty<f32, 4>>() == $len * 4 * size_of::<f32>());
+            const _: () = assert!(size_of::<$ty<f32, 8>>() == $len * 8 * size_of::<f32>());
+            const _: () = assert!(size_of::<$ty<f64, 4>>() == $len * 4 * size_of::<f64>());
+        )+
+    };
+}
+
+// The padding lane of Vec3A may hold anything after a cast from bytes, every
+// operation either ignores it or clears it
+macro_rules! impl_pod_simd {
+    [$($ty:ident), +] => {
+        $(
+            #[cfg(feature = "simd")]
+            unsafe impl Zeroable for $ty {}
+
+            #[cfg(feature = "simd")]
+            unsafe impl Pod for $ty {}
+
+            #[cfg(feature = "simd")]
+            const _: () = assert!(size_of::<$ty>() == 16 && align_of::<$ty>() == 16);
+        )+
+    };
+}
+
+impl_pod![Vector2: 2];
+impl_pod![Vector3: 3];
+impl_pod![Vector4: 4];
+impl_pod![Bivector2: 1];
+impl_pod![Bivector3: 3];
+impl_pod![Bivector4: 6];
+impl_pod![Trivector3: 1];
+impl_pod![Rotor2: 2];
+impl_pod![Rotor3: 4];
+impl_pod![Rotor4: 8];
+impl_pod![Multivector3: 8];
+impl_pod![Matrix2: 4];
+impl_pod![Matrix3: 9];
+impl_pod![Matrix4: 16];
+impl_pod![Transformation2: 5];
+impl_pod![Transformation3: 8];
+impl_pod![Point2: 3];
+impl_pod![Line2: 3];
+impl_pod![Motor2: 4];
+impl_pod![Plane3: 4];
+impl_pod![Line3: 6];
+impl_pod![Point3: 4];
+impl_pod![Motor3: 8];
+impl_pod![cga3d::Multivector: 32];
+impl_pod![FourVector: 4];
+impl_pod![SpacetimeBivector: 6];
+impl_pod![LorentzRotor: 8];
+impl_pod_wide![WideVector3: 3, WideBivector3: 3, WideRotor3: 4];
+impl_pod_simd![Vec3A, Vec4A, Rot3A];
@@ -0,0 +1,386 @@
+// Conformal geometric algebra of 3D space, Cl(4,1) with basis e1, e2, e3, e4, e5
+// where e4 * e4 = 1 and e5 * e5 = -1. Blade components are indexed by bitmask,
+// bit i set for e(i + 1), so c[0b00011] is the e12 component.
+//
+// Objects are kept in dual form: a point x lies on an object a when
+// up(x).inner(a) is zero. Spheres and planes are vectors, and meeting them with
+// the outer product gives circles, lines and point pairs.
+use crate::*;
+use std::ops::*;
+
+const BLADES: usize = 32;
+
+// The sign of the product of two basis blades, including the metric
+const SIGNS: [[i8; BLADES]; BLADES] = algebra::sign_table::<BLADES>(&[1, 1, 1, 1, -1]);
+
+#[inline]
+fn grade_of(blade: usize) -> usize {
+    blade.count_ones() as usize
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Multivector<T> {
+    pub c: [T; BLADES],
+}
+
+impl<T: Scalar> Multivector<T> {
+    #[inline]
+    pub fn new(c: [T; BLADES]) -> Self {
+        Self { c }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new([T::zero(); BLADES])
+    }
+
+    #[inline]
+    pub fn scalar(s: T) -> Self {
+        let mut m = Self::zero();
+        m.c[0] = s;
+        m
+    }
+
+    // The basis vector e(i + 1)
+    #[inline]
+    pub fn basis(i: usize) -> Self {
+        let mut m = Self::zero();
+        m.c[1 << i] = T::one();
+        m
+    }
+
+    #[inline]
+    pub fn vector(v: Vector3<T>) -> Self {
+        let mut m = Self::zero();
+        m.c[0b00001] = v.x;
+        m.c[0b00010] = v.y;
+        m.c[0b00100] = v.z;
+        m
+    }
+
+    // The point at the origin, (e5 - e4) / 2
+    #[inline]
+    pub fn no() -> Self {
+        (Self::basis(4) - Self::basis(3)) * T::half()
+    }
+
+    // The point at infinity, e4 + e5
+    #[inline]
+    pub fn ni() -> Self {
+        Self::basis(3) + Self::basis(4)
+    }
+
+    #[inline]
+    pub fn up(v: Vector3<T>) -> Self {
+        Self::no() + Self::vector(v) + Self::ni() * (v.mag_sq() * T::half())
+    }
+
+    // The Euclidean position of a point, whatever its weight
+    #[inline]
+    pub fn down(&self) -> Vector3<T> {
+        let weight = -Self::ni().inner(*self).c[0];
+        Vector3::new(self.c[0b00001], self.c[0b00010], self.c[0b00100]) / weight
+    }
+
+    #[inline]
+    pub fn sphere(center: Vector3<T>, radius: T) -> Self {
+        Self::up(center) - Self::ni() * (radius * radius * T::half())
+    }
+
+    // The plane normal.dot(x) == distance, for a unit normal
+    #[inline]
+    pub fn plane(normal: Vector3<T>, distance: T) -> Self {
+        Self::vector(normal) + Self::ni() * distance
+    }
+
+    #[inline]
+    pub fn sphere_through(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>, d: Vector3<T>) -> Self {
+        Self::up(a)
+            .wedge(Self::up(b))
+            .wedge(Self::up(c))
+            .wedge(Self::up(d))
+            .dual()
+    }
+
+    #[inline]
+    pub fn circle_through(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> Self {
+        Self::up(a).wedge(Self::up(b)).wedge(Self::up(c)).dual()
+    }
+
+    #[inline]
+    pub fn line_through(a: Vector3<T>, b: Vector3<T>) -> Self {
+        Self::up(a).wedge(Self::up(b)).wedge(Self::ni()).dual()
+    }
+
+    #[inline]
+    pub fn point_pair(a: Vector3<T>, b: Vector3<T>) -> Self {
+        Self::up(a).wedge(Self::up(b)).dual()
+    }
+
+    // Moves points by t
+    #[inline]
+    pub fn translator(t: Vector3<T>) -> Self {
+        Self::scalar(T::one()) - Self::vector(t) * Self::ni() * T::half()
+    }
+
+    #[inline]
+    pub fn rotor(r: Rotor3<T>) -> Self {
+        let mut m = Self::scalar(r.s);
+        m.c[0b00011] = r.bv.xy;
+        m.c[0b00101] = r.bv.xz;
+        m.c[0b00110] = r.bv.yz;
+        m
+    }
+
+    // Scales points about the origin
+    #[inline]
+    pub fn dilator(scale: T) -> Self {
+        let half_log = scale.ln() * T::half();
+        let (sinh, cosh) = (half_log.sinh(), half_log.cosh());
+        Self::scalar(cosh) + Self::no().wedge(Self::ni()) * sinh
+    }
+
+    // Reflects points in the sphere, mapping x to center + radius^2 (x - center) / |x - center|^2
+    #[inline]
+    pub fn inversion(center: Vector3<T>, radius: T) -> Self {
+        Self::sphere(center, radius)
+    }
+
+    #[inline]
+    pub fn grade(&self, k: usize) -> Self {
+        let mut m = Self::zero();
+        for (blade, c) in m.c.iter_mut().enumerate() {
+            if grade_of(blade) == k {
+                *c = self.c[blade];
+            }
+        }
+        m
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        for (blade, c) in self.c.iter_mut().enumerate() {
+            if grade_of(blade) % 4 >= 2 {
+                *c = -*c;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut m = *self;
+        m.reverse();
+        m
+    }
+
+    #[inline]
+    pub fn involute(&mut self) {
+        for (blade, c) in self.c.iter_mut().enumerate() {
+            if grade_of(blade) % 2 == 1 {
+                *c = -*c;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn involuted(&self) -> Self {
+        let mut m = *self;
+        m.involute();
+        m
+    }
+
+    // Switches between the direct and dual forms of an object, self / e12345
+    #[inline]
+    pub fn dual(&self) -> Self {
+        -(*self * Self::basis_blade(BLADES - 1))
+    }
+
+    #[inline]
+    pub fn undual(&self) -> Self {
+        *self * Self::basis_blade(BLADES - 1)
+    }
+
+    #[inline]
+    fn basis_blade(blade: usize) -> Self {
+        let mut m = Self::zero();
+        m.c[blade] = T::one();
+        m
+    }
+
+    fn product(&self, other: Self, keep: impl Fn(usize, usize) -> bool) -> Self {
+        let mut m = Self::zero();
+        for (a, &ca) in self.c.iter().enumerate() {
+            for (b, &cb) in other.c.iter().enumerate() {
+                if !keep(a, b) {
+                    continue;
+                }
+
+                match SIGNS[a][b] {
+                    1 => m.c[a ^ b] += ca * cb,
+                    -1 => m.c[a ^ b] -= ca * cb,
+                    _ => {}
+                }
+            }
+        }
+        m
+    }
+
+    #[inline]
+    pub fn wedge(&self, other: Self) -> Self {
+        self.product(other, |a, b| a & b == 0)
+    }
+
+    // The left contraction, self ⌋ other
+    #[inline]
+    pub fn inner(&self, other: Self) -> Self {
+        self.product(other, |a, b| a & b == a)
+    }
+
+    #[inline]
+    pub fn scalar_product(&self, other: Self) -> T {
+        self.product(other, |a, b| a == b).c[0]
+    }
+
+    // The intersection of two objects in dual form
+    #[inline]
+    pub fn meet(&self, other: Self) -> Self {
+        self.wedge(other)
+    }
+
+    // The inverse of a versor or blade
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        let reversed = self.reversed();
+        reversed / self.scalar_product(reversed)
+    }
+
+    // Applies this versor to x, self * x * self.inverse()
+    #[inline]
+    pub fn transform(&self, x: Self) -> Self {
+        *self * x * self.inverse()
+    }
+
+    // The centre of a sphere, circle or point pair in dual form
+    #[inline]
+    pub fn center(&self) -> Vector3<T> {
+        (*self * Self::ni() * *self).down()
+    }
+
+    // The squared radius of a sphere, circle or point pair in dual form, negative
+    // for imaginary ones
+    #[inline]
+    pub fn radius_sq(&self) -> T {
+        let n = Self::ni().inner(*self);
+        -self.scalar_product(self.involuted()) / n.scalar_product(n)
+    }
+
+    // The two points of a point pair in dual form
+    pub fn point_pair_points(&self) -> (Vector3<T>, Vector3<T>) {
+        let p = self.undual();
+        let sqrt = Self::scalar(p.scalar_product(p).sqrt());
+        let n = Self::ni().inner(p);
+        (((p - sqrt) * n).down(), ((p + sqrt) * n).down())
+    }
+}
+
+impl<T: Scalar> Add for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Scalar> AddAssign for Multivector<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        for (c, r) in self.c.iter_mut().zip(rhs.c.iter()) {
+            *c += *r;
+        }
+    }
+}
+
+impl<T: Scalar> Sub for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Scalar> SubAssign for Multivector<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        for (c, r) in self.c.iter_mut().zip(rhs.c.iter()) {
+            *c -= *r;
+        }
+    }
+}
+
+impl<T: Scalar> Neg for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self * -T::one()
+    }
+}
+
+impl<T: Scalar> Mul for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.product(rhs, |_, _| true)
+    }
+}
+
+impl<T: Scalar> MulAssign for Multivector<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: T) -> Self {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Multivector<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        for c in self.c.iter_mut() {
+            *c *= rhs;
+        }
+    }
+}
+
+impl<T: Scalar> Div<T> for Multivector<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(mut self, rhs: T) -> Self {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Multivector<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        for c in self.c.iter_mut() {
+            *c /= rhs;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::*;
+
+impl<T: Scalar> From<Vector2<T>> for cgmath::Vector2<T> {
+    #[inline]
+    fn from(v: Vector2<T>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar> From<cgmath::Vector2<T>> for Vector2<T> {
+    #[inline]
+    fn from(v: cgmath::Vector2<T>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for cgmath::Vector3<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<cgmath::Vector3<T>> for Vector3<T> {
+    #[inline]
+    fn from(v: cgmath::Vector3<T>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<Vector4<T>> for cgmath::Vector4<T> {
+    #[inline]
+    fn from(v: Vector4<T>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl<T: Scalar> From<cgmath::Vector4<T>> for Vector4<T> {
+    #[inline]
+    fn from(v: cgmath::Vector4<T>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+// Follows the convention of Rotor3::to_quaternion
+impl<T: Scalar> From<Rotor3<T>> for cgmath::Quaternion<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        let q = r.to_quaternion();
+        Self::from_sv(q.w, cgmath::Vector3::new(q.x, q.y, q.z))
+    }
+}
+
+impl<T: Scalar> From<cgmath::Quaternion<T>> for Rotor3<T> {
+    #[inline]
+    fn from(q: cgmath::Quaternion<T>) -> Self {
+        Self::from_quaternion(Vector4::new(q.v.x, q.v.y, q.v.z, q.s))
+    }
+}
+
+impl<T: Scalar> From<Matrix2<T>> for cgmath::Matrix2<T> {
+    #[inline]
+    fn from(m: Matrix2<T>) -> Self {
+        Self::from_cols(m.cols[0].into(), m.cols[1].into())
+    }
+}
+
+impl<T: Scalar> From<cgmath::Matrix2<T>> for Matrix2<T> {
+    #[inline]
+    fn from(m: cgmath::Matrix2<T>) -> Self {
+        Self::new(m.x.into(), m.y.into())
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for cgmath::Matrix3<T> {
+    #[inline]
+    fn from(m: Matrix3<T>) -> Self {
+        Self::from_cols(m.cols[0].into(), m.cols[1].into(), m.cols[2].into())
+    }
+}
+
+impl<T: Scalar> From<cgmath::Matrix3<T>> for Matrix3<T> {
+    #[inline]
+    fn from(m: cgmath::Matrix3<T>) -> Self {
+        Self::new(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl<T: Scalar> From<Matrix4<T>> for cgmath::Matrix4<T> {
+    #[inline]
+    fn from(m: Matrix4<T>) -> Self {
+        Self::from_cols(
+            m.cols[0].into(),
+            m.cols[1].into(),
+            m.cols[2].into(),
+            m.cols[3].into(),
+        )
+    }
+}
+
+impl<T: Scalar> From<cgmath::Matrix4<T>> for Matrix4<T> {
+    #[inline]
+    fn from(m: cgmath::Matrix4<T>) -> Self {
+        Self::new(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
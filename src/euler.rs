@@ -0,0 +1,74 @@
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+    XYX,
+    XZX,
+    YXY,
+    YZY,
+    ZXZ,
+    ZYZ,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerFrame {
+    // Each rotation is about an axis of the already rotated frame
+    Intrinsic,
+    // Each rotation is about an axis of the fixed world frame
+    Extrinsic,
+}
+
+impl EulerOrder {
+    pub const ALL: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
+    #[inline]
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    #[inline]
+    pub fn reversed(self) -> Self {
+        match self {
+            EulerOrder::XYZ => EulerOrder::ZYX,
+            EulerOrder::XZY => EulerOrder::YZX,
+            EulerOrder::YXZ => EulerOrder::ZXY,
+            EulerOrder::YZX => EulerOrder::XZY,
+            EulerOrder::ZXY => EulerOrder::YXZ,
+            EulerOrder::ZYX => EulerOrder::XYZ,
+            order => order,
+        }
+    }
+}
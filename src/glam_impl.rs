@@ -0,0 +1,118 @@
+use crate::*;
+
+macro_rules! impl_glam {
+    [$t:ident: $vec2:ident, $vec3:ident, $vec4:ident, $quat:ident, $mat2:ident, $mat3:ident, $mat4:ident] => {
+        impl From<Vector2<$t>> for glam::$vec2 {
+            #[inline]
+            fn from(v: Vector2<$t>) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        impl From<glam::$vec2> for Vector2<$t> {
+            #[inline]
+            fn from(v: glam::$vec2) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        impl From<Vector3<$t>> for glam::$vec3 {
+            #[inline]
+            fn from(v: Vector3<$t>) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<glam::$vec3> for Vector3<$t> {
+            #[inline]
+            fn from(v: glam::$vec3) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<Vector4<$t>> for glam::$vec4 {
+            #[inline]
+            fn from(v: Vector4<$t>) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+
+        impl From<glam::$vec4> for Vector4<$t> {
+            #[inline]
+            fn from(v: glam::$vec4) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+
+        // Follows the convention of Rotor3::to_quaternion
+        impl From<Rotor3<$t>> for glam::$quat {
+            #[inline]
+            fn from(r: Rotor3<$t>) -> Self {
+                let q = r.to_quaternion();
+                Self::from_xyzw(q.x, q.y, q.z, q.w)
+            }
+        }
+
+        impl From<glam::$quat> for Rotor3<$t> {
+            #[inline]
+            fn from(q: glam::$quat) -> Self {
+                Self::from_quaternion(Vector4::new(q.x, q.y, q.z, q.w))
+            }
+        }
+
+        impl From<Matrix2<$t>> for glam::$mat2 {
+            #[inline]
+            fn from(m: Matrix2<$t>) -> Self {
+                Self::from_cols(m.cols[0].into(), m.cols[1].into())
+            }
+        }
+
+        impl From<glam::$mat2> for Matrix2<$t> {
+            #[inline]
+            fn from(m: glam::$mat2) -> Self {
+                Self::new(m.x_axis.into(), m.y_axis.into())
+            }
+        }
+
+        impl From<Matrix3<$t>> for glam::$mat3 {
+            #[inline]
+            fn from(m: Matrix3<$t>) -> Self {
+                Self::from_cols(m.cols[0].into(), m.cols[1].into(), m.cols[2].into())
+            }
+        }
+
+        impl From<glam::$mat3> for Matrix3<$t> {
+            #[inline]
+            fn from(m: glam::$mat3) -> Self {
+                Self::new(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+            }
+        }
+
+        impl From<Matrix4<$t>> for glam::$mat4 {
+            #[inline]
+            fn from(m: Matrix4<$t>) -> Self {
+                Self::from_cols(
+                    m.cols[0].into(),
+                    m.cols[1].into(),
+                    m.cols[2].into(),
+                    m.cols[3].into(),
+                )
+            }
+        }
+
+        impl From<glam::$mat4> for Matrix4<$t> {
+            #[inline]
+            fn from(m: glam::$mat4) -> Self {
+                Self::new(
+                    m.x_axis.into(),
+                    m.y_axis.into(),
+                    m.z_axis.into(),
+                    m.w_axis.into(),
+                )
+            }
+        }
+    };
+}
+
+impl_glam![f32: Vec2, Vec3, Vec4, Quat, Mat2, Mat3, Mat4];
+impl_glam![f64: DVec2, DVec3, DVec4, DQuat, DMat2, DMat3, DMat4];
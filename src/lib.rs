@@ -1,27 +1,88 @@
+pub mod algebra;
+mod approx_eq;
+#[cfg(feature = "approx")]
+mod approx_impl;
 mod bivec2;
 mod bivec3;
+mod bivec4;
 mod bivector;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+pub mod cga3d;
+#[cfg(feature = "cgmath")]
+mod cgmath_impl;
+mod euler;
+#[cfg(feature = "glam")]
+mod glam_impl;
 mod mat2;
 mod mat3;
 mod mat4;
+#[cfg(feature = "mint")]
+mod mint_impl;
+mod multivec3;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+pub mod pga2d;
+pub mod pga3d;
+mod projection;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
 mod rot2;
 mod rot3;
+#[cfg(feature = "simd")]
+mod rot3a;
+mod rot4;
 mod rotor;
+mod scalar;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "simd")]
+mod simd;
+pub mod sta;
+mod transform2;
+mod transform3;
+mod trivec3;
 mod vec2;
 mod vec3;
+#[cfg(feature = "simd")]
+mod vec3a;
 mod vec4;
+#[cfg(feature = "simd")]
+mod vec4a;
 mod vector;
+mod wide_bivec3;
+mod wide_rot3;
+mod wide_vec3;
 
+pub use approx_eq::*;
 pub use bivec2::*;
 pub use bivec3::*;
+pub use bivec4::*;
 pub use bivector::*;
+pub use euler::*;
 pub use mat2::*;
 pub use mat3::*;
 pub use mat4::*;
+pub use multivec3::*;
+pub use projection::*;
 pub use rot2::*;
 pub use rot3::*;
+#[cfg(feature = "simd")]
+pub use rot3a::*;
+pub use rot4::*;
 pub use rotor::*;
+pub use scalar::*;
+pub use transform2::*;
+pub use transform3::*;
+pub use trivec3::*;
 pub use vec2::*;
 pub use vec3::*;
+#[cfg(feature = "simd")]
+pub use vec3a::*;
 pub use vec4::*;
+#[cfg(feature = "simd")]
+pub use vec4a::*;
 pub use vector::*;
+pub use wide_bivec3::*;
+pub use wide_rot3::*;
+pub use wide_vec3::*;
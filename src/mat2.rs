@@ -1,11 +1,278 @@
-use crate::*;
-
-pub struct Mat2 {
-    pub cols: [Vec2; 2],
-}
-
-impl Mat2 {
-    pub fn new(col1: Vec2, col2: Vec2) -> Self {
-        Self { cols: [col1, col2] }
-    }
-}
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Matrix2<T> {
+    pub cols: [Vector2<T>; 2],
+}
+
+pub type Mat2 = Matrix2<f32>;
+pub type DMat2 = Matrix2<f64>;
+
+impl<T: Scalar> Matrix2<T> {
+    pub fn new(col1: Vector2<T>, col2: Vector2<T>) -> Self {
+        Self { cols: [col1, col2] }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            cols: [Vector2::zero(), Vector2::zero()],
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(
+            Vector2::new(T::one(), T::zero()),
+            Vector2::new(T::zero(), T::one()),
+        )
+    }
+
+    #[inline]
+    pub fn col(&self, index: usize) -> Vector2<T> {
+        self[index]
+    }
+
+    #[inline]
+    pub fn row(&self, index: usize) -> Vector2<T> {
+        Vector2::new(self.cols[0][index], self.cols[1][index])
+    }
+
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Self::new(self.row(0), self.row(1))
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.cols[0].x * self.cols[1].y - self.cols[1].x * self.cols[0].y
+    }
+
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        let inv_det = T::one() / det;
+        Some(Self::new(
+            Vector2::new(self.cols[1].y, -self.cols[0].y) * inv_det,
+            Vector2::new(-self.cols[1].x, self.cols[0].x) * inv_det,
+        ))
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+}
+
+impl<T: Scalar> Add for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            cols: [self.cols[0] + rhs.cols[0], self.cols[1] + rhs.cols[1]],
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Matrix2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.cols[0] += rhs.cols[0];
+        self.cols[1] += rhs.cols[1];
+    }
+}
+
+impl<T: Scalar> Sub for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            cols: [self.cols[0] - rhs.cols[0], self.cols[1] - rhs.cols[1]],
+        }
+    }
+}
+
+impl<T: Scalar> SubAssign for Matrix2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.cols[0] -= rhs.cols[0];
+        self.cols[1] -= rhs.cols[1];
+    }
+}
+
+impl<T: Scalar> Neg for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            cols: [-self.cols[0], -self.cols[1]],
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cols: [self * rhs.cols[0], self * rhs.cols[1]],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Matrix2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<Vector2<T>> for Matrix2<T> {
+    type Output = Vector2<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector2<T>) -> Vector2<T> {
+        self.cols[0] * rhs.x + self.cols[1] * rhs.y
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self {
+            cols: [self.cols[0] * rhs, self.cols[1] * rhs],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.cols[0] *= rhs;
+        self.cols[1] *= rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self {
+            cols: [self.cols[0] / rhs, self.cols[1] / rhs],
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Matrix2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.cols[0] /= rhs;
+        self.cols[1] /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Matrix2<T> {
+    type Output = Vector2<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.cols[0],
+            1 => &self.cols[1],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix2<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.cols[0],
+            1 => &mut self.cols[1],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T: Scalar> From<[[T; 2]; 2]> for Matrix2<T> {
+    #[inline]
+    fn from(cols: [[T; 2]; 2]) -> Self {
+        Self {
+            cols: [cols[0].into(), cols[1].into()],
+        }
+    }
+}
+
+impl<T> From<Matrix2<T>> for [[T; 2]; 2] {
+    #[inline]
+    fn from(m: Matrix2<T>) -> Self {
+        let [c0, c1] = m.cols;
+        [c0.into(), c1.into()]
+    }
+}
+
+macro_rules! impl_scalar_mat2 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Matrix2<$t>> for $t {
+                type Output = Matrix2<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Matrix2<$t>) -> Matrix2<$t> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_mat2![f32, f64];
+
+impl From<Mat2> for DMat2 {
+    #[inline]
+    fn from(m: Mat2) -> Self {
+        Self::new(m.cols[0].into(), m.cols[1].into())
+    }
+}
+
+impl Mat2 {
+    #[inline]
+    pub fn as_dmat2(&self) -> DMat2 {
+        DMat2::new(self.cols[0].into(), self.cols[1].into())
+    }
+}
+
+impl DMat2 {
+    #[inline]
+    pub fn as_mat2(&self) -> Mat2 {
+        Mat2::new(self.cols[0].as_vec2(), self.cols[1].as_vec2())
+    }
+}
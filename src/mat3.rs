@@ -1,13 +1,323 @@
-use crate::*;
-
-pub struct Mat3 {
-    pub cols: [Vec3; 3],
-}
-
-impl Mat3 {
-    pub fn new(col1: Vec3, col2: Vec3, col3: Vec3) -> Self {
-        Self {
-            cols: [col1, col2, col3],
-        }
-    }
-}
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Matrix3<T> {
+    pub cols: [Vector3<T>; 3],
+}
+
+pub type Mat3 = Matrix3<f32>;
+pub type DMat3 = Matrix3<f64>;
+
+impl<T: Scalar> Matrix3<T> {
+    pub fn new(col1: Vector3<T>, col2: Vector3<T>, col3: Vector3<T>) -> Self {
+        Self {
+            cols: [col1, col2, col3],
+        }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            cols: [Vector3::zero(), Vector3::zero(), Vector3::zero()],
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(
+            Vector3::new(T::one(), T::zero(), T::zero()),
+            Vector3::new(T::zero(), T::one(), T::zero()),
+            Vector3::new(T::zero(), T::zero(), T::one()),
+        )
+    }
+
+    #[inline]
+    pub fn col(&self, index: usize) -> Vector3<T> {
+        self[index]
+    }
+
+    #[inline]
+    pub fn row(&self, index: usize) -> Vector3<T> {
+        Vector3::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+        )
+    }
+
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Self::new(self.row(0), self.row(1), self.row(2))
+    }
+
+    #[inline]
+    pub fn orthonormalize(&mut self) {
+        let x = self.cols[0].normalized();
+        let y = self.cols[1].rejected(x).normalized();
+        let z = x.cross(y);
+        *self = Self::new(x, y, z);
+    }
+
+    #[inline]
+    pub fn orthonormalized(&self) -> Self {
+        let mut m = *self;
+        m.orthonormalize();
+        m
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.cols[0].dot(self.cols[1].cross(self.cols[2]))
+    }
+
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        // The rows of the inverse are the cross products of the columns.
+        let inv_det = T::one() / det;
+        let r0 = self.cols[1].cross(self.cols[2]) * inv_det;
+        let r1 = self.cols[2].cross(self.cols[0]) * inv_det;
+        let r2 = self.cols[0].cross(self.cols[1]) * inv_det;
+        Some(Self::new(r0, r1, r2).transposed())
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+}
+
+impl<T: Scalar> Add for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            cols: [
+                self.cols[0] + rhs.cols[0],
+                self.cols[1] + rhs.cols[1],
+                self.cols[2] + rhs.cols[2],
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Matrix3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.cols[0] += rhs.cols[0];
+        self.cols[1] += rhs.cols[1];
+        self.cols[2] += rhs.cols[2];
+    }
+}
+
+impl<T: Scalar> Sub for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            cols: [
+                self.cols[0] - rhs.cols[0],
+                self.cols[1] - rhs.cols[1],
+                self.cols[2] - rhs.cols[2],
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> SubAssign for Matrix3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.cols[0] -= rhs.cols[0];
+        self.cols[1] -= rhs.cols[1];
+        self.cols[2] -= rhs.cols[2];
+    }
+}
+
+impl<T: Scalar> Neg for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            cols: [-self.cols[0], -self.cols[1], -self.cols[2]],
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cols: [self * rhs.cols[0], self * rhs.cols[1], self * rhs.cols[2]],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Matrix3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<Vector3<T>> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        self.cols[0] * rhs.x + self.cols[1] * rhs.y + self.cols[2] * rhs.z
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self {
+            cols: [self.cols[0] * rhs, self.cols[1] * rhs, self.cols[2] * rhs],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.cols[0] *= rhs;
+        self.cols[1] *= rhs;
+        self.cols[2] *= rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self {
+            cols: [self.cols[0] / rhs, self.cols[1] / rhs, self.cols[2] / rhs],
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Matrix3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.cols[0] /= rhs;
+        self.cols[1] /= rhs;
+        self.cols[2] /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Matrix3<T> {
+    type Output = Vector3<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.cols[0],
+            1 => &self.cols[1],
+            2 => &self.cols[2],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.cols[0],
+            1 => &mut self.cols[1],
+            2 => &mut self.cols[2],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T: Scalar> From<[[T; 3]; 3]> for Matrix3<T> {
+    #[inline]
+    fn from(cols: [[T; 3]; 3]) -> Self {
+        Self {
+            cols: [cols[0].into(), cols[1].into(), cols[2].into()],
+        }
+    }
+}
+
+impl<T> From<Matrix3<T>> for [[T; 3]; 3] {
+    #[inline]
+    fn from(m: Matrix3<T>) -> Self {
+        let [c0, c1, c2] = m.cols;
+        [c0.into(), c1.into(), c2.into()]
+    }
+}
+
+macro_rules! impl_scalar_mat3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Matrix3<$t>> for $t {
+                type Output = Matrix3<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Matrix3<$t>) -> Matrix3<$t> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_mat3![f32, f64];
+
+impl From<Mat3> for DMat3 {
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self::new(m.cols[0].into(), m.cols[1].into(), m.cols[2].into())
+    }
+}
+
+impl Mat3 {
+    #[inline]
+    pub fn as_dmat3(&self) -> DMat3 {
+        DMat3::new(
+            self.cols[0].into(),
+            self.cols[1].into(),
+            self.cols[2].into(),
+        )
+    }
+}
+
+impl DMat3 {
+    #[inline]
+    pub fn as_mat3(&self) -> Mat3 {
+        Mat3::new(
+            self.cols[0].as_vec3(),
+            self.cols[1].as_vec3(),
+            self.cols[2].as_vec3(),
+        )
+    }
+}
@@ -1,13 +1,541 @@
-use crate::*;
-
-pub struct Mat4 {
-    pub cols: [Vec4; 4],
-}
-
-impl Mat4 {
-    pub fn new(col1: Vec4, col2: Vec4, col3: Vec4, col4: Vec4) -> Self {
-        Self {
-            cols: [col1, col2, col3, col4],
-        }
-    }
-}
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Matrix4<T> {
+    pub cols: [Vector4<T>; 4],
+}
+
+pub type Mat4 = Matrix4<f32>;
+pub type DMat4 = Matrix4<f64>;
+
+impl<T: Scalar> Matrix4<T> {
+    pub fn new(col1: Vector4<T>, col2: Vector4<T>, col3: Vector4<T>, col4: Vector4<T>) -> Self {
+        Self {
+            cols: [col1, col2, col3, col4],
+        }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self {
+            cols: [
+                Vector4::zero(),
+                Vector4::zero(),
+                Vector4::zero(),
+                Vector4::zero(),
+            ],
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(
+            Vector4::new(T::one(), T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::one(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::one(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), T::zero(), T::one()),
+        )
+    }
+
+    #[inline]
+    pub fn col(&self, index: usize) -> Vector4<T> {
+        self[index]
+    }
+
+    #[inline]
+    pub fn row(&self, index: usize) -> Vector4<T> {
+        Vector4::new(
+            self.cols[0][index],
+            self.cols[1][index],
+            self.cols[2][index],
+            self.cols[3][index],
+        )
+    }
+
+    #[inline]
+    pub fn transpose(&mut self) {
+        *self = self.transposed();
+    }
+
+    #[inline]
+    pub fn transposed(&self) -> Self {
+        Self::new(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    #[inline]
+    fn sub_factors(&self) -> ([T; 6], [T; 6]) {
+        let [a, b, c, d] = self.cols;
+        (
+            [
+                a.x * b.y - b.x * a.y,
+                a.x * b.z - b.x * a.z,
+                a.x * b.w - b.x * a.w,
+                a.y * b.z - b.y * a.z,
+                a.y * b.w - b.y * a.w,
+                a.z * b.w - b.z * a.w,
+            ],
+            [
+                c.x * d.y - d.x * c.y,
+                c.x * d.z - d.x * c.z,
+                c.x * d.w - d.x * c.w,
+                c.y * d.z - d.y * c.z,
+                c.y * d.w - d.y * c.w,
+                c.z * d.w - d.z * c.w,
+            ],
+        )
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        let (s, c) = self.sub_factors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        let (s, c) = self.sub_factors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == T::zero() {
+            return None;
+        }
+
+        let [a, b, e, d] = self.cols;
+        let inv_det = T::one() / det;
+        Some(Self::new(
+            Vector4::new(
+                b.y * c[5] - b.z * c[4] + b.w * c[3],
+                -a.y * c[5] + a.z * c[4] - a.w * c[3],
+                d.y * s[5] - d.z * s[4] + d.w * s[3],
+                -e.y * s[5] + e.z * s[4] - e.w * s[3],
+            ) * inv_det,
+            Vector4::new(
+                -b.x * c[5] + b.z * c[2] - b.w * c[1],
+                a.x * c[5] - a.z * c[2] + a.w * c[1],
+                -d.x * s[5] + d.z * s[2] - d.w * s[1],
+                e.x * s[5] - e.z * s[2] + e.w * s[1],
+            ) * inv_det,
+            Vector4::new(
+                b.x * c[4] - b.y * c[2] + b.w * c[0],
+                -a.x * c[4] + a.y * c[2] - a.w * c[0],
+                d.x * s[4] - d.y * s[2] + d.w * s[0],
+                -e.x * s[4] + e.y * s[2] - e.w * s[0],
+            ) * inv_det,
+            Vector4::new(
+                -b.x * c[3] + b.y * c[1] - b.z * c[0],
+                a.x * c[3] - a.y * c[1] + a.z * c[0],
+                -d.x * s[3] + d.y * s[1] - d.z * s[0],
+                e.x * s[3] - e.y * s[1] + e.z * s[0],
+            ) * inv_det,
+        ))
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+
+    // Transforms a point with w = 1 and divides by the resulting w, so a
+    // projection gives normalized device coordinates rather than clip space
+    #[inline]
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let p = self.cols[0] * point.x + self.cols[1] * point.y + self.cols[2] * point.z;
+        let p = p + self.cols[3];
+        Vector3::new(p.x, p.y, p.z) / p.w
+    }
+
+    // Transforms a direction with w = 0, ignoring the translation
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        let v = self.cols[0] * vector.x + self.cols[1] * vector.y + self.cols[2] * vector.z;
+        Vector3::new(v.x, v.y, v.z)
+    }
+
+    // transform_point for each point, projections give normalized device coordinates
+    pub fn transform_points(&self, points: &mut [Vector3<T>]) {
+        for p in points {
+            *p = self.transform_point(*p);
+        }
+    }
+
+    pub fn transform_vectors(&self, vectors: &mut [Vector3<T>]) {
+        for v in vectors {
+            *v = self.transform_vector(*v);
+        }
+    }
+
+    pub fn from_rot3_translation(rotation: Rotor3<T>, translation: Vector3<T>) -> Self {
+        let rot = rotation.into_matrix();
+        Self::new(
+            rot.cols[0].into(),
+            rot.cols[1].into(),
+            rot.cols[2].into(),
+            Vector4::new(translation.x, translation.y, translation.z, T::one()),
+        )
+    }
+
+    // The view matrix of a camera at eye looking at target. If up is parallel
+    // to the view direction another world axis stands in for it.
+    #[inline]
+    pub fn look_at(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>, hand: Handedness) -> Self {
+        Self::look_to(eye, target - eye, up, hand)
+    }
+
+    pub fn look_to(
+        eye: Vector3<T>,
+        direction: Vector3<T>,
+        up: Vector3<T>,
+        hand: Handedness,
+    ) -> Self {
+        // The camera's orientation in the world, its columns are the view space axes
+        let forward = direction.normalized();
+        let up = Vector3::look_up(forward, up);
+        let (right, up, z) = match hand {
+            Handedness::Left => {
+                let right = up.cross(forward).normalized();
+                (right, forward.cross(right), forward)
+            }
+            Handedness::Right => {
+                let right = forward.cross(up).normalized();
+                (right, right.cross(forward), -forward)
+            }
+        };
+        let camera = Rotor3::from_matrix(&Matrix3::new(right, up, z));
+
+        let view = camera.reversed();
+        Self::from_rot3_translation(view, view * -eye)
+    }
+
+    // Projects a frustum with vertical field of view fov_y onto clip space
+    pub fn perspective(
+        fov_y: T,
+        aspect: T,
+        near: T,
+        far: T,
+        hand: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (a, b): (T, T) = depth.near_far();
+        let z_scale = (b * far - a * near) / (far - near);
+        let z_offset = (a - b) * near * far / (far - near);
+        Self::perspective_with_depth(fov_y, aspect, z_scale, z_offset, hand)
+    }
+
+    // A perspective projection with the far plane at infinity
+    pub fn perspective_infinite(
+        fov_y: T,
+        aspect: T,
+        near: T,
+        hand: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (a, b): (T, T) = depth.near_far();
+        Self::perspective_with_depth(fov_y, aspect, b, (a - b) * near, hand)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn orthographic(
+        left: T,
+        right: T,
+        bottom: T,
+        top: T,
+        near: T,
+        far: T,
+        hand: Handedness,
+        depth: DepthRange,
+    ) -> Self {
+        let (a, b): (T, T) = depth.near_far();
+        let forward: T = hand.forward_sign();
+        let (width, height, depth) = (right - left, top - bottom, far - near);
+        Self::new(
+            Vector4::new(T::two() / width, T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::two() / height, T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), forward * (b - a) / depth, T::zero()),
+            Vector4::new(
+                -(right + left) / width,
+                -(top + bottom) / height,
+                a - (b - a) * near / depth,
+                T::one(),
+            ),
+        )
+    }
+
+    // Clip space z is z_scale * d + z_offset and w is d, for the depth d in
+    // front of the camera
+    fn perspective_with_depth(
+        fov_y: T,
+        aspect: T,
+        z_scale: T,
+        z_offset: T,
+        hand: Handedness,
+    ) -> Self {
+        let focal = T::one() / (fov_y * T::half()).tan();
+        let forward: T = hand.forward_sign();
+        Self::new(
+            Vector4::new(focal / aspect, T::zero(), T::zero(), T::zero()),
+            Vector4::new(T::zero(), focal, T::zero(), T::zero()),
+            Vector4::new(T::zero(), T::zero(), forward * z_scale, forward),
+            Vector4::new(T::zero(), T::zero(), z_offset, T::zero()),
+        )
+    }
+}
+
+impl<T: Scalar> Add for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            cols: [
+                self.cols[0] + rhs.cols[0],
+                self.cols[1] + rhs.cols[1],
+                self.cols[2] + rhs.cols[2],
+                self.cols[3] + rhs.cols[3],
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> AddAssign for Matrix4<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.cols[0] += rhs.cols[0];
+        self.cols[1] += rhs.cols[1];
+        self.cols[2] += rhs.cols[2];
+        self.cols[3] += rhs.cols[3];
+    }
+}
+
+impl<T: Scalar> Sub for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self {
+            cols: [
+                self.cols[0] - rhs.cols[0],
+                self.cols[1] - rhs.cols[1],
+                self.cols[2] - rhs.cols[2],
+                self.cols[3] - rhs.cols[3],
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> SubAssign for Matrix4<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.cols[0] -= rhs.cols[0];
+        self.cols[1] -= rhs.cols[1];
+        self.cols[2] -= rhs.cols[2];
+        self.cols[3] -= rhs.cols[3];
+    }
+}
+
+impl<T: Scalar> Neg for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            cols: [-self.cols[0], -self.cols[1], -self.cols[2], -self.cols[3]],
+        }
+    }
+}
+
+impl<T: Scalar> Mul for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            cols: [
+                self * rhs.cols[0],
+                self * rhs.cols[1],
+                self * rhs.cols[2],
+                self * rhs.cols[3],
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Matrix4<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<Vector4<T>> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector4<T>) -> Vector4<T> {
+        self.cols[0] * rhs.x + self.cols[1] * rhs.y + self.cols[2] * rhs.z + self.cols[3] * rhs.w
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self {
+            cols: [
+                self.cols[0] * rhs,
+                self.cols[1] * rhs,
+                self.cols[2] * rhs,
+                self.cols[3] * rhs,
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Matrix4<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.cols[0] *= rhs;
+        self.cols[1] *= rhs;
+        self.cols[2] *= rhs;
+        self.cols[3] *= rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Matrix4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self {
+            cols: [
+                self.cols[0] / rhs,
+                self.cols[1] / rhs,
+                self.cols[2] / rhs,
+                self.cols[3] / rhs,
+            ],
+        }
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Matrix4<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.cols[0] /= rhs;
+        self.cols[1] /= rhs;
+        self.cols[2] /= rhs;
+        self.cols[3] /= rhs;
+    }
+}
+
+impl<T> Index<usize> for Matrix4<T> {
+    type Output = Vector4<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.cols[0],
+            1 => &self.cols[1],
+            2 => &self.cols[2],
+            3 => &self.cols[3],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Matrix4<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.cols[0],
+            1 => &mut self.cols[1],
+            2 => &mut self.cols[2],
+            3 => &mut self.cols[3],
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
+
+impl<T: Scalar> From<[[T; 4]; 4]> for Matrix4<T> {
+    #[inline]
+    fn from(cols: [[T; 4]; 4]) -> Self {
+        Self {
+            cols: [
+                cols[0].into(),
+                cols[1].into(),
+                cols[2].into(),
+                cols[3].into(),
+            ],
+        }
+    }
+}
+
+impl<T> From<Matrix4<T>> for [[T; 4]; 4] {
+    #[inline]
+    fn from(m: Matrix4<T>) -> Self {
+        let [c0, c1, c2, c3] = m.cols;
+        [c0.into(), c1.into(), c2.into(), c3.into()]
+    }
+}
+
+macro_rules! impl_scalar_mat4 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Matrix4<$t>> for $t {
+                type Output = Matrix4<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Matrix4<$t>) -> Matrix4<$t> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_mat4![f32, f64];
+
+impl From<Mat4> for DMat4 {
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        Self::new(
+            m.cols[0].into(),
+            m.cols[1].into(),
+            m.cols[2].into(),
+            m.cols[3].into(),
+        )
+    }
+}
+
+impl Mat4 {
+    #[inline]
+    pub fn as_dmat4(&self) -> DMat4 {
+        DMat4::new(
+            self.cols[0].into(),
+            self.cols[1].into(),
+            self.cols[2].into(),
+            self.cols[3].into(),
+        )
+    }
+}
+
+impl DMat4 {
+    #[inline]
+    pub fn as_mat4(&self) -> Mat4 {
+        Mat4::new(
+            self.cols[0].as_vec4(),
+            self.cols[1].as_vec4(),
+            self.cols[2].as_vec4(),
+            self.cols[3].as_vec4(),
+        )
+    }
+}
@@ -0,0 +1,125 @@
+use crate::*;
+
+impl<T: Scalar> From<Vector2<T>> for mint::Vector2<T> {
+    #[inline]
+    fn from(v: Vector2<T>) -> Self {
+        Self { x: v.x, y: v.y }
+    }
+}
+
+impl<T: Scalar> From<mint::Vector2<T>> for Vector2<T> {
+    #[inline]
+    fn from(v: mint::Vector2<T>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for mint::Vector3<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::Vector3<T>> for Vector3<T> {
+    #[inline]
+    fn from(v: mint::Vector3<T>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<Vector4<T>> for mint::Vector4<T> {
+    #[inline]
+    fn from(v: Vector4<T>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::Vector4<T>> for Vector4<T> {
+    #[inline]
+    fn from(v: mint::Vector4<T>) -> Self {
+        Self::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+// Follows the convention of Rotor3::to_quaternion
+impl<T: Scalar> From<Rotor3<T>> for mint::Quaternion<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        let q = r.to_quaternion();
+        Self {
+            v: Vector3::new(q.x, q.y, q.z).into(),
+            s: q.w,
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::Quaternion<T>> for Rotor3<T> {
+    #[inline]
+    fn from(q: mint::Quaternion<T>) -> Self {
+        Self::from_quaternion(Vector4::new(q.v.x, q.v.y, q.v.z, q.s))
+    }
+}
+
+impl<T: Scalar> From<Matrix2<T>> for mint::ColumnMatrix2<T> {
+    #[inline]
+    fn from(m: Matrix2<T>) -> Self {
+        Self {
+            x: m.cols[0].into(),
+            y: m.cols[1].into(),
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::ColumnMatrix2<T>> for Matrix2<T> {
+    #[inline]
+    fn from(m: mint::ColumnMatrix2<T>) -> Self {
+        Self::new(m.x.into(), m.y.into())
+    }
+}
+
+impl<T: Scalar> From<Matrix3<T>> for mint::ColumnMatrix3<T> {
+    #[inline]
+    fn from(m: Matrix3<T>) -> Self {
+        Self {
+            x: m.cols[0].into(),
+            y: m.cols[1].into(),
+            z: m.cols[2].into(),
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::ColumnMatrix3<T>> for Matrix3<T> {
+    #[inline]
+    fn from(m: mint::ColumnMatrix3<T>) -> Self {
+        Self::new(m.x.into(), m.y.into(), m.z.into())
+    }
+}
+
+impl<T: Scalar> From<Matrix4<T>> for mint::ColumnMatrix4<T> {
+    #[inline]
+    fn from(m: Matrix4<T>) -> Self {
+        Self {
+            x: m.cols[0].into(),
+            y: m.cols[1].into(),
+            z: m.cols[2].into(),
+            w: m.cols[3].into(),
+        }
+    }
+}
+
+impl<T: Scalar> From<mint::ColumnMatrix4<T>> for Matrix4<T> {
+    #[inline]
+    fn from(m: mint::ColumnMatrix4<T>) -> Self {
+        Self::new(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+    }
+}
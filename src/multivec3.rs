@@ -0,0 +1,450 @@
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Multivector3<T> {
+    pub s: T,
+    pub v: Vector3<T>,
+    pub bv: Bivector3<T>,
+    pub tv: Trivector3<T>,
+}
+
+pub type Multivec3 = Multivector3<f32>;
+pub type DMultivec3 = Multivector3<f64>;
+
+impl<T: Scalar> Multivector3<T> {
+    #[inline]
+    pub fn new(s: T, v: Vector3<T>, bv: Bivector3<T>, tv: Trivector3<T>) -> Self {
+        Self { s, v, bv, tv }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(
+            T::zero(),
+            Vector3::zero(),
+            Bivector3::zero(),
+            Trivector3::zero(),
+        )
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::from(T::one())
+    }
+
+    #[inline]
+    pub fn mag(&self) -> T {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    pub fn mag_sq(&self) -> T {
+        self.scalar_product(self.reversed())
+    }
+
+    // The part of this multivector of the given grade, zero above grade 3
+    #[inline]
+    pub fn grade(&self, k: usize) -> Self {
+        let mut m = Self::zero();
+        match k {
+            0 => m.s = self.s,
+            1 => m.v = self.v,
+            2 => m.bv = self.bv,
+            3 => m.tv = self.tv,
+            _ => {}
+        }
+        m
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.bv = -self.bv;
+        self.tv = -self.tv;
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut m = *self;
+        m.reverse();
+        m
+    }
+
+    #[inline]
+    pub fn involute(&mut self) {
+        self.v = -self.v;
+        self.tv = -self.tv;
+    }
+
+    #[inline]
+    pub fn involuted(&self) -> Self {
+        let mut m = *self;
+        m.involute();
+        m
+    }
+
+    #[inline]
+    pub fn conjugate(&mut self) {
+        self.v = -self.v;
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    pub fn conjugated(&self) -> Self {
+        let mut m = *self;
+        m.conjugate();
+        m
+    }
+
+    // Maps each basis blade to its complement, 1 <-> xyz, x <-> yz, y <-> -xz, z <-> xy
+    #[inline]
+    pub fn dual(&self) -> Self {
+        Self::new(
+            self.tv.dual(),
+            self.bv.dual(),
+            self.v.dual(),
+            Trivector3::new(self.s),
+        )
+    }
+
+    pub fn try_inverse(&self) -> Option<Self> {
+        // self * self.conjugated() only has scalar and pseudoscalar parts, which commute
+        // with everything, so it can be inverted like a complex number.
+        let conj = self.conjugated();
+        let n = *self * conj;
+        let den = (n.s * n.s) + (n.tv.xyz * n.tv.xyz);
+        if den == T::zero() {
+            return None;
+        }
+
+        let n_inv = Self::new(
+            n.s / den,
+            Vector3::zero(),
+            Bivector3::zero(),
+            Trivector3::new(-n.tv.xyz / den),
+        );
+        Some(conj * n_inv)
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+
+    #[inline]
+    pub fn scalar_product(&self, other: Self) -> T {
+        let (a, b) = (self, other);
+        (a.s * b.s) + (a.v.x * b.v.x) + (a.v.y * b.v.y) + (a.v.z * b.v.z)
+            - (a.bv.xy * b.bv.xy)
+            - (a.bv.xz * b.bv.xz)
+            - (a.bv.yz * b.bv.yz)
+            - (a.tv.xyz * b.tv.xyz)
+    }
+
+    pub fn wedge(&self, other: Self) -> Self {
+        let (a, b) = (self, other);
+        Self::new(
+            a.s * b.s,
+            Vector3::new(
+                (a.s * b.v.x) + (a.v.x * b.s),
+                (a.s * b.v.y) + (a.v.y * b.s),
+                (a.s * b.v.z) + (a.v.z * b.s),
+            ),
+            Bivector3::new(
+                (a.s * b.bv.xy) + (a.v.x * b.v.y) - (a.v.y * b.v.x) + (a.bv.xy * b.s),
+                (a.s * b.bv.xz) + (a.v.x * b.v.z) - (a.v.z * b.v.x) + (a.bv.xz * b.s),
+                (a.s * b.bv.yz) + (a.v.y * b.v.z) - (a.v.z * b.v.y) + (a.bv.yz * b.s),
+            ),
+            Trivector3::new(
+                (a.s * b.tv.xyz) + (a.v.x * b.bv.yz) - (a.v.y * b.bv.xz)
+                    + (a.v.z * b.bv.xy)
+                    + (a.bv.xy * b.v.z)
+                    - (a.bv.xz * b.v.y)
+                    + (a.bv.yz * b.v.x)
+                    + (a.tv.xyz * b.s),
+            ),
+        )
+    }
+
+    // The meet of the two multivectors, the dual of the wedge of their duals
+    #[inline]
+    pub fn regressive(&self, other: Self) -> Self {
+        self.dual().wedge(other.dual()).dual()
+    }
+
+    pub fn left_contract(&self, other: Self) -> Self {
+        let (a, b) = (self, other);
+        Self::new(
+            self.scalar_product(other),
+            Vector3::new(
+                (a.s * b.v.x) - (a.v.y * b.bv.xy) - (a.v.z * b.bv.xz) - (a.bv.yz * b.tv.xyz),
+                (a.s * b.v.y) + (a.v.x * b.bv.xy) - (a.v.z * b.bv.yz) + (a.bv.xz * b.tv.xyz),
+                (a.s * b.v.z) + (a.v.x * b.bv.xz) + (a.v.y * b.bv.yz) - (a.bv.xy * b.tv.xyz),
+            ),
+            Bivector3::new(
+                (a.s * b.bv.xy) + (a.v.z * b.tv.xyz),
+                (a.s * b.bv.xz) - (a.v.y * b.tv.xyz),
+                (a.s * b.bv.yz) + (a.v.x * b.tv.xyz),
+            ),
+            Trivector3::new(a.s * b.tv.xyz),
+        )
+    }
+
+    pub fn right_contract(&self, other: Self) -> Self {
+        let (a, b) = (self, other);
+        Self::new(
+            self.scalar_product(other),
+            Vector3::new(
+                (a.v.x * b.s) + (a.bv.xy * b.v.y) + (a.bv.xz * b.v.z) - (a.tv.xyz * b.bv.yz),
+                (a.v.y * b.s) - (a.bv.xy * b.v.x) + (a.bv.yz * b.v.z) + (a.tv.xyz * b.bv.xz),
+                (a.v.z * b.s) - (a.bv.xz * b.v.x) - (a.bv.yz * b.v.y) - (a.tv.xyz * b.bv.xy),
+            ),
+            Bivector3::new(
+                (a.bv.xy * b.s) + (a.tv.xyz * b.v.z),
+                (a.bv.xz * b.s) - (a.tv.xyz * b.v.y),
+                (a.bv.yz * b.s) + (a.tv.xyz * b.v.x),
+            ),
+            Trivector3::new(a.tv.xyz * b.s),
+        )
+    }
+}
+
+impl<T: Scalar> Add for Multivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.s + rhs.s,
+            self.v + rhs.v,
+            self.bv + rhs.bv,
+            self.tv + rhs.tv,
+        )
+    }
+}
+
+impl<T: Scalar> AddAssign for Multivector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.s += rhs.s;
+        self.v += rhs.v;
+        self.bv += rhs.bv;
+        self.tv += rhs.tv;
+    }
+}
+
+impl<T: Scalar> Sub for Multivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.s - rhs.s,
+            self.v - rhs.v,
+            self.bv - rhs.bv,
+            self.tv - rhs.tv,
+        )
+    }
+}
+
+impl<T: Scalar> SubAssign for Multivector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.s -= rhs.s;
+        self.v -= rhs.v;
+        self.bv -= rhs.bv;
+        self.tv -= rhs.tv;
+    }
+}
+
+impl<T: Scalar> Neg for Multivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.s, -self.v, -self.bv, -self.tv)
+    }
+}
+
+impl<T: Scalar> Mul for Multivector3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self::new(
+            a.scalar_product(b),
+            Vector3::new(
+                (a.s * b.v.x) + (a.v.x * b.s) - (a.v.y * b.bv.xy) - (a.v.z * b.bv.xz)
+                    + (a.bv.xy * b.v.y)
+                    + (a.bv.xz * b.v.z)
+                    - (a.bv.yz * b.tv.xyz)
+                    - (a.tv.xyz * b.bv.yz),
+                (a.s * b.v.y) + (a.v.x * b.bv.xy) + (a.v.y * b.s)
+                    - (a.v.z * b.bv.yz)
+                    - (a.bv.xy * b.v.x)
+                    + (a.bv.xz * b.tv.xyz)
+                    + (a.bv.yz * b.v.z)
+                    + (a.tv.xyz * b.bv.xz),
+                (a.s * b.v.z) + (a.v.x * b.bv.xz) + (a.v.y * b.bv.yz) + (a.v.z * b.s)
+                    - (a.bv.xy * b.tv.xyz)
+                    - (a.bv.xz * b.v.x)
+                    - (a.bv.yz * b.v.y)
+                    - (a.tv.xyz * b.bv.xy),
+            ),
+            Bivector3::new(
+                (a.s * b.bv.xy) + (a.v.x * b.v.y) - (a.v.y * b.v.x)
+                    + (a.v.z * b.tv.xyz)
+                    + (a.bv.xy * b.s)
+                    - (a.bv.xz * b.bv.yz)
+                    + (a.bv.yz * b.bv.xz)
+                    + (a.tv.xyz * b.v.z),
+                (a.s * b.bv.xz) + (a.v.x * b.v.z) - (a.v.y * b.tv.xyz) - (a.v.z * b.v.x)
+                    + (a.bv.xy * b.bv.yz)
+                    + (a.bv.xz * b.s)
+                    - (a.bv.yz * b.bv.xy)
+                    - (a.tv.xyz * b.v.y),
+                (a.s * b.bv.yz) + (a.v.x * b.tv.xyz) + (a.v.y * b.v.z)
+                    - (a.v.z * b.v.y)
+                    - (a.bv.xy * b.bv.xz)
+                    + (a.bv.xz * b.bv.xy)
+                    + (a.bv.yz * b.s)
+                    + (a.tv.xyz * b.v.x),
+            ),
+            Trivector3::new(
+                (a.s * b.tv.xyz) + (a.v.x * b.bv.yz) - (a.v.y * b.bv.xz)
+                    + (a.v.z * b.bv.xy)
+                    + (a.bv.xy * b.v.z)
+                    - (a.bv.xz * b.v.y)
+                    + (a.bv.yz * b.v.x)
+                    + (a.tv.xyz * b.s),
+            ),
+        )
+    }
+}
+
+impl<T: Scalar> MulAssign for Multivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Multivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.s * rhs, self.v * rhs, self.bv * rhs, self.tv * rhs)
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Multivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.s *= rhs;
+        self.v *= rhs;
+        self.bv *= rhs;
+        self.tv *= rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Multivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.s / rhs, self.v / rhs, self.bv / rhs, self.tv / rhs)
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Multivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.s /= rhs;
+        self.v /= rhs;
+        self.bv /= rhs;
+        self.tv /= rhs;
+    }
+}
+
+impl<T: Scalar> From<T> for Multivector3<T> {
+    #[inline]
+    fn from(s: T) -> Self {
+        Self::new(s, Vector3::zero(), Bivector3::zero(), Trivector3::zero())
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for Multivector3<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Self::new(T::zero(), v, Bivector3::zero(), Trivector3::zero())
+    }
+}
+
+impl<T: Scalar> From<Bivector3<T>> for Multivector3<T> {
+    #[inline]
+    fn from(bv: Bivector3<T>) -> Self {
+        Self::new(T::zero(), Vector3::zero(), bv, Trivector3::zero())
+    }
+}
+
+impl<T: Scalar> From<Trivector3<T>> for Multivector3<T> {
+    #[inline]
+    fn from(tv: Trivector3<T>) -> Self {
+        Self::new(T::zero(), Vector3::zero(), Bivector3::zero(), tv)
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for Multivector3<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::new(r.s, Vector3::zero(), r.bv, Trivector3::zero())
+    }
+}
+
+macro_rules! impl_scalar_multivec3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Multivector3<$t>> for $t {
+                type Output = Multivector3<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Multivector3<$t>) -> Multivector3<$t> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_multivec3![f32, f64];
+
+impl From<Multivec3> for DMultivec3 {
+    #[inline]
+    fn from(m: Multivec3) -> Self {
+        Self::new(f64::from(m.s), m.v.into(), m.bv.into(), m.tv.into())
+    }
+}
+
+impl Multivec3 {
+    #[inline]
+    pub fn as_dmultivec3(&self) -> DMultivec3 {
+        DMultivec3::from(*self)
+    }
+}
+
+impl DMultivec3 {
+    #[inline]
+    pub fn as_multivec3(&self) -> Multivec3 {
+        Multivec3::new(
+            self.s as f32,
+            self.v.as_vec3(),
+            self.bv.as_bivec3(),
+            self.tv.as_trivec3(),
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use crate::*;
+
+macro_rules! impl_nalgebra {
+    [$($t:ident), +] => {
+        $(
+            impl From<Vector2<$t>> for nalgebra::Vector2<$t> {
+                #[inline]
+                fn from(v: Vector2<$t>) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+
+            impl From<nalgebra::Vector2<$t>> for Vector2<$t> {
+                #[inline]
+                fn from(v: nalgebra::Vector2<$t>) -> Self {
+                    Self::new(v.x, v.y)
+                }
+            }
+
+            impl From<Vector3<$t>> for nalgebra::Vector3<$t> {
+                #[inline]
+                fn from(v: Vector3<$t>) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<nalgebra::Vector3<$t>> for Vector3<$t> {
+                #[inline]
+                fn from(v: nalgebra::Vector3<$t>) -> Self {
+                    Self::new(v.x, v.y, v.z)
+                }
+            }
+
+            impl From<Vector4<$t>> for nalgebra::Vector4<$t> {
+                #[inline]
+                fn from(v: Vector4<$t>) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+
+            impl From<nalgebra::Vector4<$t>> for Vector4<$t> {
+                #[inline]
+                fn from(v: nalgebra::Vector4<$t>) -> Self {
+                    Self::new(v.x, v.y, v.z, v.w)
+                }
+            }
+
+            // Follows the convention of Rotor3::to_quaternion. Rotors drift off
+            // unit length, so this normalises rather than trusting the input.
+            impl From<Rotor3<$t>> for nalgebra::UnitQuaternion<$t> {
+                #[inline]
+                fn from(r: Rotor3<$t>) -> Self {
+                    let q = r.to_quaternion();
+                    Self::from_quaternion(nalgebra::Quaternion::new(q.w, q.x, q.y, q.z))
+                }
+            }
+
+            impl From<nalgebra::UnitQuaternion<$t>> for Rotor3<$t> {
+                #[inline]
+                fn from(q: nalgebra::UnitQuaternion<$t>) -> Self {
+                    Self::from_quaternion(q.coords.into())
+                }
+            }
+
+            impl From<Matrix2<$t>> for nalgebra::Matrix2<$t> {
+                #[inline]
+                fn from(m: Matrix2<$t>) -> Self {
+                    Self::from_columns(&[m.cols[0].into(), m.cols[1].into()])
+                }
+            }
+
+            impl From<nalgebra::Matrix2<$t>> for Matrix2<$t> {
+                #[inline]
+                fn from(m: nalgebra::Matrix2<$t>) -> Self {
+                    Self::new(m.column(0).into_owned().into(), m.column(1).into_owned().into())
+                }
+            }
+
+            impl From<Matrix3<$t>> for nalgebra::Matrix3<$t> {
+                #[inline]
+                fn from(m: Matrix3<$t>) -> Self {
+                    Self::from_columns(&[m.cols[0].into(), m.cols[1].into(), m.cols[2].into()])
+                }
+            }
+
+            impl From<nalgebra::Matrix3<$t>> for Matrix3<$t> {
+                #[inline]
+                fn from(m: nalgebra::Matrix3<$t>) -> Self {
+                    Self::new(
+                        m.column(0).into_owned().into(),
+                        m.column(1).into_owned().into(),
+                        m.column(2).into_owned().into(),
+                    )
+                }
+            }
+
+            impl From<Matrix4<$t>> for nalgebra::Matrix4<$t> {
+                #[inline]
+                fn from(m: Matrix4<$t>) -> Self {
+                    Self::from_columns(&[
+                        m.cols[0].into(),
+                        m.cols[1].into(),
+                        m.cols[2].into(),
+                        m.cols[3].into(),
+                    ])
+                }
+            }
+
+            impl From<nalgebra::Matrix4<$t>> for Matrix4<$t> {
+                #[inline]
+                fn from(m: nalgebra::Matrix4<$t>) -> Self {
+                    Self::new(
+                        m.column(0).into_owned().into(),
+                        m.column(1).into_owned().into(),
+                        m.column(2).into_owned().into(),
+                        m.column(3).into_owned().into(),
+                    )
+                }
+            }
+        )+
+    };
+}
+
+impl_nalgebra![f32, f64];
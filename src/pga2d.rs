@@ -0,0 +1,419 @@
+// 2D projective geometric algebra, Cl(2,0,1) with e0 * e0 = 0. Lines are
+// vectors and points are bivectors, so two points join into a line with the
+// regressive product and two lines meet in a point with the outer product.
+use crate::*;
+use std::ops::*;
+
+// The bivector e20 * x + e01 * y + e12 * w, the point (x / w, y / w), or a
+// direction when w is zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Point2<T> {
+    pub e20: T,
+    pub e01: T,
+    pub e12: T,
+}
+
+// The vector e1 * a + e2 * b + e0 * c, the line a * x + b * y + c = 0
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Line2<T> {
+    pub e1: T,
+    pub e2: T,
+    pub e0: T,
+}
+
+// A rotation followed by a translation, applied with the sandwich m * x * m.reversed()
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Motor2<T> {
+    pub s: T,
+    pub e12: T,
+    pub e20: T,
+    pub e01: T,
+}
+
+impl<T: Scalar> Point2<T> {
+    #[inline]
+    pub fn new(x: T, y: T) -> Self {
+        Self::new_homogeneous(x, y, T::one())
+    }
+
+    #[inline]
+    pub fn new_homogeneous(x: T, y: T, w: T) -> Self {
+        Self {
+            e20: x,
+            e01: y,
+            e12: w,
+        }
+    }
+
+    // The point at infinity in the given direction
+    #[inline]
+    pub fn direction(x: T, y: T) -> Self {
+        Self::new_homogeneous(x, y, T::zero())
+    }
+
+    #[inline]
+    pub fn is_direction(&self) -> bool {
+        self.e12 == T::zero()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        *self /= self.e12;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut p = *self;
+        p.normalize();
+        p
+    }
+
+    #[inline]
+    pub fn to_vec2(&self) -> Vector2<T> {
+        Vector2::new(self.e20 / self.e12, self.e01 / self.e12)
+    }
+
+    // The line through both points, self & other
+    #[inline]
+    pub fn join(&self, other: Self) -> Line2<T> {
+        Line2 {
+            e1: (self.e01 * other.e12) - (other.e01 * self.e12),
+            e2: (self.e12 * other.e20) - (other.e12 * self.e20),
+            e0: (self.e20 * other.e01) - (other.e20 * self.e01),
+        }
+    }
+
+    #[inline]
+    pub fn distance(&self, other: Self) -> T {
+        self.normalized().join(other.normalized()).norm()
+    }
+
+    // The motor rotating by this point's weight times -2 about it, the inverse
+    // of Motor2::log. Directions exponentiate to translations.
+    #[inline]
+    pub fn exp(&self) -> Motor2<T> {
+        let angle = self.e12.abs();
+        if angle <= T::epsilon() {
+            // sin(angle) / angle tends to one
+            return Motor2::new(angle.cos(), self.e12, self.e20, self.e01);
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        let p = *self * (sin / angle);
+        Motor2::new(cos, p.e12, p.e20, p.e01)
+    }
+}
+
+impl<T: Scalar> Line2<T> {
+    #[inline]
+    pub fn new(a: T, b: T, c: T) -> Self {
+        Self {
+            e1: a,
+            e2: b,
+            e0: c,
+        }
+    }
+
+    #[inline]
+    pub fn through(a: Point2<T>, b: Point2<T>) -> Self {
+        a.join(b)
+    }
+
+    // The point where both lines cross, self ^ other. Parallel lines meet at a direction.
+    #[inline]
+    pub fn meet(&self, other: Self) -> Point2<T> {
+        Point2::new_homogeneous(
+            (self.e2 * other.e0) - (other.e2 * self.e0),
+            (other.e1 * self.e0) - (self.e1 * other.e0),
+            (self.e1 * other.e2) - (other.e1 * self.e2),
+        )
+    }
+
+    #[inline]
+    pub fn normal(&self) -> Vector2<T> {
+        Vector2::new(self.e1, self.e2)
+    }
+
+    #[inline]
+    pub fn norm(&self) -> T {
+        ((self.e1 * self.e1) + (self.e2 * self.e2)).sqrt()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        *self /= norm;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut l = *self;
+        l.normalize();
+        l
+    }
+
+    // Positive on the side the normal points to
+    #[inline]
+    pub fn signed_distance(&self, p: Point2<T>) -> T {
+        ((self.e1 * p.e20) + (self.e2 * p.e01) + (self.e0 * p.e12)) / (p.e12 * self.norm())
+    }
+
+    #[inline]
+    pub fn angle_between(&self, other: Self) -> T {
+        let dot = (self.e1 * other.e1) + (self.e2 * other.e2);
+        (dot / (self.norm() * other.norm())).acos()
+    }
+}
+
+impl<T: Scalar> Motor2<T> {
+    #[inline]
+    pub fn new(s: T, e12: T, e20: T, e01: T) -> Self {
+        Self { s, e12, e20, e01 }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), T::zero(), T::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn from_rotation(angle: T) -> Self {
+        Self::from_rot2(Rotor2::from_angle(angle))
+    }
+
+    #[inline]
+    pub fn from_rotation_about(angle: T, center: Point2<T>) -> Self {
+        (center.normalized() * (-angle / T::two())).exp()
+    }
+
+    #[inline]
+    pub fn from_translation(t: Vector2<T>) -> Self {
+        Self::new(T::one(), T::zero(), t.y / T::two(), -t.x / T::two())
+    }
+
+    #[inline]
+    pub fn from_rot2(r: Rotor2<T>) -> Self {
+        Self::new(r.s, r.bv.xy, T::zero(), T::zero())
+    }
+
+    // Rotates by r and then translates by t
+    #[inline]
+    pub fn from_rot2_translation(r: Rotor2<T>, t: Vector2<T>) -> Self {
+        Self::from_translation(t) * Self::from_rot2(r)
+    }
+
+    #[inline]
+    pub fn to_rot2_translation(&self) -> (Rotor2<T>, Vector2<T>) {
+        let mag_sq = self.mag_sq();
+        let r = Rotor2::new(self.s, Bivector2::new(self.e12)) / mag_sq.sqrt();
+
+        // Where the origin ends up
+        let t = Vector2::new(
+            T::two() * ((self.e12 * self.e20) - (self.s * self.e01)),
+            T::two() * ((self.e01 * self.e12) + (self.e20 * self.s)),
+        ) / mag_sq;
+        (r, t)
+    }
+
+    #[inline]
+    pub fn mag(&self) -> T {
+        self.mag_sq().sqrt()
+    }
+
+    // The weight, self * self.reversed()
+    #[inline]
+    pub fn mag_sq(&self) -> T {
+        (self.s * self.s) + (self.e12 * self.e12)
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut m = *self;
+        m.normalize();
+        m
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.e12 = -self.e12;
+        self.e20 = -self.e20;
+        self.e01 = -self.e01;
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut m = *self;
+        m.reverse();
+        m
+    }
+
+    #[inline]
+    pub fn log(&self) -> Point2<T> {
+        let bv = Point2::new_homogeneous(self.e20, self.e01, self.e12);
+        let sin = self.e12.abs();
+        if sin <= T::epsilon() {
+            // atan2(sin, s) / sin tends to 1 / s, which also picks the short way
+            // round when s is negative.
+            return bv / self.s;
+        }
+
+        bv * (sin.atan2(self.s) / sin)
+    }
+
+    #[inline]
+    pub fn powf(&self, t: T) -> Self {
+        (self.log() * t).exp()
+    }
+
+    #[inline]
+    pub fn interpolate(&self, to: Self, t: T) -> Self {
+        *self * (self.reversed() * to).powf(t)
+    }
+}
+
+macro_rules! impl_ops_pga2d {
+    [$ty:ident: $($f:ident), +] => {
+        impl<T: Scalar> Add for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($f: self.$f + rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Sub for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($f: self.$f - rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Neg for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self { $($f: self.$f * rhs), + }
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $ty<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$f *= rhs;)+
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self { $($f: self.$f / rhs), + }
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $ty<T> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$f /= rhs;)+
+            }
+        }
+    };
+}
+
+impl_ops_pga2d![Point2: e20, e01, e12];
+impl_ops_pga2d![Line2: e1, e2, e0];
+impl_ops_pga2d![Motor2: s, e12, e20, e01];
+
+impl<T: Scalar> Mul for Motor2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            s: (self.s * rhs.s) - (self.e12 * rhs.e12),
+            e12: (self.e12 * rhs.s) + (self.s * rhs.e12),
+            e20: (self.e20 * rhs.s) + (self.s * rhs.e20) + (self.e12 * rhs.e01)
+                - (self.e01 * rhs.e12),
+            e01: (self.e01 * rhs.s) + (self.s * rhs.e01) + (self.e20 * rhs.e12)
+                - (self.e12 * rhs.e20),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Point2<T>> for Motor2<T> {
+    type Output = Point2<T>;
+
+    #[inline]
+    fn mul(self, p: Point2<T>) -> Point2<T> {
+        let two = T::two();
+        let s2 = self.s * self.s;
+        let e12_2 = self.e12 * self.e12;
+        let s_e12 = two * self.s * self.e12;
+
+        Point2::new_homogeneous(
+            (s2 - e12_2) * p.e20
+                + s_e12 * p.e01
+                + two * ((self.e12 * self.e20) - (self.s * self.e01)) * p.e12,
+            (s2 - e12_2) * p.e01 - s_e12 * p.e20
+                + two * ((self.e01 * self.e12) + (self.e20 * self.s)) * p.e12,
+            (s2 + e12_2) * p.e12,
+        )
+    }
+}
+
+impl<T: Scalar> Mul<Line2<T>> for Motor2<T> {
+    type Output = Line2<T>;
+
+    #[inline]
+    fn mul(self, l: Line2<T>) -> Line2<T> {
+        let two = T::two();
+        let s2 = self.s * self.s;
+        let e12_2 = self.e12 * self.e12;
+        let s_e12 = two * self.s * self.e12;
+
+        Line2::new(
+            (s2 - e12_2) * l.e1 + s_e12 * l.e2,
+            (s2 - e12_2) * l.e2 - s_e12 * l.e1,
+            (s2 + e12_2) * l.e0
+                + two * ((self.s * self.e01) + (self.e12 * self.e20)) * l.e1
+                + two * ((self.e01 * self.e12) - (self.s * self.e20)) * l.e2,
+        )
+    }
+}
+
+impl<T: Scalar> From<Vector2<T>> for Point2<T> {
+    #[inline]
+    fn from(v: Vector2<T>) -> Self {
+        Self::new(v.x, v.y)
+    }
+}
+
+impl<T: Scalar> From<Rotor2<T>> for Motor2<T> {
+    #[inline]
+    fn from(r: Rotor2<T>) -> Self {
+        Self::from_rot2(r)
+    }
+}
@@ -0,0 +1,788 @@
+// 3D projective geometric algebra, Cl(3,0,1) with e0 * e0 = 0. Planes are
+// vectors, lines are bivectors and points are trivectors, so points join into
+// lines and planes with the regressive product and planes meet in lines and
+// points with the outer product.
+use crate::*;
+use std::ops::*;
+
+// The vector e1 * a + e2 * b + e3 * c + e0 * d, the plane a * x + b * y + c * z + d = 0
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Plane3<T> {
+    pub e1: T,
+    pub e2: T,
+    pub e3: T,
+    pub e0: T,
+}
+
+// A bivector, the line with Plücker direction (e23, e31, e12) and moment (e01, e02, e03)
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Line3<T> {
+    pub e01: T,
+    pub e02: T,
+    pub e03: T,
+    pub e12: T,
+    pub e31: T,
+    pub e23: T,
+}
+
+// The trivector e032 * x + e013 * y + e021 * z + e123 * w, the point (x / w, y / w, z / w),
+// or a direction when w is zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Point3<T> {
+    pub e032: T,
+    pub e013: T,
+    pub e021: T,
+    pub e123: T,
+}
+
+// A rotation followed by a translation, applied with the sandwich m * x * m.reversed().
+// The scalar and e23, e31, e12 parts are a Rotor3.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Motor3<T> {
+    pub s: T,
+    pub e23: T,
+    pub e31: T,
+    pub e12: T,
+    pub e01: T,
+    pub e02: T,
+    pub e03: T,
+    pub e0123: T,
+}
+
+impl<T: Scalar> Plane3<T> {
+    #[inline]
+    pub fn new(a: T, b: T, c: T, d: T) -> Self {
+        Self {
+            e1: a,
+            e2: b,
+            e3: c,
+            e0: d,
+        }
+    }
+
+    #[inline]
+    pub fn from_point_normal(p: Vector3<T>, normal: Vector3<T>) -> Self {
+        Self::new(normal.x, normal.y, normal.z, -p.dot(normal))
+    }
+
+    #[inline]
+    pub fn through(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Self {
+        a.join(b).join_point(c)
+    }
+
+    #[inline]
+    pub fn normal(&self) -> Vector3<T> {
+        Vector3::new(self.e1, self.e2, self.e3)
+    }
+
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.normal().mag()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        *self /= norm;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut p = *self;
+        p.normalize();
+        p
+    }
+
+    // The line where both planes cross, self ^ other
+    #[inline]
+    pub fn meet(&self, other: Self) -> Line3<T> {
+        Line3 {
+            e01: (other.e1 * self.e0) - (other.e0 * self.e1),
+            e02: (other.e2 * self.e0) - (other.e0 * self.e2),
+            e03: (other.e3 * self.e0) - (other.e0 * self.e3),
+            e12: (other.e2 * self.e1) - (other.e1 * self.e2),
+            e31: (other.e1 * self.e3) - (other.e3 * self.e1),
+            e23: (other.e3 * self.e2) - (other.e2 * self.e3),
+        }
+    }
+
+    // Positive on the side the normal points to
+    #[inline]
+    pub fn signed_distance(&self, p: Point3<T>) -> T {
+        ((self.e1 * p.e032) + (self.e2 * p.e013) + (self.e3 * p.e021) + (self.e0 * p.e123))
+            / (p.e123 * self.norm())
+    }
+
+    #[inline]
+    pub fn angle_between(&self, other: Self) -> T {
+        self.normal().angle_between(other.normal())
+    }
+}
+
+impl<T: Scalar> Line3<T> {
+    #[inline]
+    pub fn from_plucker(direction: Vector3<T>, moment: Vector3<T>) -> Self {
+        Self {
+            e01: moment.x,
+            e02: moment.y,
+            e03: moment.z,
+            e12: direction.z,
+            e31: direction.y,
+            e23: direction.x,
+        }
+    }
+
+    #[inline]
+    pub fn from_point_direction(p: Vector3<T>, direction: Vector3<T>) -> Self {
+        Self::from_plucker(direction, p.cross(direction))
+    }
+
+    #[inline]
+    pub fn through(a: Point3<T>, b: Point3<T>) -> Self {
+        a.join(b)
+    }
+
+    #[inline]
+    pub fn direction(&self) -> Vector3<T> {
+        Vector3::new(self.e23, self.e31, self.e12)
+    }
+
+    #[inline]
+    pub fn moment(&self) -> Vector3<T> {
+        Vector3::new(self.e01, self.e02, self.e03)
+    }
+
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.direction().mag()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        *self /= norm;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut l = *self;
+        l.normalize();
+        l
+    }
+
+    // The point on the line closest to the origin
+    #[inline]
+    pub fn closest_to_origin(&self) -> Vector3<T> {
+        self.direction().cross(self.moment()) / self.direction().mag_sq()
+    }
+
+    // The plane containing the line and the point, self & p
+    #[inline]
+    pub fn join_point(&self, p: Point3<T>) -> Plane3<T> {
+        Plane3 {
+            e1: (p.e021 * self.e31) + (p.e123 * self.e01) - (p.e013 * self.e12),
+            e2: (p.e032 * self.e12) + (p.e123 * self.e02) - (p.e021 * self.e23),
+            e3: (p.e013 * self.e23) + (p.e123 * self.e03) - (p.e032 * self.e31),
+            e0: -(p.e013 * self.e02) - (p.e021 * self.e03) - (p.e032 * self.e01),
+        }
+    }
+
+    // The point where the line crosses the plane, self ^ p
+    #[inline]
+    pub fn meet_plane(&self, p: Plane3<T>) -> Point3<T> {
+        Point3 {
+            e032: (p.e2 * self.e03) - (p.e0 * self.e23) - (p.e3 * self.e02),
+            e013: (p.e3 * self.e01) - (p.e0 * self.e31) - (p.e1 * self.e03),
+            e021: (p.e1 * self.e02) - (p.e0 * self.e12) - (p.e2 * self.e01),
+            e123: (p.e1 * self.e23) + (p.e2 * self.e31) + (p.e3 * self.e12),
+        }
+    }
+
+    #[inline]
+    pub fn distance_to_point(&self, p: Point3<T>) -> T {
+        let p = p.to_vec3();
+        let to_p = p - self.closest_to_origin();
+        to_p.rejected(self.direction()).mag()
+    }
+
+    // The rotation by angle about this line, see Motor3::from_screw
+    #[inline]
+    pub fn exp(&self) -> Motor3<T> {
+        let u_sq = self.direction().mag_sq();
+        let u = u_sq.sqrt();
+        // self * self = -u^2 + e0123 * 2 * half_p
+        let half_p = self.direction().dot(self.moment());
+        if u <= T::epsilon() {
+            return Motor3::new(u.cos(), *self, half_p);
+        }
+
+        let (sin, cos) = u.sin_cos();
+        let sinc = sin / u;
+        let t = half_p * (cos - sinc) / u_sq;
+        let mut l = *self * sinc;
+        l.e01 += t * self.e23;
+        l.e02 += t * self.e31;
+        l.e03 += t * self.e12;
+        Motor3::new(cos, l, half_p * sinc)
+    }
+}
+
+impl<T: Scalar> Point3<T> {
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::new_homogeneous(x, y, z, T::one())
+    }
+
+    #[inline]
+    pub fn new_homogeneous(x: T, y: T, z: T, w: T) -> Self {
+        Self {
+            e032: x,
+            e013: y,
+            e021: z,
+            e123: w,
+        }
+    }
+
+    #[inline]
+    pub fn origin() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero())
+    }
+
+    // The point at infinity in the given direction
+    #[inline]
+    pub fn direction(x: T, y: T, z: T) -> Self {
+        Self::new_homogeneous(x, y, z, T::zero())
+    }
+
+    #[inline]
+    pub fn is_direction(&self) -> bool {
+        self.e123 == T::zero()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        *self /= self.e123;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut p = *self;
+        p.normalize();
+        p
+    }
+
+    #[inline]
+    pub fn to_vec3(&self) -> Vector3<T> {
+        Vector3::new(self.e032, self.e013, self.e021) / self.e123
+    }
+
+    // The line through both points, self & other, directed from self to other
+    #[inline]
+    pub fn join(&self, other: Self) -> Line3<T> {
+        Line3 {
+            e01: (other.e021 * self.e013) - (other.e013 * self.e021),
+            e02: (other.e032 * self.e021) - (other.e021 * self.e032),
+            e03: (other.e013 * self.e032) - (other.e032 * self.e013),
+            e12: (other.e021 * self.e123) - (other.e123 * self.e021),
+            e31: (other.e013 * self.e123) - (other.e123 * self.e013),
+            e23: (other.e032 * self.e123) - (other.e123 * self.e032),
+        }
+    }
+
+    #[inline]
+    pub fn distance(&self, other: Self) -> T {
+        self.normalized().join(other.normalized()).norm()
+    }
+}
+
+impl<T: Scalar> Motor3<T> {
+    #[inline]
+    pub fn new(s: T, l: Line3<T>, e0123: T) -> Self {
+        Self {
+            s,
+            e23: l.e23,
+            e31: l.e31,
+            e12: l.e12,
+            e01: l.e01,
+            e02: l.e02,
+            e03: l.e03,
+            e0123,
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), Line3::default(), T::zero())
+    }
+
+    #[inline]
+    pub fn from_rot3(r: Rotor3<T>) -> Self {
+        let mut m = Self::identity();
+        m.s = r.s;
+        m.e23 = r.bv.yz;
+        m.e31 = -r.bv.xz;
+        m.e12 = r.bv.xy;
+        m
+    }
+
+    #[inline]
+    pub fn from_translation(t: Vector3<T>) -> Self {
+        let mut m = Self::identity();
+        m.e01 = -t.x / T::two();
+        m.e02 = -t.y / T::two();
+        m.e03 = -t.z / T::two();
+        m
+    }
+
+    // Rotates by r and then translates by t
+    #[inline]
+    pub fn from_rot3_translation(r: Rotor3<T>, t: Vector3<T>) -> Self {
+        Self::from_translation(t) * Self::from_rot3(r)
+    }
+
+    // Rotates by angle about the axis, as Rotor3::from_axis_angle does about the
+    // origin, while sliding distance along the axis.
+    #[inline]
+    pub fn from_screw(axis: Line3<T>, angle: T, distance: T) -> Self {
+        let axis = axis.normalized();
+        let mut l = axis * (-angle / T::two());
+        let slide = distance / T::two();
+        l.e01 -= axis.e23 * slide;
+        l.e02 -= axis.e31 * slide;
+        l.e03 -= axis.e12 * slide;
+        l.exp()
+    }
+
+    // The axis, angle and distance with Motor3::from_screw(axis, angle, distance) == self.
+    // A pure translation has no axis, so it gets one through the origin.
+    #[inline]
+    pub fn to_screw(&self) -> (Line3<T>, T, T) {
+        let l = self.log();
+        let u = l.norm();
+        if u <= T::epsilon() {
+            let t = l.moment() * -T::two();
+            let distance = t.mag();
+            if distance == T::zero() {
+                return (
+                    Line3::from_plucker(Vector3::forward(), Vector3::zero()),
+                    T::zero(),
+                    T::zero(),
+                );
+            }
+
+            return (
+                Line3::from_plucker(t / distance, Vector3::zero()),
+                T::zero(),
+                distance,
+            );
+        }
+
+        // Split the moment into the part along the direction, which slides, and
+        // the part that places the axis.
+        let direction = -l.direction() / u;
+        let slide = -l.moment().dot(direction) / u;
+        let moment = -l.moment() / u - direction * slide;
+        let axis = Line3::from_plucker(direction, moment);
+        (axis, T::two() * u, T::two() * slide * u)
+    }
+
+    #[inline]
+    pub fn to_rot3_translation(&self) -> (Rotor3<T>, Vector3<T>) {
+        let r = Rotor3::new(self.s, Bivector3::new(self.e12, -self.e31, self.e23));
+        (r.normalized(), (*self * Point3::origin()).to_vec3())
+    }
+
+    pub fn from_matrix(m: &Matrix4<T>) -> Self {
+        let rot = Matrix3::new(m.cols[0].into(), m.cols[1].into(), m.cols[2].into());
+        Self::from_rot3_translation(Rotor3::from_matrix(&rot), m.cols[3].into())
+    }
+
+    pub fn into_matrix(self) -> Matrix4<T> {
+        let (r, t) = self.to_rot3_translation();
+        Matrix4::from_rot3_translation(r, t)
+    }
+
+    #[inline]
+    pub fn line(&self) -> Line3<T> {
+        Line3 {
+            e01: self.e01,
+            e02: self.e02,
+            e03: self.e03,
+            e12: self.e12,
+            e31: self.e31,
+            e23: self.e23,
+        }
+    }
+
+    #[inline]
+    pub fn mag(&self) -> T {
+        self.mag_sq().sqrt()
+    }
+
+    // The scalar part of self * self.reversed()
+    #[inline]
+    pub fn mag_sq(&self) -> T {
+        (self.s * self.s) + self.line().direction().mag_sq()
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        // self * self.reversed() is mag_sq + e0123 * k for a general sum of motors.
+        // Multiplying by its inverse square root, which e0123 * e0123 = 0 makes
+        // (1 - e0123 * k / (2 * mag_sq)) / mag, keeps this a motor.
+        let mag_sq = self.mag_sq();
+        let k =
+            T::two() * ((self.s * self.e0123) - self.line().direction().dot(self.line().moment()));
+        let inv_mag = T::one() / mag_sq.sqrt();
+        let q = -k / (T::two() * mag_sq) * inv_mag;
+        // self * e0123
+        let dual = Self::new(
+            T::zero(),
+            Line3::from_plucker(Vector3::zero(), -self.line().direction()),
+            self.s,
+        );
+        *self = *self * inv_mag + dual * q;
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut m = *self;
+        m.normalize();
+        m
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.e23 = -self.e23;
+        self.e31 = -self.e31;
+        self.e12 = -self.e12;
+        self.e01 = -self.e01;
+        self.e02 = -self.e02;
+        self.e03 = -self.e03;
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut m = *self;
+        m.reverse();
+        m
+    }
+
+    #[inline]
+    pub fn log(&self) -> Line3<T> {
+        // m and -m are the same motion, as with Rotor3::log the one with s >= 0
+        // is taken
+        if self.s < T::zero() {
+            return (-*self).log();
+        }
+
+        let l = self.line();
+        let sin = l.norm();
+        if sin <= T::epsilon() {
+            // atan2(sin, s) / sin tends to 1 / s
+            return l / self.s;
+        }
+
+        // The inverse of Line3::exp
+        let u = sin.atan2(self.s);
+        let sinc = sin / u;
+        let half_p = self.e0123 / sinc;
+        let t = half_p * (self.s - sinc) / (u * u * sinc);
+        let mut b = l / sinc;
+        b.e01 -= t * b.e23;
+        b.e02 -= t * b.e31;
+        b.e03 -= t * b.e12;
+        b
+    }
+
+    #[inline]
+    pub fn powf(&self, t: T) -> Self {
+        (self.log() * t).exp()
+    }
+
+    #[inline]
+    pub fn interpolate(&self, to: Self, t: T) -> Self {
+        *self * (self.reversed() * to).powf(t)
+    }
+}
+
+macro_rules! impl_ops_pga3d {
+    [$ty:ident: $($f:ident), +] => {
+        impl<T: Scalar> Add for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($f: self.$f + rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Sub for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($f: self.$f - rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Neg for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self { $($f: self.$f * rhs), + }
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $ty<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$f *= rhs;)+
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self { $($f: self.$f / rhs), + }
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $ty<T> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$f /= rhs;)+
+            }
+        }
+    };
+}
+
+impl_ops_pga3d![Plane3: e1, e2, e3, e0];
+impl_ops_pga3d![Line3: e01, e02, e03, e12, e31, e23];
+impl_ops_pga3d![Point3: e032, e013, e021, e123];
+impl_ops_pga3d![Motor3: s, e23, e31, e12, e01, e02, e03, e0123];
+
+impl<T: Scalar> Mul for Motor3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            s: (rhs.s * self.s)
+                - (rhs.e12 * self.e12)
+                - (rhs.e23 * self.e23)
+                - (rhs.e31 * self.e31),
+            e23: (rhs.e23 * self.s) + (rhs.e31 * self.e12) + (rhs.s * self.e23)
+                - (rhs.e12 * self.e31),
+            e31: (rhs.e12 * self.e23) + (rhs.e31 * self.s) + (rhs.s * self.e31)
+                - (rhs.e23 * self.e12),
+            e12: (rhs.e12 * self.s) + (rhs.e23 * self.e31) + (rhs.s * self.e12)
+                - (rhs.e31 * self.e23),
+            e01: (rhs.e01 * self.s)
+                + (rhs.e02 * self.e12)
+                + (rhs.e31 * self.e03)
+                + (rhs.s * self.e01)
+                - (rhs.e0123 * self.e23)
+                - (rhs.e03 * self.e31)
+                - (rhs.e12 * self.e02)
+                - (rhs.e23 * self.e0123),
+            e02: (rhs.e02 * self.s)
+                + (rhs.e03 * self.e23)
+                + (rhs.e12 * self.e01)
+                + (rhs.s * self.e02)
+                - (rhs.e01 * self.e12)
+                - (rhs.e0123 * self.e31)
+                - (rhs.e23 * self.e03)
+                - (rhs.e31 * self.e0123),
+            e03: (rhs.e01 * self.e31)
+                + (rhs.e03 * self.s)
+                + (rhs.e23 * self.e02)
+                + (rhs.s * self.e03)
+                - (rhs.e0123 * self.e12)
+                - (rhs.e02 * self.e23)
+                - (rhs.e12 * self.e0123)
+                - (rhs.e31 * self.e01),
+            e0123: (rhs.e01 * self.e23)
+                + (rhs.e0123 * self.s)
+                + (rhs.e02 * self.e31)
+                + (rhs.e03 * self.e12)
+                + (rhs.e12 * self.e03)
+                + (rhs.e23 * self.e01)
+                + (rhs.e31 * self.e02)
+                + (rhs.s * self.e0123),
+        }
+    }
+}
+
+impl<T: Scalar> MulAssign for Motor3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<Point3<T>> for Motor3<T> {
+    type Output = Point3<T>;
+
+    #[inline]
+    fn mul(self, p: Point3<T>) -> Point3<T> {
+        let a1 = -(p.e123 * self.e23);
+        let a2 = -(p.e123 * self.e31);
+        let a3 = -(p.e123 * self.e12);
+        let a0 =
+            (p.e013 * self.e31) + (p.e021 * self.e12) + (p.e032 * self.e23) - (p.e123 * self.e0123);
+        let a032 =
+            (p.e013 * self.e12) + (p.e032 * self.s) - (p.e021 * self.e31) - (p.e123 * self.e01);
+        let a013 =
+            (p.e013 * self.s) + (p.e021 * self.e23) - (p.e032 * self.e12) - (p.e123 * self.e02);
+        let a021 =
+            (p.e021 * self.s) + (p.e032 * self.e31) - (p.e013 * self.e23) - (p.e123 * self.e03);
+        let a123 = p.e123 * self.s;
+
+        Point3 {
+            e032: (a0 * self.e23)
+                + (a013 * self.e12)
+                + (a032 * self.s)
+                + (a1 * self.e0123)
+                + (a3 * self.e02)
+                - (a021 * self.e31)
+                - (a123 * self.e01)
+                - (a2 * self.e03),
+            e013: (a0 * self.e31)
+                + (a013 * self.s)
+                + (a021 * self.e23)
+                + (a1 * self.e03)
+                + (a2 * self.e0123)
+                - (a032 * self.e12)
+                - (a123 * self.e02)
+                - (a3 * self.e01),
+            e021: (a0 * self.e12)
+                + (a021 * self.s)
+                + (a032 * self.e31)
+                + (a2 * self.e01)
+                + (a3 * self.e0123)
+                - (a013 * self.e23)
+                - (a1 * self.e02)
+                - (a123 * self.e03),
+            e123: (a123 * self.s) - (a1 * self.e23) - (a2 * self.e31) - (a3 * self.e12),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Plane3<T>> for Motor3<T> {
+    type Output = Plane3<T>;
+
+    #[inline]
+    fn mul(self, p: Plane3<T>) -> Plane3<T> {
+        let a1 = (p.e1 * self.s) + (p.e2 * self.e12) - (p.e3 * self.e31);
+        let a2 = (p.e2 * self.s) + (p.e3 * self.e23) - (p.e1 * self.e12);
+        let a3 = (p.e1 * self.e31) + (p.e3 * self.s) - (p.e2 * self.e23);
+        let a0 = (p.e0 * self.s) + (p.e1 * self.e01) + (p.e2 * self.e02) + (p.e3 * self.e03);
+        let a032 = (p.e2 * self.e03) - (p.e0 * self.e23) - (p.e1 * self.e0123) - (p.e3 * self.e02);
+        let a013 = (p.e3 * self.e01) - (p.e0 * self.e31) - (p.e1 * self.e03) - (p.e2 * self.e0123);
+        let a021 = (p.e1 * self.e02) - (p.e0 * self.e12) - (p.e2 * self.e01) - (p.e3 * self.e0123);
+        let a123 = (p.e1 * self.e23) + (p.e2 * self.e31) + (p.e3 * self.e12);
+
+        Plane3 {
+            e1: (a1 * self.s) + (a123 * self.e23) + (a2 * self.e12) - (a3 * self.e31),
+            e2: (a123 * self.e31) + (a2 * self.s) + (a3 * self.e23) - (a1 * self.e12),
+            e3: (a1 * self.e31) + (a123 * self.e12) + (a3 * self.s) - (a2 * self.e23),
+            e0: (a0 * self.s)
+                + (a1 * self.e01)
+                + (a123 * self.e0123)
+                + (a2 * self.e02)
+                + (a3 * self.e03)
+                - (a013 * self.e31)
+                - (a021 * self.e12)
+                - (a032 * self.e23),
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Line3<T>> for Motor3<T> {
+    type Output = Line3<T>;
+
+    #[inline]
+    fn mul(self, l: Line3<T>) -> Line3<T> {
+        let a = -(l.e12 * self.e12) - (l.e23 * self.e23) - (l.e31 * self.e31);
+        let a23 = (l.e23 * self.s) + (l.e31 * self.e12) - (l.e12 * self.e31);
+        let a31 = (l.e12 * self.e23) + (l.e31 * self.s) - (l.e23 * self.e12);
+        let a12 = (l.e12 * self.s) + (l.e23 * self.e31) - (l.e31 * self.e23);
+        let a01 = (l.e01 * self.s) + (l.e02 * self.e12) + (l.e31 * self.e03)
+            - (l.e03 * self.e31)
+            - (l.e12 * self.e02)
+            - (l.e23 * self.e0123);
+        let a02 = (l.e02 * self.s) + (l.e03 * self.e23) + (l.e12 * self.e01)
+            - (l.e01 * self.e12)
+            - (l.e23 * self.e03)
+            - (l.e31 * self.e0123);
+        let a03 = (l.e01 * self.e31) + (l.e03 * self.s) + (l.e23 * self.e02)
+            - (l.e02 * self.e23)
+            - (l.e12 * self.e0123)
+            - (l.e31 * self.e01);
+        let a0123 = (l.e01 * self.e23)
+            + (l.e02 * self.e31)
+            + (l.e03 * self.e12)
+            + (l.e12 * self.e03)
+            + (l.e23 * self.e01)
+            + (l.e31 * self.e02);
+
+        Line3 {
+            e01: (a01 * self.s) + (a0123 * self.e23) + (a02 * self.e12) + (a31 * self.e03)
+                - (a * self.e01)
+                - (a03 * self.e31)
+                - (a12 * self.e02)
+                - (a23 * self.e0123),
+            e02: (a0123 * self.e31) + (a02 * self.s) + (a03 * self.e23) + (a12 * self.e01)
+                - (a * self.e02)
+                - (a01 * self.e12)
+                - (a23 * self.e03)
+                - (a31 * self.e0123),
+            e03: (a01 * self.e31) + (a0123 * self.e12) + (a03 * self.s) + (a23 * self.e02)
+                - (a * self.e03)
+                - (a02 * self.e23)
+                - (a12 * self.e0123)
+                - (a31 * self.e01),
+            e12: (a12 * self.s) + (a23 * self.e31) - (a * self.e12) - (a31 * self.e23),
+            e31: (a12 * self.e23) + (a31 * self.s) - (a * self.e31) - (a23 * self.e12),
+            e23: (a23 * self.s) + (a31 * self.e12) - (a * self.e23) - (a12 * self.e31),
+        }
+    }
+}
+
+impl<T: Scalar> From<Vector3<T>> for Point3<T> {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for Motor3<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::from_rot3(r)
+    }
+}
+
+impl<T: Scalar> From<Motor3<T>> for Matrix4<T> {
+    #[inline]
+    fn from(m: Motor3<T>) -> Self {
+        m.into_matrix()
+    }
+}
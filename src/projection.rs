@@ -0,0 +1,44 @@
+use crate::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Handedness {
+    // The camera looks down +z, as with Vector3::forward
+    Left,
+    // The camera looks down -z
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DepthRange {
+    // Near maps to 0 and far to 1, as in Direct3D, Metal and Vulkan
+    ZeroToOne,
+    // Near maps to -1 and far to 1, as in OpenGL
+    NegativeOneToOne,
+    // Near maps to 1 and far to 0, reversed-Z for better float precision
+    OneToZero,
+}
+
+impl Handedness {
+    // The sign of view space z in front of the camera
+    #[inline]
+    pub fn forward_sign<T: Scalar>(self) -> T {
+        match self {
+            Handedness::Left => T::one(),
+            Handedness::Right => -T::one(),
+        }
+    }
+}
+
+impl DepthRange {
+    // The depths that near and far map to
+    #[inline]
+    pub fn near_far<T: Scalar>(self) -> (T, T) {
+        match self {
+            DepthRange::ZeroToOne => (T::zero(), T::one()),
+            DepthRange::NegativeOneToOne => (-T::one(), T::one()),
+            DepthRange::OneToZero => (T::one(), T::zero()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::*;
+use rayon::prelude::*;
+
+// Large enough that each task amortises its scheduling, small enough to keep
+// every core busy on buffers of a few hundred thousand elements
+const CHUNK: usize = 4096;
+
+impl<T: Scalar + Send + Sync> Rotor2<T> {
+    pub fn par_rotate_slice(&self, vecs: &mut [Vector2<T>]) {
+        vecs.par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.rotate_slice(chunk));
+    }
+}
+
+impl<T: Scalar + Send + Sync> Rotor3<T> {
+    pub fn par_rotate_slice(&self, vecs: &mut [Vector3<T>]) {
+        vecs.par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.rotate_slice(chunk));
+    }
+}
+
+impl<T: Scalar + Send + Sync> Transformation2<T> {
+    pub fn par_transform_points(&self, points: &mut [Vector2<T>]) {
+        points
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_points(chunk));
+    }
+
+    pub fn par_transform_vectors(&self, vectors: &mut [Vector2<T>]) {
+        vectors
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_vectors(chunk));
+    }
+}
+
+impl<T: Scalar + Send + Sync> Transformation3<T> {
+    pub fn par_transform_points(&self, points: &mut [Vector3<T>]) {
+        points
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_points(chunk));
+    }
+
+    pub fn par_transform_vectors(&self, vectors: &mut [Vector3<T>]) {
+        vectors
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_vectors(chunk));
+    }
+}
+
+impl<T: Scalar + Send + Sync> Matrix4<T> {
+    pub fn par_transform_points(&self, points: &mut [Vector3<T>]) {
+        points
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_points(chunk));
+    }
+
+    pub fn par_transform_vectors(&self, vectors: &mut [Vector3<T>]) {
+        vectors
+            .par_chunks_mut(CHUNK)
+            .for_each(|chunk| self.transform_vectors(chunk));
+    }
+}
+
+macro_rules! impl_par_normalize_all {
+    [$($v:ident), +] => {
+        $(
+            impl<T: Scalar + Send + Sync> $v<T> {
+                pub fn par_normalize_all(vecs: &mut [Self]) {
+                    vecs.par_chunks_mut(CHUNK).for_each(Self::normalize_all);
+                }
+            }
+        )+
+    };
+}
+
+impl_par_normalize_all![Vector2, Vector3, Vector4];
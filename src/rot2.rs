@@ -1,220 +1,308 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_rotor2 {
-    [$(($t:ident, $nam:ident, $bv:ident, $v2:ident, $m2:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub s: $t,
-                pub bv: $bv,
-            }
-
-            impl $nam {
-                #[inline]
-                pub fn new(s: $t, bv: $bv) -> Self {
-                    Self {s, bv}
-                }
-
-                #[inline]
-                pub fn identity() -> Self {
-                    Self::new(1.0, $bv::zero())
-                }
-
-                #[inline]
-                pub fn from_angle(angle: $t) -> Self {
-                    let half_angle = angle / 2.0;
-                    let (sin, cos) = half_angle.sin_cos();
-                    Self::new(cos, $bv::new(-sin))
-                }
-            }
-
-            impl Rotor for $nam {
-                type Decimal = $t;
-                type Vector = $v2;
-                type Matrix = $m2;
-
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.mag_sq().sqrt()
-                }
-
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    self.s * self.s + self.bv.mag_sq()
-                }
-
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    self.s * other.s + self.bv.dot(other.bv)
-                }
-
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    self.s /= mag;
-                    self.bv.xy /= mag;
-                }
-
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut r = self.clone();
-                    r.normalize();
-                    r
-                }
-
-                #[inline]
-                fn reverse(&mut self) {
-                    self.bv = -self.bv;
-                }
-
-                #[inline]
-                fn reversed(&self) -> Self {
-                    let mut r = self.clone();
-                    r.reverse();
-                    r
-                }
-
-                #[inline]
-                fn rotate_by(&mut self, other: Self) {
-                    let b = *self;
-                    let a = other;
-                    let sa2_plus_baxy2 = a.s.mul_add(a.s, a.bv.xy * a.bv.xy);
-
-                    self.s = (a.s - b.s) * a.bv.xy * b.bv.xy
-                        + b.s * sa2_plus_baxy2;
-                    self.bv.xy = b.bv.xy * sa2_plus_baxy2;
-                }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Rotor2<T> {
+    pub s: T,
+    pub bv: Bivector2<T>,
+}
 
-                #[inline]
-                fn rotated_by(&self, other: Self) -> Self {
-                    let mut r = self.clone();
-                    r.rotate_by(other);
-                    r
-                }
+pub type Rot2 = Rotor2<f32>;
+pub type DRot2 = Rotor2<f64>;
+
+impl<T: Scalar> Rotor2<T> {
+    #[inline]
+    pub fn new(s: T, bv: Bivector2<T>) -> Self {
+        Self { s, bv }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), Bivector2::zero())
+    }
+
+    #[inline]
+    pub fn from_angle(angle: T) -> Self {
+        let half_angle = angle / T::two();
+        let (sin, cos) = half_angle.sin_cos();
+        Self::new(cos, Bivector2::new(-sin))
+    }
+
+    pub fn from_rotation_between(a: Vector2<T>, b: Vector2<T>) -> Self {
+        let a = a.normalized();
+        let b = b.normalized();
+
+        // The rotor half way between identity and the geometric product b * a
+        let s = T::one() + a.dot(b);
+        if s <= T::epsilon() {
+            // a and b are antiparallel, so turn half way round
+            return Self::new(T::zero(), Bivector2::new(-T::one()));
+        }
+
+        Self::new(s, -a.wedge(b)).normalized()
+    }
+
+    pub fn from_matrix(m: &Matrix2<T>) -> Self {
+        let trace = m.cols[0].x + m.cols[1].y;
+        let diff = m.cols[1].x - m.cols[0].y;
+
+        // Four times the squares of s and xy, solving for the larger one first.
+        let s_sq = T::two() + trace;
+        let xy_sq = T::two() - trace;
+
+        let r = if s_sq >= xy_sq {
+            let k = s_sq.sqrt() * T::two();
+            Self::new(k / (T::two() * T::two()), Bivector2::new(diff / k))
+        } else {
+            let k = xy_sq.sqrt() * T::two();
+            Self::new(diff / k, Bivector2::new(k / (T::two() * T::two())))
+        };
+
+        r.normalized()
+    }
+
+    // Rotates every vector in place through the rotor's matrix, which costs
+    // less per vector than the sandwich product once it is built
+    pub fn rotate_slice(&self, vecs: &mut [Vector2<T>]) {
+        let m = self.into_matrix();
+        for v in vecs {
+            *v = m * *v;
+        }
+    }
+}
 
-                #[inline]
-                fn rotate_vec(&self, vec: &mut Self::Vector) {
-                    let fe1 = self.s * vec.x + self.bv.xy * vec.y;
-                    let fe2 = self.s * vec.y - (self.bv.xy * vec.x);
+impl<T: Scalar> Rotor for Rotor2<T> {
+    type Decimal = T;
+    type Bivec = Bivector2<T>;
+    type Vector = Vector2<T>;
+    type Matrix = Matrix2<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        self.s * self.s + self.bv.mag_sq()
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.s * other.s + self.bv.dot(other.bv)
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        self.s /= mag;
+        self.bv.xy /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut r = *self;
+        r.normalize();
+        r
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    fn rotate_by(&mut self, other: Self) {
+        let b = *self;
+        let a = other;
+        let sa2_plus_baxy2 = a.s.mul_add(a.s, a.bv.xy * a.bv.xy);
+
+        self.s = (a.s - b.s) * a.bv.xy * b.bv.xy + b.s * sa2_plus_baxy2;
+        self.bv.xy = b.bv.xy * sa2_plus_baxy2;
+    }
+
+    #[inline]
+    fn rotated_by(&self, other: Self) -> Self {
+        let mut r = *self;
+        r.rotate_by(other);
+        r
+    }
+
+    #[inline]
+    fn rotate_vec(&self, vec: &mut Self::Vector) {
+        let fe1 = self.s * vec.x + self.bv.xy * vec.y;
+        let fe2 = self.s * vec.y - (self.bv.xy * vec.x);
+
+        vec.x = self.s * fe1 + self.bv.xy * fe2;
+        vec.y = self.s * fe2 - (self.bv.xy * fe1);
+    }
+
+    #[inline]
+    fn into_matrix(&self) -> Self::Matrix {
+        let s2_minus_bxy2 = self.s * self.s - self.bv.xy * self.bv.xy;
+        let two_s_bxy = T::two() * self.s * self.bv.xy;
+
+        Matrix2::new(
+            Vector2::new(s2_minus_bxy2, -two_s_bxy),
+            Vector2::new(two_s_bxy, s2_minus_bxy2),
+        )
+    }
+
+    #[inline]
+    fn log(&self) -> Self::Bivec {
+        Bivector2::new(self.bv.xy.atan2(self.s))
+    }
+
+    // log can take the longer arc, so flip to first
+    #[inline]
+    fn interpolate(&self, to: Self, t: Self::Decimal) -> Self {
+        let to = if self.dot(to) < T::zero() { -to } else { to };
+        *self * (self.reversed() * to).powf(t)
+    }
+}
 
-                    vec.x = self.s * fe1 + self.bv.xy * fe2;
-                    vec.y = self.s * fe2 - (self.bv.xy * fe1);
-                }
+impl<T: Scalar> Add for Rotor2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn into_matrix(&self) -> Self::Matrix {
-                    let s2_minus_bxy2 = self.s * self.s - self.bv.xy * self.bv.xy;
-                    let two_s_bxy = 2.0 * self.s * self.bv.xy;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.s + rhs.s, self.bv + rhs.bv)
+    }
+}
 
-                    $m2::new(
-                        $v2::new(s2_minus_bxy2, -two_s_bxy),
-                        $v2::new(two_s_bxy, s2_minus_bxy2))
-                }
-            }
+impl<T: Scalar> AddAssign for Rotor2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.s += rhs.s;
+        self.bv += rhs.bv;
+    }
+}
 
-            impl Add for $nam {
-                type Output = Self;
+impl<T: Scalar> Sub for Rotor2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn add(self, rhs: Self) -> Self {
-                    Self::new(self.s + rhs.s, self.bv + rhs.bv)
-                }
-            }
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.s - rhs.s, self.bv - rhs.bv)
+    }
+}
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: Self) {
-                    self.s += rhs.s;
-                    self.bv += rhs.bv;
-                }
-            }
+impl<T: Scalar> SubAssign for Rotor2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.s -= rhs.s;
+        self.bv -= rhs.bv;
+    }
+}
 
-            impl Sub for $nam {
-                type Output = Self;
+impl<T: Scalar> Neg for Rotor2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn sub(self, rhs: Self) -> Self {
-                    Self::new(self.s - rhs.s, self.bv - rhs.bv)
-                }
-            }
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.s, -self.bv)
+    }
+}
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: Self) {
-                    self.s -= rhs.s;
-                    self.bv -= rhs.bv;
-                }
-            }
+impl<T: Scalar> Mul for Rotor2<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            s: self.s * rhs.s - (self.bv.xy * rhs.bv.xy),
+            bv: Bivector2 {
+                xy: self.s * rhs.bv.xy + rhs.s * self.bv.xy,
+            },
+        }
+    }
+}
 
-            impl Mul for $nam {
-                type Output = Self;
+impl<T: Scalar> Mul<Vector2<T>> for Rotor2<T> {
+    type Output = Vector2<T>;
 
-                #[inline]
-                fn mul(self, rhs: Self) -> Self {
-                    Self {
-                        s: self.s * rhs.s - (self.bv.xy * rhs.bv.xy),
-                        bv: $bv {
-                            xy: self.s * rhs.bv.xy + rhs.s * self.bv.xy,
-                        }
-                    }
-                }
-            }
+    #[inline]
+    fn mul(self, mut rhs: Vector2<T>) -> Vector2<T> {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
 
-            impl Mul<$v2> for $nam {
-                type Output = $v2;
+impl<T: Scalar> MulAssign<T> for Rotor2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.s *= rhs;
+        self.bv *= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, mut rhs: $v2) -> $v2 {
-                    self.rotate_vec(&mut rhs);
-                    rhs
-                }
-            }
+impl<T: Scalar> Mul<T> for Rotor2<T> {
+    type Output = Self;
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.s *= rhs;
-                    self.bv *= rhs;
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.s * rhs, self.bv * rhs)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = Self;
+impl<T: Scalar> Div<T> for Rotor2<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.s / rhs, self.bv / rhs)
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self {
-                    Self::new(self.s * rhs, self.bv * rhs)
-                }
-            }
+impl<T: Scalar> DivAssign<T> for Rotor2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.s /= rhs;
+        self.bv /= rhs;
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+macro_rules! impl_scalar_rotor2 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Rotor2<$t>> for $t {
+                type Output = Rotor2<$t>;
 
                 #[inline]
-                fn mul(self, rotor: $nam) -> $nam {
+                fn mul(self, rotor: Rotor2<$t>) -> Rotor2<$t> {
                     rotor * self
                 }
             }
-
-            impl Div<$t> for $nam {
-                type Output = Self;
-                #[inline]
-                fn div(self, rhs: $t) -> Self {
-                    Self::new(self.s / rhs, self.bv / rhs)
-                }
-            }
-
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.s /= rhs;
-                    self.bv /= rhs;
-                }
-            }
         )+
     };
 }
 
-impl_rotor2![(f32, Rot2, Bivec2, Vec2, Mat2)];
+impl_scalar_rotor2![f32, f64];
+
+impl From<Rot2> for DRot2 {
+    #[inline]
+    fn from(r: Rot2) -> Self {
+        Self::new(f64::from(r.s), DBivec2::from(r.bv))
+    }
+}
+
+impl Rot2 {
+    #[inline]
+    pub fn as_drot2(&self) -> DRot2 {
+        DRot2::from(*self)
+    }
+}
+
+impl DRot2 {
+    #[inline]
+    pub fn as_rot2(&self) -> Rot2 {
+        Rot2::new(self.s as f32, self.bv.as_bivec2())
+    }
+}
@@ -1,271 +1,523 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_rotor3 {
-    [$(($t:ident, $nam:ident, $bv:ident, $v3:ident, $m3:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub s: $t,
-                pub bv: $bv,
-            }
-
-            impl $nam {
-                #[inline]
-                pub fn new(s: $t, bv: $bv) -> Self {
-                    Self {s, bv}
-                }
-
-                #[inline]
-                pub fn identity() -> Self {
-                    Self::new(1.0, $bv::zero())
-                }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Rotor3<T> {
+    pub s: T,
+    pub bv: Bivector3<T>,
+}
 
-                #[inline]
-                pub fn from_angle_plane(angle: $t, plane: $bv) -> Self {
-                    let half_angle = angle / 2.0;
-                    let (sin, cos) = half_angle.sin_cos();
-                    Self::new(cos, -sin * plane)
-                }
+pub type Rot3 = Rotor3<f32>;
+pub type DRot3 = Rotor3<f64>;
+
+impl<T: Scalar> Rotor3<T> {
+    #[inline]
+    pub fn new(s: T, bv: Bivector3<T>) -> Self {
+        Self { s, bv }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), Bivector3::zero())
+    }
+
+    #[inline]
+    pub fn from_angle_plane(angle: T, plane: Bivector3<T>) -> Self {
+        let half_angle = angle / T::two();
+        let (sin, cos) = half_angle.sin_cos();
+        Self::new(cos, plane * -sin)
+    }
+
+    #[inline]
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Self {
+        Self::from_angle_plane(angle, axis.normalized().dual())
+    }
+
+    #[inline]
+    pub fn to_angle_plane(&self) -> (T, Bivector3<T>) {
+        let bv_mag = self.bv.mag();
+        let angle = T::two() * bv_mag.atan2(self.s);
+        if bv_mag == T::zero() {
+            return (angle, Bivector3::new(T::one(), T::zero(), T::zero()));
+        }
+
+        (angle, -self.bv / bv_mag)
+    }
+
+    #[inline]
+    pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+        let (angle, plane) = self.to_angle_plane();
+        (plane.dual(), angle)
+    }
+
+    pub fn from_euler(order: EulerOrder, frame: EulerFrame, a: T, b: T, c: T) -> Self {
+        let axes = order.axes();
+        let basis = |i: usize, angle: T| {
+            let mut axis = Vector3::zero();
+            axis[i] = T::one();
+            Self::from_axis_angle(axis, angle)
+        };
+
+        let (ra, rb, rc) = (basis(axes[0], a), basis(axes[1], b), basis(axes[2], c));
+        match frame {
+            EulerFrame::Intrinsic => ra * rb * rc,
+            EulerFrame::Extrinsic => rc * rb * ra,
+        }
+    }
+
+    pub fn to_euler(&self, order: EulerOrder, frame: EulerFrame) -> (T, T, T) {
+        if frame == EulerFrame::Extrinsic {
+            let (c, b, a) = self.to_euler(order.reversed(), EulerFrame::Intrinsic);
+            return (a, b, c);
+        }
+
+        let m = self.into_matrix();
+        let e = |row: usize, col: usize| m.cols[col][row];
+        let [i, j, third] = order.axes();
+        let k = 3 - i - j;
+        let four = T::two() * T::two();
+        let gimbal_eps = T::epsilon() * four * four;
+
+        // An odd permutation of the axes is a mirrored frame, which flips the
+        // sign of the matrix entries the angles are read from.
+        let p = if (j + 3 - i) % 3 == 1 {
+            T::one()
+        } else {
+            -T::one()
+        };
+
+        if third == i {
+            let sin_b = (e(i, j) * e(i, j) + e(i, k) * e(i, k)).sqrt();
+            let b = sin_b.atan2(e(i, i));
+            if sin_b > gimbal_eps {
+                (e(j, i).atan2(-p * e(k, i)), b, e(i, j).atan2(p * e(i, k)))
+            } else {
+                ((p * e(k, j)).atan2(e(j, j)), b, T::zero())
             }
+        } else {
+            let cos_b = (e(i, i) * e(i, i) + e(i, j) * e(i, j)).sqrt();
+            let b = (p * e(i, k)).atan2(cos_b);
+            if cos_b > gimbal_eps {
+                (
+                    (-p * e(j, k)).atan2(e(k, k)),
+                    b,
+                    (-p * e(i, j)).atan2(e(i, i)),
+                )
+            } else {
+                ((p * e(k, j)).atan2(e(j, j)), b, T::zero())
+            }
+        }
+    }
+
+    pub fn from_rotation_between(a: Vector3<T>, b: Vector3<T>) -> Self {
+        let a = a.normalized();
+        let b = b.normalized();
+
+        // The rotor half way between identity and the geometric product b * a
+        let s = T::one() + a.dot(b);
+        if s <= T::epsilon() {
+            // a and b are antiparallel so any plane containing a will do, pick
+            // the basis vector that is furthest from a to build one.
+            let (x, y, z) = (a.x.abs(), a.y.abs(), a.z.abs());
+            let other = if x <= y && x <= z {
+                Vector3::right()
+            } else if y <= z {
+                Vector3::up()
+            } else {
+                Vector3::forward()
+            };
+            let plane = a.wedge(other.rejected(a)).normalized();
+            return Self::new(T::zero(), -plane);
+        }
+
+        Self::new(s, -a.wedge(b)).normalized()
+    }
+
+    pub fn from_matrix(m: &Matrix3<T>) -> Self {
+        let one = T::one();
+        let four = T::two() * T::two();
+        let (m00, m01, m02) = (m.cols[0].x, m.cols[1].x, m.cols[2].x);
+        let (m10, m11, m12) = (m.cols[0].y, m.cols[1].y, m.cols[2].y);
+        let (m20, m21, m22) = (m.cols[0].z, m.cols[1].z, m.cols[2].z);
+
+        // Four times the squares of s, yz, xz and xy. Solving for the largest one
+        // first keeps the division below well away from zero.
+        let s_sq = one + m00 + m11 + m22;
+        let yz_sq = one + m00 - m11 - m22;
+        let xz_sq = one - m00 + m11 - m22;
+        let xy_sq = one - m00 - m11 + m22;
+
+        let r = if s_sq >= yz_sq && s_sq >= xz_sq && s_sq >= xy_sq {
+            let k = s_sq.sqrt() * T::two();
+            Self::new(
+                k / four,
+                Bivector3::new((m01 - m10) / k, (m02 - m20) / k, (m12 - m21) / k),
+            )
+        } else if yz_sq >= xz_sq && yz_sq >= xy_sq {
+            let k = yz_sq.sqrt() * T::two();
+            Self::new(
+                (m12 - m21) / k,
+                Bivector3::new((m02 + m20) / k, -(m01 + m10) / k, k / four),
+            )
+        } else if xz_sq >= xy_sq {
+            let k = xz_sq.sqrt() * T::two();
+            Self::new(
+                (m02 - m20) / k,
+                Bivector3::new(-(m12 + m21) / k, k / four, -(m01 + m10) / k),
+            )
+        } else {
+            let k = xy_sq.sqrt() * T::two();
+            Self::new(
+                (m01 - m10) / k,
+                Bivector3::new(k / four, -(m12 + m21) / k, (m02 + m20) / k),
+            )
+        };
+
+        r.normalized()
+    }
+
+    #[inline]
+    pub fn from_matrix_orthonormalized(m: &Matrix3<T>) -> Self {
+        Self::from_matrix(&m.orthonormalized())
+    }
+
+    // The quaternion x * i + y * j + z * k + w rotating the same way, where
+    // i = -yz, j = xz and k = -xy so (x, y, z) is along the rotation axis
+    #[inline]
+    pub fn from_quaternion(q: Vector4<T>) -> Self {
+        Self::new(q.w, -Vector3::new(q.x, q.y, q.z).dual())
+    }
+
+    #[inline]
+    pub fn to_quaternion(&self) -> Vector4<T> {
+        let v = -self.bv.dual();
+        Vector4::new(v.x, v.y, v.z, self.s)
+    }
+
+    // Rotates every vector in place through the rotor's matrix, which costs
+    // less per vector than the sandwich product once it is built
+    pub fn rotate_slice(&self, vecs: &mut [Vector3<T>]) {
+        let m = self.into_matrix();
+        for v in vecs {
+            *v = m * *v;
+        }
+    }
+}
 
-            impl Rotor for $nam {
-                type Decimal = $t;
-                type Vector = $v3;
-                type Matrix = $m3;
-
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.mag_sq().sqrt()
-                }
-
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    self.s * self.s + self.bv.mag_sq()
-                }
-
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    self.s * other.s + self.bv.dot(other.bv)
-                }
-
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    self.s /= mag;
-                    self.bv.xy /= mag;
-                    self.bv.xz /= mag;
-                    self.bv.yz /= mag;
-                }
-
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut r = self.clone();
-                    r.normalize();
-                    r
-                }
+impl<T: Scalar> Rotor for Rotor3<T> {
+    type Decimal = T;
+    type Bivec = Bivector3<T>;
+    type Vector = Vector3<T>;
+    type Matrix = Matrix3<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        self.s * self.s + self.bv.mag_sq()
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.s * other.s + self.bv.dot(other.bv)
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        self.s /= mag;
+        self.bv.xy /= mag;
+        self.bv.xz /= mag;
+        self.bv.yz /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut r = *self;
+        r.normalize();
+        r
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    fn rotate_by(&mut self, other: Self) {
+        let b = *self;
+        let a = other;
+        let sa2 = a.s * a.s;
+        let baxy2 = a.bv.xy * a.bv.xy;
+        let baxz2 = a.bv.xz * a.bv.xz;
+        let bayz2 = a.bv.yz * a.bv.yz;
+        let sa_baxy = a.s * a.bv.xy;
+        let sa_baxz = a.s * a.bv.xz;
+        let sa_bayz = a.s * a.bv.yz;
+        let baxy_baxz = a.bv.xy * a.bv.xz;
+        let baxy_bayz = a.bv.xy * a.bv.yz;
+        let baxz_bayz = a.bv.xz * a.bv.yz;
+        let two_bbxy = T::two() * b.bv.xy;
+        let two_bbxz = T::two() * b.bv.xz;
+        let two_bbyz = T::two() * b.bv.yz;
+
+        self.s = (sa2 + baxy2 + baxz2 + bayz2) * b.s;
+
+        self.bv.xy = (sa2 + baxy2 - baxz2 - bayz2) * b.bv.xy
+            + (baxy_baxz + sa_bayz) * two_bbxz
+            + (baxy_bayz - sa_baxz) * two_bbyz;
+
+        self.bv.xz = (sa2 - baxy2 + baxz2 - bayz2) * b.bv.xz
+            + (baxy_baxz - sa_bayz) * two_bbxy
+            + (baxz_bayz + sa_baxy) * two_bbyz;
+
+        self.bv.yz = (sa2 - baxy2 - baxz2 + bayz2) * b.bv.yz
+            + (baxy_bayz + sa_baxz) * two_bbxy
+            + (baxz_bayz - sa_baxy) * two_bbxz;
+    }
+
+    #[inline]
+    fn rotate_vec(&self, vec: &mut Self::Vector) {
+        let fx = self.s * vec.x + self.bv.xy * vec.y + self.bv.xz * vec.z;
+        let fy = self.s * vec.y - self.bv.xy * vec.x + self.bv.yz * vec.z;
+        let fz = self.s * vec.z - self.bv.xz * vec.x - self.bv.yz * vec.y;
+        let fw = self.bv.xy * vec.z - self.bv.xz * vec.y + self.bv.yz * vec.x;
+
+        vec.x = self.s * fx + self.bv.xy * fy + self.bv.xz * fz + self.bv.yz * fw;
+        vec.y = self.s * fy - self.bv.xy * fx - self.bv.xz * fw + self.bv.yz * fz;
+        vec.z = self.s * fz + self.bv.xy * fw - self.bv.xz * fx - self.bv.yz * fy;
+    }
+
+    #[inline]
+    fn rotated_by(&self, other: Self) -> Self {
+        let mut r = *self;
+        r.rotate_by(other);
+        r
+    }
+
+    #[inline]
+    fn into_matrix(&self) -> Self::Matrix {
+        let two = T::two();
+        let s2 = self.s * self.s;
+        let bxy2 = self.bv.xy * self.bv.xy;
+        let bxz2 = self.bv.xz * self.bv.xz;
+        let byz2 = self.bv.yz * self.bv.yz;
+        let s_bxy = self.s * self.bv.xy;
+        let s_bxz = self.s * self.bv.xz;
+        let s_byz = self.s * self.bv.yz;
+        let bxz_byz = self.bv.xz * self.bv.yz;
+        let bxy_byz = self.bv.xy * self.bv.yz;
+        let bxy_bxz = self.bv.xy * self.bv.xz;
+
+        Matrix3::new(
+            Vector3::new(
+                s2 - bxy2 - bxz2 + byz2,
+                -two * (bxz_byz + s_bxy),
+                two * (bxy_byz - s_bxz),
+            ),
+            Vector3::new(
+                two * (s_bxy - bxz_byz),
+                s2 - bxy2 + bxz2 - byz2,
+                -two * (s_byz + bxy_bxz),
+            ),
+            Vector3::new(
+                two * (s_bxz + bxy_byz),
+                two * (s_byz - bxy_bxz),
+                s2 + bxy2 - bxz2 - byz2,
+            ),
+        )
+    }
+
+    #[inline]
+    fn log(&self) -> Self::Bivec {
+        // r and -r are the same rotation, taking the one with s >= 0 keeps the
+        // angle within pi and continuous around s = -1
+        if self.s < T::zero() {
+            return (-*self).log();
+        }
+
+        let bv_mag = self.bv.mag();
+        if bv_mag <= T::epsilon() {
+            // atan2(bv_mag, s) / bv_mag tends to 1 / s
+            return self.bv / self.s;
+        }
+
+        self.bv * (bv_mag.atan2(self.s) / bv_mag)
+    }
+}
 
-                #[inline]
-                fn reverse(&mut self) {
-                    self.bv = -self.bv;
-                }
+impl<T: Scalar> Add for Rotor3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn reversed(&self) -> Self {
-                    let mut r = self.clone();
-                    r.reverse();
-                    r
-                }
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.s + rhs.s, self.bv + rhs.bv)
+    }
+}
 
-                #[inline]
-                fn rotate_by(&mut self, other: Self) {
-                    let b = *self;
-                    let a = other;
-                    let sa2 = a.s * a.s;
-                    let baxy2 = a.bv.xy * a.bv.xy;
-                    let baxz2 = a.bv.xz * a.bv.xz;
-                    let bayz2 = a.bv.yz * a.bv.yz;
-                    let sa_baxy = a.s * a.bv.xy;
-                    let sa_baxz = a.s * a.bv.xz;
-                    let sa_bayz = a.s * a.bv.yz;
-                    let baxy_baxz = a.bv.xy * a.bv.xz;
-                    let baxy_bayz = a.bv.xy * a.bv.yz;
-                    let baxz_bayz = a.bv.xz * a.bv.yz;
-                    let two_bbxy =  2.0 * b.bv.xy;
-                    let two_bbxz =  2.0 * b.bv.xz;
-                    let two_bbyz =  2.0 * b.bv.yz;
-
-                    self.s = (sa2 + baxy2 + baxz2 + bayz2) * b.s;
-
-                    self.bv.xy = (sa2 + baxy2 - baxz2 - bayz2) * b.bv.xy
-                        + (baxy_baxz + sa_bayz) * two_bbxz
-                        + (baxy_bayz - sa_baxz) * two_bbyz;
-
-                    self.bv.xz = (sa2 - baxy2 + baxz2 - bayz2) * b.bv.xz
-                        + (baxy_baxz - sa_bayz) * two_bbxy
-                        + (baxz_bayz + sa_baxy) * two_bbyz;
-
-                    self.bv.yz = (sa2 - baxy2 - baxz2 + bayz2) * b.bv.yz
-                        + (baxy_bayz + sa_baxz) * two_bbxy
-                        + (baxz_bayz - sa_baxy) * two_bbxz;
-                }
+impl<T: Scalar> AddAssign for Rotor3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.s += rhs.s;
+        self.bv += rhs.bv;
+    }
+}
 
-                #[inline]
-                fn rotate_vec(&self, vec: &mut $v3) {
-                    let fx = self.s * vec.x + self.bv.xy * vec.y + self.bv.xz * vec.z;
-                    let fy = self.s * vec.y - self.bv.xy * vec.x + self.bv.yz * vec.z;
-                    let fz = self.s * vec.z - self.bv.xz * vec.x - self.bv.yz * vec.y;
-                    let fw = self.bv.xy * vec.z - self.bv.xz * vec.y + self.bv.yz * vec.x;
-
-                    vec.x = self.s * fx + self.bv.xy * fy + self.bv.xz * fz + self.bv.yz * fw;
-                    vec.y = self.s * fy - self.bv.xy * fx - self.bv.xz * fw + self.bv.yz * fz;
-                    vec.z = self.s * fz + self.bv.xy * fw - self.bv.xz * fx - self.bv.yz * fy;
-                }
+impl<T: Scalar> Sub for Rotor3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn rotated_by(&self, other: Self) -> Self {
-                    let mut r = self.clone();
-                    r.rotate_by(other);
-                    r
-                }
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.s - rhs.s, self.bv - rhs.bv)
+    }
+}
 
-                #[inline]
-                fn into_matrix(&self) -> Self::Matrix {
-                    let s2 = self.s * self.s;
-                    let bxy2 = self.bv.xy * self.bv.xy;
-                    let bxz2 = self.bv.xz * self.bv.xz;
-                    let byz2 = self.bv.yz * self.bv.yz;
-                    let s_bxy = self.s * self.bv.xy;
-                    let s_bxz = self.s * self.bv.xz;
-                    let s_byz = self.s * self.bv.yz;
-                    let bxz_byz = self.bv.xz * self.bv.yz;
-                    let bxy_byz = self.bv.xy * self.bv.yz;
-                    let bxy_bxz = self.bv.xy * self.bv.xz;
-
-                    $m3::new(
-                        $v3::new(
-                            s2 - bxy2 - bxz2 + byz2,
-                            -2.0 * (bxz_byz + s_bxy),
-                            2.0 * (bxy_byz - s_bxz)),
-                        $v3::new(
-                            2.0 * (s_bxy - bxz_byz),
-                            s2 - bxy2 + bxz2 - byz2,
-                            -2.0 * (s_byz + bxy_bxz)
-                        ),
-                        $v3::new(
-                            2.0 * (s_bxz + bxy_byz),
-                            2.0 * (s_byz - bxy_bxz),
-                            s2 + bxy2 - bxz2 - byz2
-                        )
-                    )
-                }
-            }
+impl<T: Scalar> SubAssign for Rotor3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.s -= rhs.s;
+        self.bv -= rhs.bv;
+    }
+}
 
+impl<T: Scalar> Neg for Rotor3<T> {
+    type Output = Self;
 
-            impl Add for $nam {
-                type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.s, -self.bv)
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: Self) -> Self {
-                    Self::new(self.s + rhs.s, self.bv + rhs.bv)
-                }
-            }
+impl<T: Scalar> Mul for Rotor3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self {
+            s: self.s * rhs.s
+                - self.bv.xy * rhs.bv.xy
+                - self.bv.xz * rhs.bv.xz
+                - self.bv.yz * rhs.bv.yz,
+            bv: Bivector3 {
+                xy: self.bv.xy * rhs.s + self.s * rhs.bv.xy + self.bv.yz * rhs.bv.xz
+                    - self.bv.xz * rhs.bv.yz,
+                xz: self.bv.xz * rhs.s + self.s * rhs.bv.xz - self.bv.yz * rhs.bv.xy
+                    + self.bv.xy * rhs.bv.yz,
+                yz: self.bv.yz * rhs.s + self.s * rhs.bv.yz + self.bv.xz * rhs.bv.xy
+                    - self.bv.xy * rhs.bv.xz,
+            },
+        }
+    }
+}
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: Self) {
-                    self.s += rhs.s;
-                    self.bv += rhs.bv;
-                }
-            }
+impl<T: Scalar> Mul<Vector3<T>> for Rotor3<T> {
+    type Output = Vector3<T>;
 
-            impl Sub for $nam {
-                type Output = Self;
+    #[inline]
+    fn mul(self, mut rhs: Vector3<T>) -> Vector3<T> {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: Self) -> Self {
-                    Self::new(self.s - rhs.s, self.bv - rhs.bv)
-                }
-            }
+impl<T: Scalar> Mul<Bivector3<T>> for Rotor3<T> {
+    type Output = Bivector3<T>;
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: Self) {
-                    self.s -= rhs.s;
-                    self.bv -= rhs.bv;
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: Bivector3<T>) -> Bivector3<T> {
+        // Bivectors rotate along with their normals
+        (self * rhs.dual()).dual()
+    }
+}
 
-            impl Mul for $nam {
-                type Output = Self;
+impl<T: Scalar> Mul<Trivector3<T>> for Rotor3<T> {
+    type Output = Trivector3<T>;
 
-                #[inline]
-                fn mul(self, rhs: Self) -> Self {
-                    Self {
-                        s: self.s * rhs.s - self.bv.xy * rhs.bv.xy - self.bv.xz * rhs.bv.xz - self.bv.yz * rhs.bv.yz,
-                        bv: $bv {
-                            xy: self.bv.xy * rhs.s + self.s * rhs.bv.xy + self.bv.yz * rhs.bv.xz - self.bv.xz * rhs.bv.yz,
-                            xz: self.bv.xz * rhs.s + self.s * rhs.bv.xz - self.bv.yz * rhs.bv.xy + self.bv.xy * rhs.bv.yz,
-                            yz: self.bv.yz * rhs.s + self.s * rhs.bv.yz + self.bv.xz * rhs.bv.xy - self.bv.xy * rhs.bv.xz,
-                        }
-                    }
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: Trivector3<T>) -> Trivector3<T> {
+        rhs * self.mag_sq()
+    }
+}
 
-            impl Mul<$v3> for $nam {
-                type Output = $v3;
+impl<T: Scalar> MulAssign<T> for Rotor3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.s *= rhs;
+        self.bv *= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, mut rhs: $v3) -> $v3 {
-                    self.rotate_vec(&mut rhs);
-                    rhs
-                }
-            }
+impl<T: Scalar> Mul<T> for Rotor3<T> {
+    type Output = Self;
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.s *= rhs;
-                    self.bv *= rhs;
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.s * rhs, self.bv * rhs)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = Self;
+impl<T: Scalar> Div<T> for Rotor3<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.s / rhs, self.bv / rhs)
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self {
-                    Self::new(self.s * rhs, self.bv * rhs)
-                }
-            }
+impl<T: Scalar> DivAssign<T> for Rotor3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.s /= rhs;
+        self.bv /= rhs;
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+macro_rules! impl_scalar_rotor3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Rotor3<$t>> for $t {
+                type Output = Rotor3<$t>;
 
                 #[inline]
-                fn mul(self, rotor: $nam) -> $nam {
+                fn mul(self, rotor: Rotor3<$t>) -> Rotor3<$t> {
                     rotor * self
                 }
             }
-
-            impl Div<$t> for $nam {
-                type Output = Self;
-                #[inline]
-                fn div(self, rhs: $t) -> Self {
-                    Self::new(self.s / rhs, self.bv / rhs)
-                }
-            }
-
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.s /= rhs;
-                    self.bv /= rhs;
-                }
-            }
         )+
     };
 }
 
-impl_rotor3![(f32, Rot3, Bivec3, Vec3, Mat3)];
+impl_scalar_rotor3![f32, f64];
+
+impl From<Rot3> for DRot3 {
+    #[inline]
+    fn from(r: Rot3) -> Self {
+        Self::new(f64::from(r.s), DBivec3::from(r.bv))
+    }
+}
+
+impl Rot3 {
+    #[inline]
+    pub fn as_drot3(&self) -> DRot3 {
+        DRot3::from(*self)
+    }
+}
+
+impl DRot3 {
+    #[inline]
+    pub fn as_rot3(&self) -> Rot3 {
+        Rot3::new(self.s as f32, self.bv.as_bivec3())
+    }
+}
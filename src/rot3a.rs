@@ -0,0 +1,291 @@
+use crate::simd::*;
+use crate::*;
+use std::fmt;
+use std::ops::*;
+
+// A Rot3 in one SIMD register. The lanes hold the rotor the way a quaternion
+// is laid out, (-yz, xz, -xy, s), which turns the products into the usual
+// quaternion shuffles.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Rot3A(pub(crate) F32x4);
+
+impl Rot3A {
+    #[inline]
+    pub fn new(s: f32, bv: Bivec3) -> Self {
+        Self(F32x4::new(-bv.yz, bv.xz, -bv.xy, s))
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(1.0, Bivec3::zero())
+    }
+
+    #[inline]
+    pub fn from_angle_plane(angle: f32, plane: Bivec3) -> Self {
+        Rot3::from_angle_plane(angle, plane).into()
+    }
+
+    #[inline]
+    pub fn from_axis_angle(axis: Vec3A, angle: f32) -> Self {
+        Rot3::from_axis_angle(axis.into(), angle).into()
+    }
+
+    #[inline]
+    pub fn s(&self) -> f32 {
+        self.0.to_array()[3]
+    }
+
+    #[inline]
+    pub fn bv(&self) -> Bivec3 {
+        let [x, y, z, _] = self.0.to_array();
+        Bivec3::new(-z, y, -x)
+    }
+
+    // The vector lanes with w cleared, and s in every lane
+    #[inline]
+    fn split(self) -> (F32x4, F32x4) {
+        (
+            self.0.with_w_of(F32x4::zero()),
+            shuffle!(self.0, [3, 3, 3, 3]),
+        )
+    }
+
+    // (s² - q·q) v + 2 (q·v) q + 2 s (q × v), for v with a zero w lane
+    #[inline]
+    fn sandwich(q: F32x4, s: F32x4, v: F32x4) -> F32x4 {
+        let two = F32x4::splat(2.0);
+        (s * s - q.dot3(q)) * v + two * q.dot3(v) * q + two * s * q.cross(v)
+    }
+}
+
+impl Rotor for Rot3A {
+    type Decimal = f32;
+    type Bivec = Bivec3;
+    type Vector = Vec3A;
+    type Matrix = Mat3;
+
+    #[inline]
+    fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> f32 {
+        self.0.dot4(other.0).x()
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        self.0 = self.0 / F32x4::splat(mag);
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut r = *self;
+        r.normalize();
+        r
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.0 = (-self.0).with_w_of(self.0);
+    }
+
+    #[inline]
+    fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    fn rotate_by(&mut self, other: Self) {
+        let (q, s) = other.split();
+        let scale = s * s + q.dot3(q);
+        let rotated = Self::sandwich(q, s, self.0.with_w_of(F32x4::zero()));
+        self.0 = rotated.with_w_of(scale * self.0);
+    }
+
+    #[inline]
+    fn rotate_vec(&self, vec: &mut Vec3A) {
+        let (q, s) = self.split();
+        vec.0 = Self::sandwich(q, s, vec.0);
+    }
+
+    #[inline]
+    fn rotated_by(&self, other: Self) -> Self {
+        let mut r = *self;
+        r.rotate_by(other);
+        r
+    }
+
+    #[inline]
+    fn into_matrix(&self) -> Mat3 {
+        // The columns are the rotated basis vectors
+        let (q, s) = self.split();
+        let column = |v| Vec3::from(Vec3A(Self::sandwich(q, s, v)));
+        Mat3::new(
+            column(F32x4::new(1.0, 0.0, 0.0, 0.0)),
+            column(F32x4::new(0.0, 1.0, 0.0, 0.0)),
+            column(F32x4::new(0.0, 0.0, 1.0, 0.0)),
+        )
+    }
+
+    // log needs atan2, which has no SIMD form, so it goes through Rot3
+    #[inline]
+    fn log(&self) -> Bivec3 {
+        Rot3::from(*self).log()
+    }
+}
+
+impl Default for Rot3A {
+    #[inline]
+    fn default() -> Self {
+        Self(F32x4::zero())
+    }
+}
+
+impl PartialEq for Rot3A {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl fmt::Debug for Rot3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rot3A")
+            .field("s", &self.s())
+            .field("bv", &self.bv())
+            .finish()
+    }
+}
+
+impl Add for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Rot3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = self.0 + rhs.0;
+    }
+}
+
+impl Sub for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Rot3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = self.0 - rhs.0;
+    }
+}
+
+impl Neg for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self.0, rhs.0);
+        let flip_w = F32x4::new(1.0, 1.0, 1.0, -1.0);
+        Self(
+            shuffle!(a, [3, 3, 3, 3]) * b
+                + shuffle!(a, [0, 1, 2, 0]) * shuffle!(b, [3, 3, 3, 0]) * flip_w
+                + shuffle!(a, [1, 2, 0, 1]) * shuffle!(b, [2, 0, 1, 1]) * flip_w
+                - shuffle!(a, [2, 0, 1, 2]) * shuffle!(b, [1, 2, 0, 2]),
+        )
+    }
+}
+
+impl Mul<Vec3A> for Rot3A {
+    type Output = Vec3A;
+
+    #[inline]
+    fn mul(self, mut rhs: Vec3A) -> Vec3A {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl Mul<f32> for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * F32x4::splat(rhs))
+    }
+}
+
+impl MulAssign<f32> for Rot3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f32> for Rot3A {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        Self(self.0 / F32x4::splat(rhs))
+    }
+}
+
+impl DivAssign<f32> for Rot3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<Rot3A> for f32 {
+    type Output = Rot3A;
+
+    #[inline]
+    fn mul(self, rotor: Rot3A) -> Rot3A {
+        rotor * self
+    }
+}
+
+impl From<Rot3> for Rot3A {
+    #[inline]
+    fn from(r: Rot3) -> Self {
+        Self::new(r.s, r.bv)
+    }
+}
+
+impl From<Rot3A> for Rot3 {
+    #[inline]
+    fn from(r: Rot3A) -> Self {
+        Self::new(r.s(), r.bv())
+    }
+}
@@ -0,0 +1,365 @@
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Rotor4<T> {
+    pub s: T,
+    pub bv: Bivector4<T>,
+    pub xyzw: T,
+}
+
+pub type Rot4 = Rotor4<f32>;
+pub type DRot4 = Rotor4<f64>;
+
+impl<T: Scalar> Rotor4<T> {
+    #[inline]
+    pub fn new(s: T, bv: Bivector4<T>, xyzw: T) -> Self {
+        Self { s, bv, xyzw }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), Bivector4::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn from_angle_plane(angle: T, plane: Bivector4<T>) -> Self {
+        let half_angle = angle / T::two();
+        let (sin, cos) = half_angle.sin_cos();
+        Self::new(cos, plane * -sin, T::zero())
+    }
+
+    // Rotates by angle1 in plane1 and angle2 in plane2, which must be orthogonal
+    #[inline]
+    pub fn from_double_rotation(
+        angle1: T,
+        plane1: Bivector4<T>,
+        angle2: T,
+        plane2: Bivector4<T>,
+    ) -> Self {
+        Self::from_angle_plane(angle1, plane1) * Self::from_angle_plane(angle2, plane2)
+    }
+
+    // self * xyzw
+    #[inline]
+    pub fn dual(&self) -> Self {
+        Self::new(self.xyzw, self.bv.dual(), self.s)
+    }
+}
+
+impl<T: Scalar> Rotor for Rotor4<T> {
+    type Decimal = T;
+    type Bivec = Bivector4<T>;
+    type Vector = Vector4<T>;
+    type Matrix = Matrix4<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        self.dot(*self)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.s * other.s + self.bv.dot(other.bv) + self.xyzw * other.xyzw
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        // self * self.reversed() has scalar and xyzw parts a + b xyzw, and a general sum
+        // of rotors needs dividing by its square root rather than its magnitude to stay
+        // a rotor. (1 +- xyzw) / 2 are idempotent, so the root splits across them.
+        let n = *self * self.reversed();
+        let plus = T::one() / (n.s + n.xyzw).sqrt();
+        let minus = T::one() / (n.s - n.xyzw).sqrt();
+        let (p, q) = ((plus + minus) * T::half(), (plus - minus) * T::half());
+        *self = *self * p + self.dual() * q;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut r = *self;
+        r.normalize();
+        r
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    fn rotate_by(&mut self, other: Self) {
+        *self = other * *self * other.reversed();
+    }
+
+    #[inline]
+    fn rotate_vec(&self, vec: &mut Self::Vector) {
+        let (s, b, q) = (self.s, self.bv, self.xyzw);
+        let fx = s * vec.x + b.xy * vec.y + b.xz * vec.z + b.xw * vec.w;
+        let fy = s * vec.y - b.xy * vec.x + b.yz * vec.z + b.yw * vec.w;
+        let fz = s * vec.z - b.xz * vec.x - b.yz * vec.y + b.zw * vec.w;
+        let fw = s * vec.w - b.xw * vec.x - b.yw * vec.y - b.zw * vec.z;
+        let fxyz = b.xy * vec.z - b.xz * vec.y + b.yz * vec.x + q * vec.w;
+        let fxyw = b.xy * vec.w - b.xw * vec.y + b.yw * vec.x - q * vec.z;
+        let fxzw = b.xz * vec.w - b.xw * vec.z + b.zw * vec.x + q * vec.y;
+        let fyzw = b.yz * vec.w - b.yw * vec.z + b.zw * vec.y - q * vec.x;
+
+        vec.x = s * fx
+            + b.xy * fy
+            + b.xz * fz
+            + b.xw * fw
+            + b.yz * fxyz
+            + b.yw * fxyw
+            + b.zw * fxzw
+            + q * fyzw;
+        vec.y = s * fy - b.xy * fx + b.yz * fz + b.yw * fw - b.xz * fxyz - b.xw * fxyw - q * fxzw
+            + b.zw * fyzw;
+        vec.z = s * fz - b.xz * fx - b.yz * fy + b.zw * fw + b.xy * fxyz + q * fxyw
+            - b.xw * fxzw
+            - b.yw * fyzw;
+        vec.w = s * fw - b.xw * fx - b.yw * fy - b.zw * fz - q * fxyz
+            + b.xy * fxyw
+            + b.xz * fxzw
+            + b.yz * fyzw;
+    }
+
+    #[inline]
+    fn rotated_by(&self, other: Self) -> Self {
+        let mut r = *self;
+        r.rotate_by(other);
+        r
+    }
+
+    #[inline]
+    fn into_matrix(&self) -> Self::Matrix {
+        let one = T::one();
+        let zero = T::zero();
+        Matrix4::new(
+            *self * Vector4::new(one, zero, zero, zero),
+            *self * Vector4::new(zero, one, zero, zero),
+            *self * Vector4::new(zero, zero, one, zero),
+            *self * Vector4::new(zero, zero, zero, one),
+        )
+    }
+
+    #[inline]
+    fn log(&self) -> Self::Bivec {
+        // The inverse of Bivector4::exp, taking each isoclinic part on its own
+        let sqrt_two = T::two().sqrt();
+        let (plus, minus) = self.bv.split_isoclinic();
+        let log_part = |part: Bivector4<T>, cos: T| {
+            let sin = sqrt_two * part.mag();
+            if sin <= T::epsilon() {
+                return part / cos;
+            }
+
+            part * (sin.atan2(cos) / sin)
+        };
+
+        log_part(plus, self.s + self.xyzw) + log_part(minus, self.s - self.xyzw)
+    }
+
+    // log can take the longer arc, so flip to first
+    #[inline]
+    fn interpolate(&self, to: Self, t: Self::Decimal) -> Self {
+        let to = if self.dot(to) < T::zero() { -to } else { to };
+        *self * (self.reversed() * to).powf(t)
+    }
+}
+
+impl<T: Scalar> Add for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.s + rhs.s, self.bv + rhs.bv, self.xyzw + rhs.xyzw)
+    }
+}
+
+impl<T: Scalar> AddAssign for Rotor4<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.s += rhs.s;
+        self.bv += rhs.bv;
+        self.xyzw += rhs.xyzw;
+    }
+}
+
+impl<T: Scalar> Sub for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.s - rhs.s, self.bv - rhs.bv, self.xyzw - rhs.xyzw)
+    }
+}
+
+impl<T: Scalar> SubAssign for Rotor4<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.s -= rhs.s;
+        self.bv -= rhs.bv;
+        self.xyzw -= rhs.xyzw;
+    }
+}
+
+impl<T: Scalar> Neg for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.s, -self.bv, -self.xyzw)
+    }
+}
+
+impl<T: Scalar> Mul for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (a, b) = (self, rhs);
+        Self {
+            s: a.s * b.s - a.bv.dot(b.bv) + a.xyzw * b.xyzw,
+            bv: Bivector4 {
+                xy: a.s * b.bv.xy + a.bv.xy * b.s - a.bv.xz * b.bv.yz - a.bv.xw * b.bv.yw
+                    + a.bv.yz * b.bv.xz
+                    + a.bv.yw * b.bv.xw
+                    - a.bv.zw * b.xyzw
+                    - a.xyzw * b.bv.zw,
+                xz: a.s * b.bv.xz + a.bv.xy * b.bv.yz + a.bv.xz * b.s
+                    - a.bv.xw * b.bv.zw
+                    - a.bv.yz * b.bv.xy
+                    + a.bv.yw * b.xyzw
+                    + a.bv.zw * b.bv.xw
+                    + a.xyzw * b.bv.yw,
+                xw: a.s * b.bv.xw + a.bv.xy * b.bv.yw + a.bv.xz * b.bv.zw + a.bv.xw * b.s
+                    - a.bv.yz * b.xyzw
+                    - a.bv.yw * b.bv.xy
+                    - a.bv.zw * b.bv.xz
+                    - a.xyzw * b.bv.yz,
+                yz: a.s * b.bv.yz - a.bv.xy * b.bv.xz + a.bv.xz * b.bv.xy - a.bv.xw * b.xyzw
+                    + a.bv.yz * b.s
+                    - a.bv.yw * b.bv.zw
+                    + a.bv.zw * b.bv.yw
+                    - a.xyzw * b.bv.xw,
+                yw: a.s * b.bv.yw - a.bv.xy * b.bv.xw
+                    + a.bv.xz * b.xyzw
+                    + a.bv.xw * b.bv.xy
+                    + a.bv.yz * b.bv.zw
+                    + a.bv.yw * b.s
+                    - a.bv.zw * b.bv.yz
+                    + a.xyzw * b.bv.xz,
+                zw: a.s * b.bv.zw - a.bv.xy * b.xyzw - a.bv.xz * b.bv.xw + a.bv.xw * b.bv.xz
+                    - a.bv.yz * b.bv.yw
+                    + a.bv.yw * b.bv.yz
+                    + a.bv.zw * b.s
+                    - a.xyzw * b.bv.xy,
+            },
+            xyzw: a.s * b.xyzw + a.bv.wedge(b.bv) + a.xyzw * b.s,
+        }
+    }
+}
+
+impl<T: Scalar> Mul<Vector4<T>> for Rotor4<T> {
+    type Output = Vector4<T>;
+
+    #[inline]
+    fn mul(self, mut rhs: Vector4<T>) -> Vector4<T> {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Rotor4<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.s *= rhs;
+        self.bv *= rhs;
+        self.xyzw *= rhs;
+    }
+}
+
+impl<T: Scalar> Mul<T> for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.s * rhs, self.bv * rhs, self.xyzw * rhs)
+    }
+}
+
+impl<T: Scalar> Div<T> for Rotor4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.s / rhs, self.bv / rhs, self.xyzw / rhs)
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Rotor4<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.s /= rhs;
+        self.bv /= rhs;
+        self.xyzw /= rhs;
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for Rotor4<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::new(r.s, r.bv.into(), T::zero())
+    }
+}
+
+macro_rules! impl_scalar_rotor4 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Rotor4<$t>> for $t {
+                type Output = Rotor4<$t>;
+
+                #[inline]
+                fn mul(self, rotor: Rotor4<$t>) -> Rotor4<$t> {
+                    rotor * self
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_rotor4![f32, f64];
+
+impl From<Rot4> for DRot4 {
+    #[inline]
+    fn from(r: Rot4) -> Self {
+        Self::new(f64::from(r.s), DBivec4::from(r.bv), f64::from(r.xyzw))
+    }
+}
+
+impl Rot4 {
+    #[inline]
+    pub fn as_drot4(&self) -> DRot4 {
+        DRot4::from(*self)
+    }
+}
+
+impl DRot4 {
+    #[inline]
+    pub fn as_rot4(&self) -> Rot4 {
+        Rot4::new(self.s as f32, self.bv.as_bivec4(), self.xyzw as f32)
+    }
+}
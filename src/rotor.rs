@@ -1,5 +1,9 @@
+use crate::*;
+use std::ops::*;
+
 pub trait Rotor {
     type Decimal;
+    type Bivec;
     type Vector;
     type Matrix;
 
@@ -23,5 +27,58 @@ pub trait Rotor {
 
     fn rotate_vec(&self, vec: &mut Self::Vector);
 
+    // Kept by reference as in the original trait, changing the receiver would
+    // break existing implementations and calls through &R
+    #[allow(clippy::wrong_self_convention)]
     fn into_matrix(&self) -> Self::Matrix;
+
+    fn log(&self) -> Self::Bivec;
+
+    #[inline]
+    fn powf(&self, t: Self::Decimal) -> Self
+    where
+        Self: Sized,
+        Self::Bivec: Bivector + Mul<Self::Decimal, Output = Self::Bivec>,
+        <Self::Bivec as Bivector>::Rotor: Into<Self>,
+    {
+        (self.log() * t).exp().into()
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: Self::Decimal) -> Self
+    where
+        Self: Copy + Neg<Output = Self> + Add<Output = Self> + Mul<Self::Decimal, Output = Self>,
+        Self::Decimal: Scalar,
+    {
+        // r and -r are the same rotation, so take the one on the shorter arc
+        let to = if self.dot(to) < Self::Decimal::zero() {
+            -to
+        } else {
+            to
+        };
+        (*self * (Self::Decimal::one() - t) + to * t).normalized()
+    }
+
+    // The geodesic between the rotations, the same path as interpolate
+    #[inline]
+    fn slerp(&self, to: Self, t: Self::Decimal) -> Self
+    where
+        Self: Copy + Mul<Output = Self>,
+        Self::Bivec: Bivector + Mul<Self::Decimal, Output = Self::Bivec>,
+        <Self::Bivec as Bivector>::Rotor: Into<Self>,
+    {
+        self.interpolate(to, t)
+    }
+
+    // Takes the shorter arc when log does, types whose log can pick the longer
+    // one flip to first
+    #[inline]
+    fn interpolate(&self, to: Self, t: Self::Decimal) -> Self
+    where
+        Self: Copy + Mul<Output = Self>,
+        Self::Bivec: Bivector + Mul<Self::Decimal, Output = Self::Bivec>,
+        <Self::Bivec as Bivector>::Rotor: Into<Self>,
+    {
+        *self * (self.reversed() * to).powf(t)
+    }
 }
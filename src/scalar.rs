@@ -0,0 +1,196 @@
+use std::fmt::Debug;
+use std::ops::*;
+
+pub trait Scalar:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    fn zero() -> Self;
+
+    fn one() -> Self;
+
+    #[inline]
+    fn two() -> Self {
+        Self::one() + Self::one()
+    }
+
+    #[inline]
+    fn half() -> Self {
+        Self::one() / Self::two()
+    }
+
+    fn epsilon() -> Self;
+
+    fn pi() -> Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+
+    fn sqrt(self) -> Self;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    fn sin_cos(self) -> (Self, Self);
+
+    fn tan(self) -> Self;
+
+    fn asin(self) -> Self;
+
+    fn acos(self) -> Self;
+
+    fn atan2(self, other: Self) -> Self;
+
+    fn sinh(self) -> Self;
+
+    fn cosh(self) -> Self;
+
+    fn exp(self) -> Self;
+
+    fn ln(self) -> Self;
+
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    fn min(self, other: Self) -> Self;
+
+    fn max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    [$(($t:ident, $pi:expr)), +] => {
+        $(
+            impl Scalar for $t {
+                #[inline]
+                fn zero() -> Self {
+                    0.0
+                }
+
+                #[inline]
+                fn one() -> Self {
+                    1.0
+                }
+
+                #[inline]
+                fn two() -> Self {
+                    2.0
+                }
+
+                #[inline]
+                fn half() -> Self {
+                    0.5
+                }
+
+                #[inline]
+                fn epsilon() -> Self {
+                    $t::EPSILON
+                }
+
+                #[inline]
+                fn pi() -> Self {
+                    $pi
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    $t::abs(self)
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    $t::signum(self)
+                }
+
+                #[inline]
+                fn sqrt(self) -> Self {
+                    $t::sqrt(self)
+                }
+
+                #[inline]
+                fn sin(self) -> Self {
+                    $t::sin(self)
+                }
+
+                #[inline]
+                fn cos(self) -> Self {
+                    $t::cos(self)
+                }
+
+                #[inline]
+                fn sin_cos(self) -> (Self, Self) {
+                    $t::sin_cos(self)
+                }
+
+                #[inline]
+                fn tan(self) -> Self {
+                    $t::tan(self)
+                }
+
+                #[inline]
+                fn asin(self) -> Self {
+                    $t::asin(self)
+                }
+
+                #[inline]
+                fn acos(self) -> Self {
+                    $t::acos(self)
+                }
+
+                #[inline]
+                fn atan2(self, other: Self) -> Self {
+                    $t::atan2(self, other)
+                }
+
+                #[inline]
+                fn sinh(self) -> Self {
+                    $t::sinh(self)
+                }
+
+                #[inline]
+                fn cosh(self) -> Self {
+                    $t::cosh(self)
+                }
+
+                #[inline]
+                fn exp(self) -> Self {
+                    $t::exp(self)
+                }
+
+                #[inline]
+                fn ln(self) -> Self {
+                    $t::ln(self)
+                }
+
+                #[inline]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    $t::mul_add(self, a, b)
+                }
+
+                #[inline]
+                fn min(self, other: Self) -> Self {
+                    $t::min(self, other)
+                }
+
+                #[inline]
+                fn max(self, other: Self) -> Self {
+                    $t::max(self, other)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar![(f32, std::f32::consts::PI), (f64, std::f64::consts::PI)];
@@ -0,0 +1,195 @@
+// Serialises every type as a tuple of its fields, so a Vec3 is [x, y, z] in JSON
+// and a Rot3 is [s, [xy, xz, yz]]
+use crate::cga3d;
+use crate::pga2d::*;
+use crate::pga3d::*;
+use crate::sta::*;
+use crate::*;
+use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeTuple, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+
+// serde only implements its traits for arrays of up to 32 elements, so the
+// lanes of the wide types are written through these as tuples of N
+struct LanesRef<'a, T, const N: usize>(&'a [T; N]);
+
+struct Lanes<T, const N: usize>([T; N]);
+
+impl<T: Serialize, const N: usize> Serialize for LanesRef<'_, T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for lane in self.0 {
+            tuple.serialize_element(lane)?;
+        }
+        tuple.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Lanes<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct LanesVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for LanesVisitor<T, N> {
+            type Value = Lanes<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a tuple of {} elements", N)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut lanes = Vec::with_capacity(N);
+                for index in 0..N {
+                    let lane = seq
+                        .next_element::<T>()?
+                        .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                    lanes.push(lane);
+                }
+                <[T; N]>::try_from(lanes)
+                    .map(Lanes)
+                    .map_err(|lanes| de::Error::invalid_length(lanes.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_tuple(N, LanesVisitor(PhantomData))
+    }
+}
+
+macro_rules! impl_serde {
+    [$($ty:ident)::+: $($f:ident), +] => {
+        impl<T: Serialize> Serialize for $($ty)::+<T> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut tuple = serializer.serialize_tuple([$(stringify!($f)), +].len())?;
+                $(tuple.serialize_element(&self.$f)?;)+
+                tuple.end()
+            }
+        }
+
+        impl<'de, T: Deserialize<'de>> Deserialize<'de> for $($ty)::+<T> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct TupleVisitor<T>(PhantomData<T>);
+
+                impl<'de, T: Deserialize<'de>> Visitor<'de> for TupleVisitor<T> {
+                    type Value = $($ty)::+<T>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "a tuple of {} elements", [$(stringify!($f)), +].len())
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                        let mut index = 0;
+                        $(
+                            index += 1;
+                            let $f = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(index - 1, &self))?;
+                        )+
+                        Ok($($ty)::+ { $($f), + })
+                    }
+                }
+
+                let len = [$(stringify!($f)), +].len();
+                deserializer.deserialize_tuple(len, TupleVisitor(PhantomData))
+            }
+        }
+    };
+    // Wide types write each component's lanes as a tuple, then nested wide types
+    [$ty:ident<N>: $($f:ident), + $(; $nested:ident)?] => {
+        impl<T: Serialize, const N: usize> Serialize for $ty<T, N> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let len = [$(stringify!($f)), + $(, stringify!($nested))?].len();
+                let mut tuple = serializer.serialize_tuple(len)?;
+                $(tuple.serialize_element(&LanesRef(&self.$f))?;)+
+                $(tuple.serialize_element(&self.$nested)?;)?
+                tuple.end()
+            }
+        }
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for $ty<T, N> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct TupleVisitor<T, const N: usize>(PhantomData<T>);
+
+                impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for TupleVisitor<T, N> {
+                    type Value = $ty<T, N>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        let len = [$(stringify!($f)), + $(, stringify!($nested))?].len();
+                        write!(f, "a tuple of {} elements", len)
+                    }
+
+                    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                        let mut index = 0;
+                        $(
+                            index += 1;
+                            let Lanes($f) = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(index - 1, &self))?;
+                        )+
+                        $(
+                            let $nested = seq
+                                .next_element()?
+                                .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+                        )?
+                        Ok($ty { $($f), + $(, $nested)? })
+                    }
+                }
+
+                let len = [$(stringify!($f)), + $(, stringify!($nested))?].len();
+                deserializer.deserialize_tuple(len, TupleVisitor(PhantomData))
+            }
+        }
+    };
+    // Aligned types go through the type they convert to, so they share its layout
+    [$ty:ident => $scalar:ident] => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                $scalar::from(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                $scalar::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+impl_serde![Vector2: x, y];
+impl_serde![Vector3: x, y, z];
+impl_serde![Vector4: x, y, z, w];
+impl_serde![Bivector2: xy];
+impl_serde![Bivector3: xy, xz, yz];
+impl_serde![Bivector4: xy, xz, xw, yz, yw, zw];
+impl_serde![Trivector3: xyz];
+impl_serde![Rotor2: s, bv];
+impl_serde![Rotor3: s, bv];
+impl_serde![Rotor4: s, bv, xyzw];
+impl_serde![Multivector3: s, v, bv, tv];
+impl_serde![Matrix2: cols];
+impl_serde![Matrix3: cols];
+impl_serde![Matrix4: cols];
+impl_serde![Transformation2: translation, rotation, scale];
+impl_serde![Transformation3: translation, rotation, scale];
+impl_serde![Point2: e20, e01, e12];
+impl_serde![Line2: e1, e2, e0];
+impl_serde![Motor2: s, e12, e20, e01];
+impl_serde![Plane3: e1, e2, e3, e0];
+impl_serde![Line3: e01, e02, e03, e12, e31, e23];
+impl_serde![Point3: e032, e013, e021, e123];
+impl_serde![Motor3: s, e23, e31, e12, e01, e02, e03, e0123];
+impl_serde![cga3d::Multivector: c];
+impl_serde![FourVector: t, x, y, z];
+impl_serde![SpacetimeBivector: e, b];
+impl_serde![LorentzRotor: s, bv, ps];
+impl_serde![WideVector3<N>: x, y, z];
+impl_serde![WideBivector3<N>: xy, xz, yz];
+impl_serde![WideRotor3<N>: s; bv];
+
+#[cfg(feature = "simd")]
+impl_serde![Vec3A => Vec3];
+#[cfg(feature = "simd")]
+impl_serde![Vec4A => Vec4];
+#[cfg(feature = "simd")]
+impl_serde![Rot3A => Rot3];
@@ -0,0 +1,314 @@
+// Four f32 lanes backing the aligned types. SSE2 and NEON are part of the
+// x86_64 and aarch64 baselines, so the backend is picked at compile time and
+// anything else, or the scalar-math feature, falls back to plain arrays.
+// Building with AVX enabled, e.g. -C target-feature=+avx, switches the x86
+// backend to single source permutes, blends and dot product instructions.
+//
+// Lanes are added and multiplied in IEEE single precision like the scalar
+// types and only the order of operations differs, so results agree with them
+// to within about 1e-6 of the magnitudes involved.
+
+use std::ops::*;
+
+// Lane indices for F32x4::shuffle, in the same layout as _MM_SHUFFLE
+macro_rules! shuffle {
+    ($v:expr, [$x:literal, $y:literal, $z:literal, $w:literal]) => {
+        $v.shuffle::<{ $x | ($y << 2) | ($z << 4) | ($w << 6) }>()
+    };
+}
+
+pub(crate) use shuffle;
+
+#[cfg(not(feature = "scalar-math"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature = "sse2"
+))]
+mod backend {
+    use super::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    #[derive(Clone, Copy)]
+    #[repr(transparent)]
+    pub(crate) struct F32x4(pub(super) __m128);
+
+    impl F32x4 {
+        #[inline]
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            Self(unsafe { _mm_set_ps(w, z, y, x) })
+        }
+
+        #[inline]
+        pub fn splat(v: f32) -> Self {
+            Self(unsafe { _mm_set1_ps(v) })
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f32; 4] {
+            let mut a = [0.0; 4];
+            unsafe { _mm_storeu_ps(a.as_mut_ptr(), self.0) };
+            a
+        }
+
+        #[cfg(not(target_feature = "avx"))]
+        #[inline]
+        pub fn shuffle<const MASK: i32>(self) -> Self {
+            Self(unsafe { _mm_shuffle_ps::<MASK>(self.0, self.0) })
+        }
+
+        #[cfg(target_feature = "avx")]
+        #[inline]
+        pub fn shuffle<const MASK: i32>(self) -> Self {
+            Self(unsafe { _mm_permute_ps::<MASK>(self.0) })
+        }
+
+        // x, y and z from self with w from other
+        #[cfg(not(target_feature = "avx"))]
+        #[inline]
+        pub fn with_w_of(self, other: Self) -> Self {
+            unsafe {
+                let zw = _mm_shuffle_ps::<0b11_11_10_10>(self.0, other.0);
+                Self(_mm_shuffle_ps::<0b10_00_01_00>(self.0, zw))
+            }
+        }
+
+        #[cfg(target_feature = "avx")]
+        #[inline]
+        pub fn with_w_of(self, other: Self) -> Self {
+            Self(unsafe { _mm_blend_ps::<0b1000>(self.0, other.0) })
+        }
+
+        // The high nibble picks the lanes to multiply, the low one the lanes
+        // that receive the sum
+        #[cfg(target_feature = "avx")]
+        #[inline]
+        pub fn dot4(self, other: Self) -> Self {
+            Self(unsafe { _mm_dp_ps::<0xff>(self.0, other.0) })
+        }
+
+        #[cfg(target_feature = "avx")]
+        #[inline]
+        pub fn dot3(self, other: Self) -> Self {
+            Self(unsafe { _mm_dp_ps::<0x7f>(self.0, other.0) })
+        }
+    }
+
+    macro_rules! impl_binary_op {
+        [$($op:ident: $f:ident, $intrinsic:ident), +] => {
+            $(
+                impl $op for F32x4 {
+                    type Output = Self;
+
+                    #[inline]
+                    fn $f(self, rhs: Self) -> Self {
+                        Self(unsafe { $intrinsic(self.0, rhs.0) })
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_binary_op![Add: add, _mm_add_ps, Sub: sub, _mm_sub_ps, Mul: mul, _mm_mul_ps, Div: div, _mm_div_ps];
+
+    impl Neg for F32x4 {
+        type Output = Self;
+
+        #[inline]
+        fn neg(self) -> Self {
+            Self(unsafe { _mm_xor_ps(self.0, _mm_set1_ps(-0.0)) })
+        }
+    }
+}
+
+#[cfg(not(feature = "scalar-math"))]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod backend {
+    use super::*;
+    use std::arch::aarch64::*;
+
+    #[derive(Clone, Copy)]
+    #[repr(transparent)]
+    pub(crate) struct F32x4(pub(super) float32x4_t);
+
+    impl F32x4 {
+        #[inline]
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            let a = [x, y, z, w];
+            Self(unsafe { vld1q_f32(a.as_ptr()) })
+        }
+
+        #[inline]
+        pub fn splat(v: f32) -> Self {
+            Self(unsafe { vdupq_n_f32(v) })
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f32; 4] {
+            let mut a = [0.0; 4];
+            unsafe { vst1q_f32(a.as_mut_ptr(), self.0) };
+            a
+        }
+
+        // NEON has no immediate shuffle, LLVM lowers this to lane moves
+        #[inline]
+        pub fn shuffle<const MASK: i32>(self) -> Self {
+            let a = self.to_array();
+            let lane = |i: i32| a[((MASK >> (2 * i)) & 3) as usize];
+            Self::new(lane(0), lane(1), lane(2), lane(3))
+        }
+
+        #[inline]
+        pub fn with_w_of(self, other: Self) -> Self {
+            Self(unsafe { vcopyq_laneq_f32::<3, 3>(self.0, other.0) })
+        }
+    }
+
+    macro_rules! impl_binary_op {
+        [$($op:ident: $f:ident, $intrinsic:ident), +] => {
+            $(
+                impl $op for F32x4 {
+                    type Output = Self;
+
+                    #[inline]
+                    fn $f(self, rhs: Self) -> Self {
+                        Self(unsafe { $intrinsic(self.0, rhs.0) })
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_binary_op![Add: add, vaddq_f32, Sub: sub, vsubq_f32, Mul: mul, vmulq_f32, Div: div, vdivq_f32];
+
+    impl Neg for F32x4 {
+        type Output = Self;
+
+        #[inline]
+        fn neg(self) -> Self {
+            Self(unsafe { vnegq_f32(self.0) })
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "scalar-math",
+    not(any(
+        all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            target_feature = "sse2"
+        ),
+        all(target_arch = "aarch64", target_feature = "neon")
+    ))
+))]
+mod backend {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    #[repr(C, align(16))]
+    pub(crate) struct F32x4(pub(super) [f32; 4]);
+
+    impl F32x4 {
+        #[inline]
+        pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+            Self([x, y, z, w])
+        }
+
+        #[inline]
+        pub fn splat(v: f32) -> Self {
+            Self([v; 4])
+        }
+
+        #[inline]
+        pub fn to_array(self) -> [f32; 4] {
+            self.0
+        }
+
+        #[inline]
+        pub fn shuffle<const MASK: i32>(self) -> Self {
+            let lane = |i: i32| self.0[((MASK >> (2 * i)) & 3) as usize];
+            Self::new(lane(0), lane(1), lane(2), lane(3))
+        }
+
+        #[inline]
+        pub fn with_w_of(self, other: Self) -> Self {
+            Self([self.0[0], self.0[1], self.0[2], other.0[3]])
+        }
+    }
+
+    macro_rules! impl_binary_op {
+        [$($op:ident: $f:ident), +] => {
+            $(
+                impl $op for F32x4 {
+                    type Output = Self;
+
+                    #[inline]
+                    fn $f(self, rhs: Self) -> Self {
+                        let (a, b) = (self.0, rhs.0);
+                        Self([a[0].$f(b[0]), a[1].$f(b[1]), a[2].$f(b[2]), a[3].$f(b[3])])
+                    }
+                }
+            )+
+        };
+    }
+
+    impl_binary_op![Add: add, Sub: sub, Mul: mul, Div: div];
+
+    impl Neg for F32x4 {
+        type Output = Self;
+
+        #[inline]
+        fn neg(self) -> Self {
+            Self([-self.0[0], -self.0[1], -self.0[2], -self.0[3]])
+        }
+    }
+}
+
+pub(crate) use backend::F32x4;
+
+impl F32x4 {
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(0.0)
+    }
+
+    #[inline]
+    pub fn x(self) -> f32 {
+        self.to_array()[0]
+    }
+
+    // The sum of all lanes, in every lane
+    #[inline]
+    pub fn sum(self) -> Self {
+        let pairs = self + shuffle!(self, [1, 0, 3, 2]);
+        pairs + shuffle!(pairs, [2, 3, 0, 1])
+    }
+
+    #[cfg(not(all(not(feature = "scalar-math"), target_feature = "avx")))]
+    #[inline]
+    pub fn dot4(self, other: Self) -> Self {
+        (self * other).sum()
+    }
+
+    #[cfg(not(all(not(feature = "scalar-math"), target_feature = "avx")))]
+    #[inline]
+    pub fn dot3(self, other: Self) -> Self {
+        (self * other).with_w_of(Self::zero()).sum()
+    }
+
+    // The cross product of the x, y and z lanes, w is zero for finite lanes
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        shuffle!(self, [1, 2, 0, 3]) * shuffle!(other, [2, 0, 1, 3])
+            - shuffle!(self, [2, 0, 1, 3]) * shuffle!(other, [1, 2, 0, 3])
+    }
+}
+
+impl PartialEq for F32x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
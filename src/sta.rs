@@ -0,0 +1,476 @@
+// Spacetime algebra, Cl(1,3) with g0 * g0 = 1 and gi * gi = -1 for the spatial
+// g1, g2, g3. Bivectors are written relative to the observer g0 through the
+// relative vectors si = gi * g0, which square to 1 like the basis of Vector3,
+// and the pseudoscalar I = g0123, which squares to -1. The even subalgebra is
+// then the complexified Pauli algebra, with I as the imaginary unit.
+use crate::*;
+use std::cmp::Ordering;
+use std::ops::*;
+
+// The four-vector g0 * t + g1 * x + g2 * y + g3 * z
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct FourVector<T> {
+    pub t: T,
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// The bivector e.x * s1 + e.y * s2 + e.z * s3 + I * (b.x * s1 + b.y * s2 + b.z * s3),
+// an electromagnetic field F = E + IB or the generator of a Lorentz rotor.
+// Spatial bivectors use the dual of Bivector3, so I * s3 is the xy plane.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct SpacetimeBivector<T> {
+    pub e: Vector3<T>,
+    pub b: Vector3<T>,
+}
+
+// An even multivector s + bv + I * ps. Unit ones are Lorentz rotors, combining
+// boosts with spatial rotations.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct LorentzRotor<T> {
+    pub s: T,
+    pub bv: SpacetimeBivector<T>,
+    pub ps: T,
+}
+
+// Complex numbers re + I * im, as (re, im)
+#[inline]
+fn complex_mul<T: Scalar>(a: (T, T), b: (T, T)) -> (T, T) {
+    (a.0 * b.0 - a.1 * b.1, a.0 * b.1 + a.1 * b.0)
+}
+
+#[inline]
+fn complex_sqrt<T: Scalar>(z: (T, T)) -> (T, T) {
+    let r = (z.0 * z.0 + z.1 * z.1).sqrt();
+    let re = ((r + z.0) * T::half()).max(T::zero()).sqrt();
+    let im = ((r - z.0) * T::half()).max(T::zero()).sqrt();
+    (re, if z.1 < T::zero() { -im } else { im })
+}
+
+#[inline]
+fn complex_div<T: Scalar>(a: (T, T), b: (T, T)) -> (T, T) {
+    let d = b.0 * b.0 + b.1 * b.1;
+    ((a.0 * b.0 + a.1 * b.1) / d, (a.1 * b.0 - a.0 * b.1) / d)
+}
+
+impl<T: Scalar> FourVector<T> {
+    #[inline]
+    pub fn new(t: T, x: T, y: T, z: T) -> Self {
+        Self { t, x, y, z }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn from_time_space(t: T, space: Vector3<T>) -> Self {
+        Self::new(t, space.x, space.y, space.z)
+    }
+
+    // The four-velocity of a particle moving with velocity v, in units of c.
+    // Only speeds below c have one.
+    #[inline]
+    pub fn try_from_velocity(v: Vector3<T>) -> Option<Self> {
+        let speed_sq = v.mag_sq();
+        // Also rejects a NaN speed
+        if speed_sq.partial_cmp(&T::one()) != Some(Ordering::Less) {
+            return None;
+        }
+
+        let gamma = T::one() / (T::one() - speed_sq).sqrt();
+        Some(Self::from_time_space(gamma, v * gamma))
+    }
+
+    #[inline]
+    pub fn from_velocity(v: Vector3<T>) -> Self {
+        match Self::try_from_velocity(v) {
+            Some(u) => u,
+            None => panic!("{} needs a speed below c", std::any::type_name::<Self>()),
+        }
+    }
+
+    #[inline]
+    pub fn space(&self) -> Vector3<T> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    // The velocity seen by the g0 observer, in units of c
+    #[inline]
+    pub fn velocity(&self) -> Vector3<T> {
+        self.space() / self.t
+    }
+
+    #[inline]
+    pub fn dot(&self, other: Self) -> T {
+        self.t * other.t - self.space().dot(other.space())
+    }
+
+    // The spacetime interval, positive for timelike vectors
+    #[inline]
+    pub fn mag_sq(&self) -> T {
+        self.dot(*self)
+    }
+
+    #[inline]
+    pub fn is_timelike(&self) -> bool {
+        self.mag_sq() > T::zero()
+    }
+
+    #[inline]
+    pub fn is_spacelike(&self) -> bool {
+        self.mag_sq() < T::zero()
+    }
+
+    #[inline]
+    pub fn wedge(&self, other: Self) -> SpacetimeBivector<T> {
+        SpacetimeBivector::new(
+            self.space() * other.t - other.space() * self.t,
+            -self.space().cross(other.space()),
+        )
+    }
+
+    // The space-time split self * g0 = t + x, as an even multivector
+    #[inline]
+    fn split(&self) -> LorentzRotor<T> {
+        LorentzRotor::new(
+            self.t,
+            SpacetimeBivector::new(self.space(), Vector3::zero()),
+            T::zero(),
+        )
+    }
+}
+
+impl<T: Scalar> SpacetimeBivector<T> {
+    #[inline]
+    pub fn new(e: Vector3<T>, b: Vector3<T>) -> Self {
+        Self { e, b }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(Vector3::zero(), Vector3::zero())
+    }
+
+    // The electromagnetic field F = E + IB
+    #[inline]
+    pub fn from_field(e: Vector3<T>, b: Vector3<T>) -> Self {
+        Self::new(e, b)
+    }
+
+    // The generator rapidity * direction, half of which exponentiates to from_boost
+    #[inline]
+    pub fn from_rapidity(rapidity: T, direction: Vector3<T>) -> Self {
+        Self::new(direction.normalized() * rapidity, Vector3::zero())
+    }
+
+    // Multiplies by the pseudoscalar, swapping the electric and magnetic parts
+    #[inline]
+    pub fn dual(&self) -> Self {
+        Self::new(-self.b, self.e)
+    }
+
+    // The Lorentz invariants (E^2 - B^2, E.B), from F * F = E^2 - B^2 + 2I * E.B
+    #[inline]
+    pub fn invariants(&self) -> (T, T) {
+        (self.e.mag_sq() - self.b.mag_sq(), self.e.dot(self.b))
+    }
+
+    #[inline]
+    pub fn exp(&self) -> LorentzRotor<T> {
+        // F * F is the complex scalar z, so exp(F) = cosh(sqrt(z)) + F * sinh(sqrt(z)) / sqrt(z)
+        let (re, im) = self.invariants();
+        let w = complex_sqrt((re, im * T::two()));
+        let (sin, cos) = w.1.sin_cos();
+        let cosh = (w.0.cosh() * cos, w.0.sinh() * sin);
+        let sinh = (w.0.sinh() * cos, w.0.cosh() * sin);
+        let sinhc = if w.0.abs() + w.1.abs() <= T::epsilon() {
+            (T::one(), T::zero())
+        } else {
+            complex_div(sinh, w)
+        };
+
+        LorentzRotor::new(cosh.0, SpacetimeBivector::zero(), cosh.1)
+            + LorentzRotor::new(T::zero(), *self, T::zero()).mul_complex(sinhc)
+    }
+}
+
+impl<T: Scalar> LorentzRotor<T> {
+    #[inline]
+    pub fn new(s: T, bv: SpacetimeBivector<T>, ps: T) -> Self {
+        Self { s, bv, ps }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(T::one(), SpacetimeBivector::zero(), T::zero())
+    }
+
+    // Boosts along direction by the rapidity, so g0 becomes
+    // g0 * cosh(rapidity) + direction * sinh(rapidity)
+    #[inline]
+    pub fn from_boost(rapidity: T, direction: Vector3<T>) -> Self {
+        let half = rapidity * T::half();
+        Self::new(
+            half.cosh(),
+            SpacetimeBivector::from_rapidity(half.sinh(), direction),
+            T::zero(),
+        )
+    }
+
+    // Boosts a particle at rest to velocity v, in units of c. The rapidity is
+    // infinite at c, so only speeds below it have a boost.
+    #[inline]
+    pub fn try_from_velocity(v: Vector3<T>) -> Option<Self> {
+        let speed = v.mag();
+        if speed.partial_cmp(&T::one()) != Some(Ordering::Less) {
+            return None;
+        }
+        if speed <= T::epsilon() {
+            return Some(Self::identity());
+        }
+
+        let rapidity = ((T::one() + speed) / (T::one() - speed)).ln() * T::half();
+        Some(Self::from_boost(rapidity, v))
+    }
+
+    #[inline]
+    pub fn from_velocity(v: Vector3<T>) -> Self {
+        match Self::try_from_velocity(v) {
+            Some(r) => r,
+            None => panic!("{} needs a speed below c", std::any::type_name::<Self>()),
+        }
+    }
+
+    #[inline]
+    pub fn from_rot3(r: Rotor3<T>) -> Self {
+        Self::new(r.s, SpacetimeBivector::from(r.bv), T::zero())
+    }
+
+    // Splits into a pure boost and a spatial rotation, self == boost * rotation
+    pub fn to_boost_rot3(&self) -> (Self, Rotor3<T>) {
+        let u = *self * FourVector::new(T::one(), T::zero(), T::zero(), T::zero());
+        let k = (T::two() * (T::one() + u.t)).sqrt();
+        let boost = Self::new(
+            (T::one() + u.t) / k,
+            SpacetimeBivector::new(u.space() / k, Vector3::zero()),
+            T::zero(),
+        );
+        let rotation = boost.reversed() * *self;
+        (boost, Rotor3::new(rotation.s, rotation.bv.b.dual()))
+    }
+
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    pub fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    pub fn normalize(&mut self) {
+        let n = *self * self.reversed();
+        *self = self.mul_complex(complex_div(
+            (T::one(), T::zero()),
+            complex_sqrt((n.s, n.ps)),
+        ));
+    }
+
+    #[inline]
+    pub fn normalized(&self) -> Self {
+        let mut r = *self;
+        r.normalize();
+        r
+    }
+
+    #[inline]
+    pub fn rotate_vec(&self, v: &mut FourVector<T>) {
+        // (R * v * R~) * g0 = R * (v * g0) * (g0 * R~ * g0), all even
+        let bar = Self::new(
+            self.s,
+            SpacetimeBivector::new(self.bv.e, -self.bv.b),
+            -self.ps,
+        );
+        let r = *self * v.split() * bar;
+        *v = FourVector::from_time_space(r.s, r.bv.e);
+    }
+
+    #[inline]
+    pub fn rotate_bivector(&self, bv: &mut SpacetimeBivector<T>) {
+        let r = *self * Self::new(T::zero(), *bv, T::zero()) * self.reversed();
+        *bv = r.bv;
+    }
+
+    // Multiplies by the complex scalar z.0 + I * z.1
+    #[inline]
+    fn mul_complex(&self, z: (T, T)) -> Self {
+        let dual = Self::new(-self.ps, self.bv.dual(), self.s);
+        *self * z.0 + dual * z.1
+    }
+}
+
+impl<T: Scalar> Mul for LorentzRotor<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        // The Pauli product (a + u) * (b + v) = ab + u.v + av + bu + I * (u x v),
+        // where a, b are complex scalars and u, v complex vectors
+        let (s1, p1, e1, b1) = (self.s, self.ps, self.bv.e, self.bv.b);
+        let (s2, p2, e2, b2) = (rhs.s, rhs.ps, rhs.bv.e, rhs.bv.b);
+        let (re, im) = complex_mul((s1, p1), (s2, p2));
+        Self::new(
+            re + e1.dot(e2) - b1.dot(b2),
+            SpacetimeBivector::new(
+                e2 * s1 - b2 * p1 + e1 * s2 - b1 * p2 - (e1.cross(b2) + b1.cross(e2)),
+                b2 * s1 + e2 * p1 + b1 * s2 + e1 * p2 + e1.cross(e2) - b1.cross(b2),
+            ),
+            im + e1.dot(b2) + b1.dot(e2),
+        )
+    }
+}
+
+impl<T: Scalar> MulAssign for LorentzRotor<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> Mul<FourVector<T>> for LorentzRotor<T> {
+    type Output = FourVector<T>;
+
+    #[inline]
+    fn mul(self, mut rhs: FourVector<T>) -> FourVector<T> {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar> Mul<SpacetimeBivector<T>> for LorentzRotor<T> {
+    type Output = SpacetimeBivector<T>;
+
+    #[inline]
+    fn mul(self, mut rhs: SpacetimeBivector<T>) -> SpacetimeBivector<T> {
+        self.rotate_bivector(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar> Mul for FourVector<T> {
+    type Output = LorentzRotor<T>;
+
+    // The geometric product, self.dot(rhs) + self.wedge(rhs)
+    #[inline]
+    fn mul(self, rhs: Self) -> LorentzRotor<T> {
+        LorentzRotor::new(self.dot(rhs), self.wedge(rhs), T::zero())
+    }
+}
+
+impl<T: Scalar> Mul for SpacetimeBivector<T> {
+    type Output = LorentzRotor<T>;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> LorentzRotor<T> {
+        LorentzRotor::new(T::zero(), self, T::zero()) * LorentzRotor::new(T::zero(), rhs, T::zero())
+    }
+}
+
+macro_rules! impl_ops_sta {
+    [$ty:ident: $($f:ident), +] => {
+        impl<T: Scalar> Add for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($f: self.$f + rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Sub for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($f: self.$f - rhs.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Neg for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f), + }
+            }
+        }
+
+        impl<T: Scalar> Mul<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: T) -> Self {
+                Self { $($f: self.$f * rhs), + }
+            }
+        }
+
+        impl<T: Scalar> MulAssign<T> for $ty<T> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: T) {
+                $(self.$f *= rhs;)+
+            }
+        }
+
+        impl<T: Scalar> Div<T> for $ty<T> {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: T) -> Self {
+                Self { $($f: self.$f / rhs), + }
+            }
+        }
+
+        impl<T: Scalar> DivAssign<T> for $ty<T> {
+            #[inline]
+            fn div_assign(&mut self, rhs: T) {
+                $(self.$f /= rhs;)+
+            }
+        }
+    };
+}
+
+impl_ops_sta![FourVector: t, x, y, z];
+impl_ops_sta![SpacetimeBivector: e, b];
+impl_ops_sta![LorentzRotor: s, bv, ps];
+
+impl<T: Scalar> From<Vector4<T>> for FourVector<T> {
+    // Takes w as the time component
+    #[inline]
+    fn from(v: Vector4<T>) -> Self {
+        Self::new(v.w, v.x, v.y, v.z)
+    }
+}
+
+impl<T: Scalar> From<Bivector3<T>> for SpacetimeBivector<T> {
+    #[inline]
+    fn from(bv: Bivector3<T>) -> Self {
+        Self::new(Vector3::zero(), bv.dual())
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for LorentzRotor<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::from_rot3(r)
+    }
+}
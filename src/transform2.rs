@@ -0,0 +1,214 @@
+use crate::*;
+use std::ops::*;
+
+// Scales uniformly, then rotates, then translates, like Transformation3
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Transformation2<T> {
+    pub translation: Vector2<T>,
+    pub rotation: Rotor2<T>,
+    pub scale: T,
+}
+
+pub type Transform2 = Transformation2<f32>;
+pub type DTransform2 = Transformation2<f64>;
+
+impl<T: Scalar> Transformation2<T> {
+    #[inline]
+    pub fn new(translation: Vector2<T>, rotation: Rotor2<T>, scale: T) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(Vector2::zero(), Rotor2::identity(), T::one())
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector2<T>) -> Self {
+        Self::new(translation, Rotor2::identity(), T::one())
+    }
+
+    #[inline]
+    pub fn from_rotation(rotation: Rotor2<T>) -> Self {
+        Self::new(Vector2::zero(), rotation, T::one())
+    }
+
+    #[inline]
+    pub fn from_scale(scale: T) -> Self {
+        Self::new(Vector2::zero(), Rotor2::identity(), scale)
+    }
+
+    // Places an object at position with its right axis facing target
+    #[inline]
+    pub fn look_at(position: Vector2<T>, target: Vector2<T>) -> Self {
+        Self::look_to(position, target - position)
+    }
+
+    #[inline]
+    pub fn look_to(position: Vector2<T>, direction: Vector2<T>) -> Self {
+        let rotation = Rotor2::from_rotation_between(Vector2::right(), direction);
+        Self::new(position, rotation, T::one())
+    }
+
+    // Decomposes an affine matrix without shear or non uniform scale. In 2D a
+    // negative scale is a half turn rather than a mirror, so matrices with a
+    // reflection (det <= 0) have no decomposition.
+    pub fn try_from_matrix(m: &Matrix3<T>) -> Option<Self> {
+        let linear = Matrix2::new(m.cols[0].into(), m.cols[1].into());
+        if linear.determinant() <= T::zero() {
+            return None;
+        }
+
+        let scale = (linear.cols[0].mag() + linear.cols[1].mag()) * T::half();
+        let rotation = Rotor2::from_matrix(&(linear / scale));
+        Some(Self::new(m.cols[2].into(), rotation, scale))
+    }
+
+    #[inline]
+    pub fn from_matrix(m: &Matrix3<T>) -> Self {
+        match Self::try_from_matrix(m) {
+            Some(t) => t,
+            None => panic!(
+                "{} cannot represent a reflection",
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+
+    // By reference like the other methods, and like Rotor::into_matrix
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_matrix(&self) -> Matrix3<T> {
+        let linear = self.rotation.into_matrix() * self.scale;
+        Matrix3::new(
+            linear.cols[0].into(),
+            linear.cols[1].into(),
+            Vector3::new(self.translation.x, self.translation.y, T::one()),
+        )
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: Vector2<T>) -> Vector2<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    // Scales and rotates, ignoring the translation
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector2<T>) -> Vector2<T> {
+        self.rotation * vector * self.scale
+    }
+
+    pub fn transform_points(&self, points: &mut [Vector2<T>]) {
+        let linear = self.rotation.into_matrix() * self.scale;
+        for p in points {
+            *p = linear * *p + self.translation;
+        }
+    }
+
+    pub fn transform_vectors(&self, vectors: &mut [Vector2<T>]) {
+        let linear = self.rotation.into_matrix() * self.scale;
+        for v in vectors {
+            *v = linear * *v;
+        }
+    }
+
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        if self.scale == T::zero() {
+            return None;
+        }
+
+        let scale = T::one() / self.scale;
+        let rotation = self.rotation.reversed();
+        let translation = rotation * -self.translation * scale;
+        Some(Self::new(translation, rotation, scale))
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+
+    #[inline]
+    pub fn interpolate(&self, to: Self, t: T) -> Self {
+        Self::new(
+            self.translation.lerp(to.translation, t),
+            self.rotation.slerp(to.rotation, t),
+            self.scale + (to.scale - self.scale) * t,
+        )
+    }
+}
+
+impl<T: Scalar> Default for Transformation2<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Scalar> Mul for Transformation2<T> {
+    type Output = Self;
+
+    // Applies rhs first, then self
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl<T: Scalar> MulAssign for Transformation2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> From<Transformation2<T>> for Matrix3<T> {
+    #[inline]
+    fn from(t: Transformation2<T>) -> Self {
+        t.into_matrix()
+    }
+}
+
+impl<T: Scalar> From<Rotor2<T>> for Transformation2<T> {
+    #[inline]
+    fn from(r: Rotor2<T>) -> Self {
+        Self::from_rotation(r)
+    }
+}
+
+impl From<Transform2> for DTransform2 {
+    #[inline]
+    fn from(t: Transform2) -> Self {
+        Self::new(t.translation.into(), t.rotation.into(), f64::from(t.scale))
+    }
+}
+
+impl Transform2 {
+    #[inline]
+    pub fn as_dtransform2(&self) -> DTransform2 {
+        DTransform2::from(*self)
+    }
+}
+
+impl DTransform2 {
+    #[inline]
+    pub fn as_transform2(&self) -> Transform2 {
+        Transform2::new(
+            self.translation.as_vec2(),
+            self.rotation.as_rot2(),
+            self.scale as f32,
+        )
+    }
+}
@@ -0,0 +1,222 @@
+use crate::*;
+use std::ops::*;
+
+// Scales uniformly, then rotates, then translates. A single scale factor keeps
+// composition and inverse exact, which a scale per axis would not.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Transformation3<T> {
+    pub translation: Vector3<T>,
+    pub rotation: Rotor3<T>,
+    pub scale: T,
+}
+
+pub type Transform3 = Transformation3<f32>;
+pub type DTransform3 = Transformation3<f64>;
+
+impl<T: Scalar> Transformation3<T> {
+    #[inline]
+    pub fn new(translation: Vector3<T>, rotation: Rotor3<T>, scale: T) -> Self {
+        Self {
+            translation,
+            rotation,
+            scale,
+        }
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(Vector3::zero(), Rotor3::identity(), T::one())
+    }
+
+    #[inline]
+    pub fn from_translation(translation: Vector3<T>) -> Self {
+        Self::new(translation, Rotor3::identity(), T::one())
+    }
+
+    #[inline]
+    pub fn from_rotation(rotation: Rotor3<T>) -> Self {
+        Self::new(Vector3::zero(), rotation, T::one())
+    }
+
+    #[inline]
+    pub fn from_scale(scale: T) -> Self {
+        Self::new(Vector3::zero(), Rotor3::identity(), scale)
+    }
+
+    // Places an object at eye with its forward axis facing target and its up
+    // axis as close to up as possible, or to another world axis if up is
+    // parallel to the direction
+    #[inline]
+    pub fn look_at(eye: Vector3<T>, target: Vector3<T>, up: Vector3<T>) -> Self {
+        Self::look_to(eye, target - eye, up)
+    }
+
+    pub fn look_to(eye: Vector3<T>, direction: Vector3<T>, up: Vector3<T>) -> Self {
+        let forward = direction.normalized();
+        let right = Vector3::look_up(forward, up).cross(forward).normalized();
+        let up = forward.cross(right);
+        let rotation = Rotor3::from_matrix(&Matrix3::new(right, up, forward));
+        Self::new(eye, rotation, T::one())
+    }
+
+    // Decomposes an affine matrix without shear or non uniform scale. A mirrored
+    // matrix (det < 0) comes back with a negative scale, since in 3D every
+    // reflection of that kind is a rotation composed with -1. A singular matrix
+    // (det == 0) has no decomposition.
+    pub fn try_from_matrix(m: &Matrix4<T>) -> Option<Self> {
+        let linear = Matrix3::new(m.cols[0].into(), m.cols[1].into(), m.cols[2].into());
+        let det = linear.determinant();
+        if det == T::zero() {
+            return None;
+        }
+
+        let three = T::one() + T::two();
+        let mag = (linear.cols[0].mag() + linear.cols[1].mag() + linear.cols[2].mag()) / three;
+        let scale = mag * det.signum();
+        let rotation = Rotor3::from_matrix_orthonormalized(&(linear / scale));
+        Some(Self::new(m.cols[3].into(), rotation, scale))
+    }
+
+    #[inline]
+    pub fn from_matrix(m: &Matrix4<T>) -> Self {
+        match Self::try_from_matrix(m) {
+            Some(t) => t,
+            None => panic!(
+                "{} cannot represent a singular matrix",
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+
+    // By reference like the other methods, and like Rotor::into_matrix
+    #[allow(clippy::wrong_self_convention)]
+    pub fn into_matrix(&self) -> Matrix4<T> {
+        let mut m = Matrix4::from_rot3_translation(self.rotation, self.translation);
+        for i in 0..3 {
+            m.cols[i] *= self.scale;
+        }
+        m
+    }
+
+    #[inline]
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        self.transform_vector(point) + self.translation
+    }
+
+    // Scales and rotates, ignoring the translation
+    #[inline]
+    pub fn transform_vector(&self, vector: Vector3<T>) -> Vector3<T> {
+        self.rotation * vector * self.scale
+    }
+
+    pub fn transform_points(&self, points: &mut [Vector3<T>]) {
+        let linear = self.rotation.into_matrix() * self.scale;
+        for p in points {
+            *p = linear * *p + self.translation;
+        }
+    }
+
+    pub fn transform_vectors(&self, vectors: &mut [Vector3<T>]) {
+        let linear = self.rotation.into_matrix() * self.scale;
+        for v in vectors {
+            *v = linear * *v;
+        }
+    }
+
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        if self.scale == T::zero() {
+            return None;
+        }
+
+        let scale = T::one() / self.scale;
+        let rotation = self.rotation.reversed();
+        let translation = rotation * -self.translation * scale;
+        Some(Self::new(translation, rotation, scale))
+    }
+
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        match self.try_inverse() {
+            Some(inv) => inv,
+            None => panic!("{} is not invertible", std::any::type_name::<Self>()),
+        }
+    }
+
+    #[inline]
+    pub fn interpolate(&self, to: Self, t: T) -> Self {
+        Self::new(
+            self.translation.lerp(to.translation, t),
+            self.rotation.slerp(to.rotation, t),
+            self.scale + (to.scale - self.scale) * t,
+        )
+    }
+}
+
+impl<T: Scalar> Default for Transformation3<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<T: Scalar> Mul for Transformation3<T> {
+    type Output = Self;
+
+    // Applies rhs first, then self
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.transform_point(rhs.translation),
+            self.rotation * rhs.rotation,
+            self.scale * rhs.scale,
+        )
+    }
+}
+
+impl<T: Scalar> MulAssign for Transformation3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar> From<Transformation3<T>> for Matrix4<T> {
+    #[inline]
+    fn from(t: Transformation3<T>) -> Self {
+        t.into_matrix()
+    }
+}
+
+impl<T: Scalar> From<Rotor3<T>> for Transformation3<T> {
+    #[inline]
+    fn from(r: Rotor3<T>) -> Self {
+        Self::from_rotation(r)
+    }
+}
+
+impl From<Transform3> for DTransform3 {
+    #[inline]
+    fn from(t: Transform3) -> Self {
+        Self::new(t.translation.into(), t.rotation.into(), f64::from(t.scale))
+    }
+}
+
+impl Transform3 {
+    #[inline]
+    pub fn as_dtransform3(&self) -> DTransform3 {
+        DTransform3::from(*self)
+    }
+}
+
+impl DTransform3 {
+    #[inline]
+    pub fn as_transform3(&self) -> Transform3 {
+        Transform3::new(
+            self.translation.as_vec3(),
+            self.rotation.as_rot3(),
+            self.scale as f32,
+        )
+    }
+}
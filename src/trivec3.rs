@@ -0,0 +1,200 @@
+use crate::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Trivector3<T> {
+    pub xyz: T,
+}
+
+pub type Trivec3 = Trivector3<f32>;
+pub type DTrivec3 = Trivector3<f64>;
+
+impl<T: Scalar> Trivector3<T> {
+    #[inline]
+    pub fn new(xyz: T) -> Self {
+        Self { xyz }
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::new(T::one())
+    }
+
+    #[inline]
+    pub fn mag(&self) -> T {
+        self.xyz.abs()
+    }
+
+    #[inline]
+    pub fn mag_sq(&self) -> T {
+        self.xyz * self.xyz
+    }
+
+    #[inline]
+    pub fn dual(&self) -> T {
+        self.xyz
+    }
+}
+
+impl<T: Scalar> Add for Trivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.xyz + rhs.xyz)
+    }
+}
+
+impl<T: Scalar> AddAssign for Trivector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.xyz += rhs.xyz;
+    }
+}
+
+impl<T: Scalar> Sub for Trivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.xyz - rhs.xyz)
+    }
+}
+
+impl<T: Scalar> SubAssign for Trivector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.xyz -= rhs.xyz;
+    }
+}
+
+impl<T: Scalar> Mul for Trivector3<T> {
+    type Output = T;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> T {
+        // xyz * xyz = -1
+        -(self.xyz * rhs.xyz)
+    }
+}
+
+impl<T: Scalar> Mul<Vector3<T>> for Trivector3<T> {
+    type Output = Bivector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Vector3<T>) -> Bivector3<T> {
+        rhs.dual() * self.xyz
+    }
+}
+
+impl<T: Scalar> Mul<Trivector3<T>> for Vector3<T> {
+    type Output = Bivector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Trivector3<T>) -> Bivector3<T> {
+        rhs * self
+    }
+}
+
+impl<T: Scalar> Mul<Bivector3<T>> for Trivector3<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Bivector3<T>) -> Vector3<T> {
+        rhs.dual() * -self.xyz
+    }
+}
+
+impl<T: Scalar> Mul<Trivector3<T>> for Bivector3<T> {
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn mul(self, rhs: Trivector3<T>) -> Vector3<T> {
+        rhs * self
+    }
+}
+
+impl<T: Scalar> Mul<T> for Trivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.xyz * rhs)
+    }
+}
+
+impl<T: Scalar> MulAssign<T> for Trivector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.xyz *= rhs;
+    }
+}
+
+impl<T: Scalar> Div<T> for Trivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.xyz / rhs)
+    }
+}
+
+impl<T: Scalar> DivAssign<T> for Trivector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.xyz /= rhs;
+    }
+}
+
+impl<T: Scalar> Neg for Trivector3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.xyz)
+    }
+}
+
+macro_rules! impl_scalar_trivec3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Trivector3<$t>> for $t {
+                type Output = Trivector3<$t>;
+
+                #[inline]
+                fn mul(self, rhs: Trivector3<$t>) -> Trivector3<$t> {
+                    Trivector3::new(self * rhs.xyz)
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar_trivec3![f32, f64];
+
+impl From<Trivec3> for DTrivec3 {
+    #[inline]
+    fn from(tv: Trivec3) -> Self {
+        Self::new(f64::from(tv.xyz))
+    }
+}
+
+impl Trivec3 {
+    #[inline]
+    pub fn as_dtrivec3(&self) -> DTrivec3 {
+        DTrivec3::from(*self)
+    }
+}
+
+impl DTrivec3 {
+    #[inline]
+    pub fn as_trivec3(&self) -> Trivec3 {
+        Trivec3::new(self.xyz as f32)
+    }
+}
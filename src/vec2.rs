@@ -1,379 +1,417 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_vec2 {
-    [$(($t:ident, $nam:ident, $v3:ident, $v4:ident, $bv:ident, $rot:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub x: $t,
-                pub y: $t,
-            }
-
-            impl $nam{
-                #[inline]
-                pub fn new(x: $t, y: $t) -> Self {
-                    Self { x, y }
-                }
-
-                #[inline]
-                pub fn uni(val: $t) -> Self {
-                    Self::new(val, val)
-                }
-
-                #[inline]
-                pub fn zero() -> Self {
-                    Self::uni(0.0)
-                }
-
-                #[inline]
-                pub fn one() -> Self {
-                    Self::uni(1.0)
-                }
-
-                #[inline]
-                pub fn up() -> Self {
-                    Self::new(0.0, 1.0)
-                }
-
-                #[inline]
-                pub fn down() -> Self {
-                    Self::new(0.0, -1.0)
-                }
-
-                #[inline]
-                pub fn right() -> Self {
-                    Self::new(1.0, 0.0)
-                }
-
-                #[inline]
-                pub fn left() -> Self {
-                    Self::new(-1.0, 0.0)
-                }
-            }
-
-            impl Vector for Vec2 {
-                type Decimal = $t;
-                type Bivec = $bv;
-                type Rotor = $rot;
-
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.mag_sq().sqrt()
-                }
-
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    (self.x * self.x) + (self.y * self.y)
-                }
-
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    self.x * other.x + self.y * other.y
-                }
-
-                #[inline]
-                fn wedge(&self, other: Self) -> Self::Bivec {
-                    Self::Bivec::new(self.x * other.y - self.y * other.x)
-                }
-
-                #[inline]
-                fn geom(&self, other: Self) -> Self::Rotor {
-                    Self::Rotor::new(self.dot(other), self.wedge(other))
-                }
-
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    *self /= mag;
-                }
-
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut v = self.clone();
-                    v.normalize();
-                    v
-                }
-
-                #[inline]
-                fn project(&mut self, other: Self) {
-                    *self = (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn projected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. project(other);
-                    v
-                }
-
-                #[inline]
-                fn reject(&mut self, other: Self) {
-                    // self = self - self.project(other)
-                    *self -= (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn rejected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reject(other);
-                    v
-                }
-
-                #[inline]
-                fn reflect(&mut self, other: Self) {
-                    // self = self - 2 * self.project(other)
-                    *self -= 2.0 * (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn reflected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect(other);
-                    v
-                }
-
-                #[inline]
-                fn reflect_normal(&mut self, normal: Self) {
-                    // self = self - 2 * self.project(normal)
-                    *self -= 2.0 * self.dot(normal) * normal;
-                }
-
-                #[inline]
-                fn reflected_normal(&self, normal: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect_normal(normal);
-                    v
-                }
-
-                #[inline]
-                fn to(&self, other: Self) -> Self {
-                    other - *self
-                }
-
-                #[inline]
-                fn distance(&self, other: Self) -> Self::Decimal {
-                    self.to(other).mag()
-                }
-
-                #[inline]
-                fn angle_between(&self, other: Self) -> Self::Decimal {
-                    self.normalized().dot(other.normalized()).acos()
-                }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Vector2<T> {
+    pub x: T,
+    pub y: T,
+}
 
-                #[inline]
-                fn angle_between_normal(&self, normal: Self) -> Self::Decimal {
-                    self.dot(normal).acos()
-                }
+pub type Vec2 = Vector2<f32>;
+pub type DVec2 = Vector2<f64>;
+
+impl<T: Scalar> Vector2<T> {
+    #[inline]
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    #[inline]
+    pub fn uni(val: T) -> Self {
+        Self::new(val, val)
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::uni(T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::uni(T::one())
+    }
+
+    #[inline]
+    pub fn up() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+
+    #[inline]
+    pub fn down() -> Self {
+        Self::new(T::zero(), -T::one())
+    }
+
+    #[inline]
+    pub fn right() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+
+    #[inline]
+    pub fn left() -> Self {
+        Self::new(-T::one(), T::zero())
+    }
+
+    pub fn normalize_all(vecs: &mut [Self]) {
+        for v in vecs {
+            v.normalize();
+        }
+    }
+}
 
-                #[inline]
-                fn lerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    (1.0 - t) * *self + t * to
-                }
+impl<T: Scalar> Vector for Vector2<T> {
+    type Decimal = T;
+    type Bivec = Bivector2<T>;
+    type Rotor = Rotor2<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        (self.x * self.x) + (self.y * self.y)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.x * other.x + self.y * other.y
+    }
+
+    #[inline]
+    fn wedge(&self, other: Self) -> Self::Bivec {
+        Self::Bivec::new(self.x * other.y - self.y * other.x)
+    }
+
+    #[inline]
+    fn geom(&self, other: Self) -> Self::Rotor {
+        Self::Rotor::new(self.dot(other), self.wedge(other))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn project(&mut self, other: Self) {
+        *self = other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn projected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.project(other);
+        v
+    }
+
+    #[inline]
+    fn reject(&mut self, other: Self) {
+        // self = self - self.project(other)
+        *self -= other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn rejected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reject(other);
+        v
+    }
+
+    #[inline]
+    fn reflect(&mut self, other: Self) {
+        // self = self - 2 * self.project(other)
+        *self -= other * (T::two() * (self.dot(other) / other.mag_sq()));
+    }
+
+    #[inline]
+    fn reflected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reflect(other);
+        v
+    }
+
+    #[inline]
+    fn reflect_normal(&mut self, normal: Self) {
+        // self = self - 2 * self.project(normal)
+        *self -= normal * (T::two() * self.dot(normal));
+    }
+
+    #[inline]
+    fn reflected_normal(&self, normal: Self) -> Self {
+        let mut v = *self;
+        v.reflect_normal(normal);
+        v
+    }
+
+    #[inline]
+    fn to(&self, other: Self) -> Self {
+        other - *self
+    }
+
+    #[inline]
+    fn distance(&self, other: Self) -> Self::Decimal {
+        self.to(other).mag()
+    }
+
+    #[inline]
+    fn angle_between(&self, other: Self) -> Self::Decimal {
+        self.normalized().dot(other.normalized()).acos()
+    }
+
+    #[inline]
+    fn angle_between_normal(&self, normal: Self) -> Self::Decimal {
+        self.dot(normal).acos()
+    }
+
+    #[inline]
+    fn lerp(&self, to: Self, t: Self::Decimal) -> Self {
+        *self * (T::one() - t) + to * t
+    }
+
+    #[inline]
+    fn slerp(&self, to: Self, t: Self::Decimal) -> Self {
+        let theta = self.angle_between(to);
+        let self_coef = ((T::one() - t) * theta).sin() / theta.sin();
+        let to_coef = (t * theta).sin() / theta.sin();
+        *self * self_coef + to * to_coef
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: Self::Decimal) -> Self {
+        self.lerp(to, t).normalized()
+    }
+}
 
-                #[inline]
-                fn slerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    let theta = self.angle_between(to);
-                    let self_coef = ((1.0 - t) * theta).sin() / theta.sin();
-                    let to_coef = (t * theta).sin() / theta.sin();
-                    self_coef * *self + to_coef * to
-                }
+impl<T: Scalar> Add for Vector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn nlerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    self.lerp(to, t).normalized()
-                }
-            }
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
 
-            impl Add for $nam {
-                type Output = $nam;
+impl<T: Scalar> AddAssign for Vector2<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x = self.x + rhs.x;
+        self.y = self.y + rhs.y;
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x + rhs.x, self.y + rhs.y)
-                }
-            }
+impl<T: Scalar> Sub for Vector2<T> {
+    type Output = Self;
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: Self) {
-                    self.x = self.x + rhs.x;
-                    self.y = self.y + rhs.y;
-                }
-            }
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
 
-            impl Sub for $nam {
-                type Output = $nam;
+impl<T: Scalar> SubAssign for Vector2<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x = self.x - rhs.x;
+        self.y = self.y - rhs.y;
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x - rhs.x, self.y - rhs.y)
-                }
-            }
+impl<T: Scalar> Neg for Vector2<T> {
+    type Output = Self;
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: Self) {
-                    self.x = self.x - rhs.x;
-                    self.y = self.y - rhs.y;
-                }
-            }
+    #[inline]
+    fn neg(self) -> Self {
+        self * -T::one()
+    }
+}
 
-            impl Neg for $nam{
-                type Output = $nam;
+impl<T: Scalar> Mul<T> for Vector2<T> {
+    type Output = Self;
 
-                #[inline]
-                fn neg(self) -> $nam {
-                    self * -1.0
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = $nam;
+impl<T: Scalar> MulAssign<T> for Vector2<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x * rhs, self.y * rhs)
-                }
-            }
+impl<T: Scalar> Div<T> for Vector2<T> {
+    type Output = Self;
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.x *= rhs;
-                    self.y *= rhs;
-                }
-            }
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs)
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> DivAssign<T> for Vector2<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self * rhs.x, self * rhs.y)
-                }
-            }
+impl<T> Index<usize> for Vector2<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-            impl Div<$t> for $nam {
-                type Output = $nam;
+impl<T> IndexMut<usize> for Vector2<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x / rhs, self.y / rhs)
-                }
-            }
+impl<T> From<Vector2<T>> for [T; 2] {
+    #[inline]
+    fn from(v: Vector2<T>) -> Self {
+        [v.x, v.y]
+    }
+}
 
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.x /= rhs;
-                    self.y /= rhs;
-                }
-            }
+impl<T: Scalar> From<[T; 2]> for Vector2<T> {
+    #[inline]
+    fn from(comps: [T; 2]) -> Self {
+        Self::new(comps[0], comps[1])
+    }
+}
 
-            impl Div<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> From<&[T; 2]> for Vector2<T> {
+    #[inline]
+    fn from(comps: &[T; 2]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self / rhs.x, self / rhs.y)
-                }
-            }
+impl<T: Scalar> From<&mut [T; 2]> for Vector2<T> {
+    #[inline]
+    fn from(comps: &mut [T; 2]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl Index<usize> for $nam {
-                type Output =  $t;
+impl<T: Scalar> From<(T, T)> for Vector2<T> {
+    #[inline]
+    fn from(comps: (T, T)) -> Self {
+        Self::new(comps.0, comps.1)
+    }
+}
 
-                fn index(&self, index: usize) -> &Self::Output {
-                    match index {
-                        0 => &self.x,
-                        1 => &self.y,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&(T, T)> for Vector2<T> {
+    #[inline]
+    fn from(comps: &(T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl IndexMut<usize> for $nam {
-                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                    match index {
-                        0 => &mut self.x,
-                        1 => &mut self.y,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&mut (T, T)> for Vector2<T> {
+    #[inline]
+    fn from(comps: &mut (T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl Into<[$t; 2]> for $nam {
-                #[inline]
-                fn into(self) -> [$t; 2] {
-                    [self.x, self.y]
-                }
-            }
+impl<T> From<Vector2<T>> for (T, T) {
+    #[inline]
+    fn from(v: Vector2<T>) -> Self {
+        (v.x, v.y)
+    }
+}
 
-            impl From<[$t; 2]> for $nam {
-                #[inline]
-                fn from(comps: [$t; 2]) -> Self {
-                    Self::new(comps[0], comps[1])
-                }
-            }
+impl<T: Scalar> From<Vector3<T>> for Vector2<T> {
+    #[inline]
+    fn from(vec3: Vector3<T>) -> Self {
+        Self::new(vec3.x, vec3.y)
+    }
+}
 
-            impl From<&[$t; 2]> for $nam {
-                #[inline]
-                fn from(comps: &[$t; 2]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+impl<T: Scalar> From<Vector4<T>> for Vector2<T> {
+    #[inline]
+    fn from(vec4: Vector4<T>) -> Self {
+        Self::new(vec4.x, vec4.y)
+    }
+}
 
-            impl From<&mut [$t; 2]> for $nam {
-                #[inline]
-                fn from(comps: &mut [$t; 2]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+macro_rules! impl_scalar_vec2 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Vector2<$t>> for $t {
+                type Output = Vector2<$t>;
 
-            impl From<($t, $t)> for $nam {
                 #[inline]
-                fn from(comps: ($t, $t)) -> Self {
-                    Self::new(comps.0, comps.1)
+                fn mul(self, rhs: Vector2<$t>) -> Self::Output {
+                    Vector2::new(self * rhs.x, self * rhs.y)
                 }
             }
 
-            impl From<&($t, $t)> for $nam {
-                #[inline]
-                fn from(comps: &($t, $t)) -> Self {
-                    Self::from(*comps)
-                }
-            }
+            impl Div<Vector2<$t>> for $t {
+                type Output = Vector2<$t>;
 
-            impl From<&mut ($t, $t)> for $nam {
                 #[inline]
-                fn from(comps: &mut ($t, $t)) -> Self {
-                    Self::from(*comps)
+                fn div(self, rhs: Vector2<$t>) -> Self::Output {
+                    Vector2::new(self / rhs.x, self / rhs.y)
                 }
             }
+        )+
+    };
+}
 
-            impl From<$nam> for ($t, $t) {
-                #[inline]
-                fn from(v: $nam) -> Self {
-                    (v.x, v.y)
-                }
-            }
+impl_scalar_vec2![f32, f64];
 
-            impl From<$v3> for $nam {
-                #[inline]
-                fn from(vec3: $v3) -> Self {
-                    Self::new(vec3.x, vec3.y)
-                }
-            }
+impl From<Vec2> for DVec2 {
+    #[inline]
+    fn from(v: Vec2) -> Self {
+        Self::new(f64::from(v.x), f64::from(v.y))
+    }
+}
 
-            impl From<$v4> for $nam {
-                #[inline]
-                fn from(vec4: $v4) -> Self {
-                    Self::new(vec4.x, vec4.y)
-                }
-            }
-        )+
-    };
+impl Vec2 {
+    #[inline]
+    pub fn as_dvec2(&self) -> DVec2 {
+        DVec2::from(*self)
+    }
 }
 
-impl_vec2![(f32, Vec2, Vec3, Vec4, Bivec2, Rot2)];
+impl DVec2 {
+    #[inline]
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+}
@@ -1,410 +1,469 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_vec3 {
-    [$(($t:ident, $nam:ident, $v2:ident, $v4:ident, $bv:ident, $rot:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub x: $t,
-                pub y: $t,
-                pub z: $t,
-            }
-
-            impl $nam{
-                #[inline]
-                pub fn new(x: $t, y: $t, z: $t) -> Self {
-                    Self { x, y, z }
-                }
-
-                #[inline]
-                pub fn uni(val: $t) -> Self {
-                    Self::new(val, val, val)
-                }
-
-                #[inline]
-                pub fn zero() -> Self {
-                    Self::uni(0.0)
-                }
-
-                #[inline]
-                pub fn one() -> Self {
-                    Self::uni(1.0)
-                }
-
-                #[inline]
-                pub fn up() -> Self {
-                    Self::new(0.0, 1.0, 0.0)
-                }
-
-                #[inline]
-                pub fn down() -> Self {
-                    Self::new(0.0, -1.0, 0.0)
-                }
-
-                #[inline]
-                pub fn right() -> Self {
-                    Self::new(1.0, 0.0, 0.0)
-                }
-
-                #[inline]
-                pub fn left() -> Self {
-                    Self::new(-1.0, 0.0, 0.0)
-                }
-
-                #[inline]
-                pub fn forward() -> Self {
-                    Self::new(0.0, 0.0, 1.0)
-                }
-
-                #[inline]
-                pub fn back() -> Self {
-                    Self::new(0.0, 0.0, -1.0)
-                }
-
-                #[inline]
-                pub fn cross(&self, other: Self) -> Self {
-                    Self::new(
-                        (self.y * other.z) + (-self.z * other.y),
-                        (self.z * other.x) + (-self.x * other.z),
-                        (self.x * other.y) + (-self.y * other.x),
-                    )
-                }
-
-            }
-
-            impl Vector for Vec3 {
-                type Decimal = $t;
-                type Bivec = $bv;
-                type Rotor = $rot;
-
-                #[inline]
-                fn mag(&self) -> Self::Decimal {
-                    self.mag_sq().sqrt()
-                }
-
-                #[inline]
-                fn mag_sq(&self) -> Self::Decimal {
-                    (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
-                }
-
-                #[inline]
-                fn dot(&self, other: Self) -> Self::Decimal {
-                    self.x * other.x + self.y * other.y + self.z * other.z
-                }
-
-                #[inline]
-                fn wedge(&self, other: Self) -> Self::Bivec {
-                    Self::Bivec::new(
-                        (self.x * other.y) - (self.y * other.x),
-                        (self.x * other.z) - (self.z * other.x),
-                        (self.y * other.z) - (self.z * other.y),
-                    )
-                }
-
-                #[inline]
-                fn geom(&self, other: Self) -> Self::Rotor {
-                    Self::Rotor::new(self.dot(other), self.wedge(other))
-                }
-
-                #[inline]
-                fn normalize(&mut self) {
-                    let mag = self.mag();
-                    *self /= mag;
-                }
-
-                #[inline]
-                fn normalized(&self) -> Self {
-                    let mut v = self.clone();
-                    v.normalize();
-                    v
-                }
-
-                #[inline]
-                fn project(&mut self, other: Self) {
-                    *self = (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn projected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. project(other);
-                    v
-                }
-
-                #[inline]
-                fn reject(&mut self, other: Self) {
-                    // self = self - self.project(other)
-                    *self -= (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn rejected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reject(other);
-                    v
-                }
-
-                #[inline]
-                fn reflect(&mut self, other: Self) {
-                    // self = self - 2 * self.project(other)
-                    *self -= 2.0 * (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                fn reflected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect(other);
-                    v
-                }
-
-                #[inline]
-                fn reflect_normal(&mut self, normal: Self) {
-                    // self = self - 2 * self.project(normal)
-                    *self -= 2.0 * self.dot(normal) * normal;
-                }
-
-                #[inline]
-                fn reflected_normal(&self, normal: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect_normal(normal);
-                    v
-                }
-
-                #[inline]
-                fn to(&self, other: Self) -> Self {
-                    other - *self
-                }
-
-                #[inline]
-                fn distance(&self, other: Self) -> Self::Decimal {
-                    self.to(other).mag()
-                }
-
-                #[inline]
-                fn angle_between(&self, other: Self) -> Self::Decimal {
-                    self.normalized().dot(other.normalized()).acos()
-                }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
 
-                #[inline]
-                fn angle_between_normal(&self, normal: Self) -> Self::Decimal {
-                    self.dot(normal).acos()
-                }
+pub type Vec3 = Vector3<f32>;
+pub type DVec3 = Vector3<f64>;
+
+impl<T: Scalar> Vector3<T> {
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn uni(val: T) -> Self {
+        Self::new(val, val, val)
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::uni(T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::uni(T::one())
+    }
+
+    #[inline]
+    pub fn up() -> Self {
+        Self::new(T::zero(), T::one(), T::zero())
+    }
+
+    #[inline]
+    pub fn down() -> Self {
+        Self::new(T::zero(), -T::one(), T::zero())
+    }
+
+    #[inline]
+    pub fn right() -> Self {
+        Self::new(T::one(), T::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn left() -> Self {
+        Self::new(-T::one(), T::zero(), T::zero())
+    }
+
+    #[inline]
+    pub fn forward() -> Self {
+        Self::new(T::zero(), T::zero(), T::one())
+    }
+
+    #[inline]
+    pub fn back() -> Self {
+        Self::new(T::zero(), T::zero(), -T::one())
+    }
+
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        self.wedge(other).dual()
+    }
+
+    // The plane this vector is normal to, self * xyz
+    #[inline]
+    pub fn dual(&self) -> Bivector3<T> {
+        Bivector3::new(self.z, -self.y, self.x)
+    }
+
+    #[inline]
+    pub fn wedge_bivec(&self, bv: Bivector3<T>) -> Trivector3<T> {
+        Trivector3::new(self.x * bv.yz - self.y * bv.xz + self.z * bv.xy)
+    }
+
+    pub fn normalize_all(vecs: &mut [Self]) {
+        for v in vecs {
+            v.normalize();
+        }
+    }
+
+    // The up vector for a view along the unit vector forward. When up is
+    // parallel to forward no plane is defined, so the world forward axis, or
+    // up when looking along z, is used instead.
+    pub(crate) fn look_up(forward: Self, up: Self) -> Self {
+        if up.cross(forward).mag_sq() > T::epsilon() * up.mag_sq() {
+            return up;
+        }
+
+        if forward.z.abs() < T::half() {
+            Self::forward()
+        } else {
+            Self::up()
+        }
+    }
+}
 
-                #[inline]
-                fn lerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    (1.0 - t) * *self + t * to
-                }
+impl<T: Scalar> Vector for Vector3<T> {
+    type Decimal = T;
+    type Bivec = Bivector3<T>;
+    type Rotor = Rotor3<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        (self.x * self.x) + (self.y * self.y) + (self.z * self.z)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    #[inline]
+    fn wedge(&self, other: Self) -> Self::Bivec {
+        Self::Bivec::new(
+            (self.x * other.y) - (self.y * other.x),
+            (self.x * other.z) - (self.z * other.x),
+            (self.y * other.z) - (self.z * other.y),
+        )
+    }
+
+    #[inline]
+    fn geom(&self, other: Self) -> Self::Rotor {
+        Self::Rotor::new(self.dot(other), self.wedge(other))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn project(&mut self, other: Self) {
+        *self = other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn projected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.project(other);
+        v
+    }
+
+    #[inline]
+    fn reject(&mut self, other: Self) {
+        // self = self - self.project(other)
+        *self -= other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn rejected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reject(other);
+        v
+    }
+
+    #[inline]
+    fn reflect(&mut self, other: Self) {
+        // self = self - 2 * self.project(other)
+        *self -= other * (T::two() * (self.dot(other) / other.mag_sq()));
+    }
+
+    #[inline]
+    fn reflected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reflect(other);
+        v
+    }
+
+    #[inline]
+    fn reflect_normal(&mut self, normal: Self) {
+        // self = self - 2 * self.project(normal)
+        *self -= normal * (T::two() * self.dot(normal));
+    }
+
+    #[inline]
+    fn reflected_normal(&self, normal: Self) -> Self {
+        let mut v = *self;
+        v.reflect_normal(normal);
+        v
+    }
+
+    #[inline]
+    fn to(&self, other: Self) -> Self {
+        other - *self
+    }
+
+    #[inline]
+    fn distance(&self, other: Self) -> Self::Decimal {
+        self.to(other).mag()
+    }
+
+    #[inline]
+    fn angle_between(&self, other: Self) -> Self::Decimal {
+        self.normalized().dot(other.normalized()).acos()
+    }
+
+    #[inline]
+    fn angle_between_normal(&self, normal: Self) -> Self::Decimal {
+        self.dot(normal).acos()
+    }
+
+    #[inline]
+    fn lerp(&self, to: Self, t: Self::Decimal) -> Self {
+        *self * (T::one() - t) + to * t
+    }
+
+    #[inline]
+    fn slerp(&self, to: Self, t: Self::Decimal) -> Self {
+        let theta = self.angle_between(to);
+        let self_coef = ((T::one() - t) * theta).sin() / theta.sin();
+        let to_coef = (t * theta).sin() / theta.sin();
+        *self * self_coef + to * to_coef
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: Self::Decimal) -> Self {
+        self.lerp(to, t).normalized()
+    }
+}
 
-                #[inline]
-                fn slerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    let theta = self.angle_between(to);
-                    let self_coef = ((1.0 - t) * theta).sin() / theta.sin();
-                    let to_coef = (t * theta).sin() / theta.sin();
-                    self_coef * *self + to_coef * to
-                }
+impl<T: Scalar> Add for Vector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn nlerp(&self, to: Self, t: Self::Decimal) -> Self {
-                    self.lerp(to, t).normalized()
-                }
-            }
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
 
-            impl Add for $nam {
-                type Output = $nam;
+impl<T: Scalar> AddAssign for Vector3<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
-                }
-            }
+impl<T: Scalar> Sub for Vector3<T> {
+    type Output = Self;
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: Self) {
-                    self.x += rhs.x;
-                    self.y += rhs.y;
-                    self.z += rhs.z;
-                }
-            }
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
 
-            impl Sub for $nam {
-                type Output = $nam;
+impl<T: Scalar> SubAssign for Vector3<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
-                }
-            }
+impl<T: Scalar> Neg for Vector3<T> {
+    type Output = Self;
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: Self) {
-                    self.x -= rhs.x;
-                    self.y -= rhs.y;
-                    self.z -= rhs.z;
-                }
-            }
+    #[inline]
+    fn neg(self) -> Self {
+        self * -T::one()
+    }
+}
 
-            impl Neg for $nam{
-                type Output = $nam;
+impl<T: Scalar> Mul<T> for Vector3<T> {
+    type Output = Self;
 
-                #[inline]
-                fn neg(self) -> $nam {
-                    self * -1.0
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = $nam;
+impl<T: Scalar> MulAssign<T> for Vector3<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
-                }
-            }
+impl<T: Scalar> Div<T> for Vector3<T> {
+    type Output = Self;
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.x *= rhs;
-                    self.y *= rhs;
-                    self.z *= rhs;
-                }
-            }
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> DivAssign<T> for Vector3<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self * rhs.x, self * rhs.y, self * rhs.z)
-                }
-            }
+impl<T> Index<usize> for Vector3<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-            impl Div<$t> for $nam {
-                type Output = $nam;
+impl<T> IndexMut<usize> for Vector3<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
-                }
-            }
+impl<T> From<Vector3<T>> for [T; 3] {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        [v.x, v.y, v.z]
+    }
+}
 
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.x /= rhs;
-                    self.y /= rhs;
-                    self.z /= rhs;
-                }
-            }
+impl<T: Scalar> From<[T; 3]> for Vector3<T> {
+    #[inline]
+    fn from(comps: [T; 3]) -> Self {
+        Self::new(comps[0], comps[1], comps[2])
+    }
+}
 
-            impl Div<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> From<&[T; 3]> for Vector3<T> {
+    #[inline]
+    fn from(comps: &[T; 3]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self / rhs.x, self / rhs.y, self / rhs.z)
-                }
-            }
+impl<T: Scalar> From<&mut [T; 3]> for Vector3<T> {
+    #[inline]
+    fn from(comps: &mut [T; 3]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl Index<usize> for $nam {
-                type Output =  $t;
+impl<T: Scalar> From<(T, T, T)> for Vector3<T> {
+    #[inline]
+    fn from(comps: (T, T, T)) -> Self {
+        Self::new(comps.0, comps.1, comps.2)
+    }
+}
 
-                fn index(&self, index: usize) -> &Self::Output {
-                    match index {
-                        0 => &self.x,
-                        1 => &self.y,
-                        2 => &self.z,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&(T, T, T)> for Vector3<T> {
+    #[inline]
+    fn from(comps: &(T, T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl IndexMut<usize> for $nam {
-                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                    match index {
-                        0 => &mut self.x,
-                        1 => &mut self.y,
-                        2 => &mut self.z,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&mut (T, T, T)> for Vector3<T> {
+    #[inline]
+    fn from(comps: &mut (T, T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl Into<[$t; 3]> for $nam {
-                #[inline]
-                fn into(self) -> [$t; 3] {
-                    [self.x, self.y, self.z]
-                }
-            }
+impl<T> From<Vector3<T>> for (T, T) {
+    #[inline]
+    fn from(v: Vector3<T>) -> Self {
+        (v.x, v.y)
+    }
+}
 
-            impl From<[$t; 3]> for $nam {
-                #[inline]
-                fn from(comps: [$t; 3]) -> Self {
-                    Self::new(comps[0], comps[1], comps[2])
-                }
-            }
+impl<T: Scalar> From<Vector2<T>> for Vector3<T> {
+    #[inline]
+    fn from(vec2: Vector2<T>) -> Self {
+        Self::new(vec2.x, vec2.y, T::zero())
+    }
+}
 
-            impl From<&[$t; 3]> for $nam {
-                #[inline]
-                fn from(comps: &[$t; 3]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+impl<T: Scalar> From<Vector4<T>> for Vector3<T> {
+    #[inline]
+    fn from(vec4: Vector4<T>) -> Self {
+        Self::new(vec4.x, vec4.y, vec4.z)
+    }
+}
 
-            impl From<&mut [$t; 3]> for $nam {
-                #[inline]
-                fn from(comps: &mut [$t; 3]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+macro_rules! impl_scalar_vec3 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Vector3<$t>> for $t {
+                type Output = Vector3<$t>;
 
-            impl From<($t, $t, $t)> for $nam {
                 #[inline]
-                fn from(comps: ($t, $t, $t)) -> Self {
-                    Self::new(comps.0, comps.1, comps.2)
+                fn mul(self, rhs: Vector3<$t>) -> Self::Output {
+                    Vector3::new(self * rhs.x, self * rhs.y, self * rhs.z)
                 }
             }
 
-            impl From<&($t, $t, $t)> for $nam {
-                #[inline]
-                fn from(comps: &($t, $t, $t)) -> Self {
-                    Self::from(*comps)
-                }
-            }
+            impl Div<Vector3<$t>> for $t {
+                type Output = Vector3<$t>;
 
-            impl From<&mut ($t, $t, $t)> for $nam {
                 #[inline]
-                fn from(comps: &mut ($t, $t, $t)) -> Self {
-                    Self::from(*comps)
+                fn div(self, rhs: Vector3<$t>) -> Self::Output {
+                    Vector3::new(self / rhs.x, self / rhs.y, self / rhs.z)
                 }
             }
+        )+
+    };
+}
 
-            impl From<$nam> for ($t, $t) {
-                #[inline]
-                fn from(v: $nam) -> Self {
-                    (v.x, v.y)
-                }
-            }
+impl_scalar_vec3![f32, f64];
 
-            impl From<$v2> for $nam {
-                #[inline]
-                fn from(vec2: $v2) -> Self {
-                    Self::new(vec2.x, vec2.y, 0.0)
-                }
-            }
+impl From<Vec3> for DVec3 {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::new(f64::from(v.x), f64::from(v.y), f64::from(v.z))
+    }
+}
 
-            impl From<$v4> for $nam {
-                #[inline]
-                fn from(vec4: $v4) -> Self {
-                    Self::new(vec4.x, vec4.y, vec4.z)
-                }
-            }
-        )+
-    };
+impl Vec3 {
+    #[inline]
+    pub fn as_dvec3(&self) -> DVec3 {
+        DVec3::from(*self)
+    }
 }
 
-impl_vec3![(f32, Vec3, Vec2, Vec4, Bivec3, Rot3)];
+impl DVec3 {
+    #[inline]
+    pub fn as_vec3(&self) -> Vec3 {
+        Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
@@ -0,0 +1,362 @@
+use crate::simd::*;
+use crate::*;
+use std::fmt;
+use std::ops::*;
+
+// A Vec3 padded to 16 bytes and aligned so it fits a SIMD register, the
+// fourth lane is kept at zero.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Vec3A(pub(crate) F32x4);
+
+impl Vec3A {
+    #[inline]
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Self(F32x4::new(x, y, z, 0.0))
+    }
+
+    #[inline]
+    pub fn uni(val: f32) -> Self {
+        Self::new(val, val, val)
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self(F32x4::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::uni(1.0)
+    }
+
+    #[inline]
+    pub fn up() -> Self {
+        Self::new(0.0, 1.0, 0.0)
+    }
+
+    #[inline]
+    pub fn down() -> Self {
+        Self::new(0.0, -1.0, 0.0)
+    }
+
+    #[inline]
+    pub fn right() -> Self {
+        Self::new(1.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    pub fn left() -> Self {
+        Self::new(-1.0, 0.0, 0.0)
+    }
+
+    #[inline]
+    pub fn forward() -> Self {
+        Self::new(0.0, 0.0, 1.0)
+    }
+
+    #[inline]
+    pub fn back() -> Self {
+        Self::new(0.0, 0.0, -1.0)
+    }
+
+    #[inline]
+    pub fn x(&self) -> f32 {
+        self.0.to_array()[0]
+    }
+
+    #[inline]
+    pub fn y(&self) -> f32 {
+        self.0.to_array()[1]
+    }
+
+    #[inline]
+    pub fn z(&self) -> f32 {
+        self.0.to_array()[2]
+    }
+
+    #[inline]
+    pub fn to_array(&self) -> [f32; 3] {
+        let [x, y, z, _] = self.0.to_array();
+        [x, y, z]
+    }
+
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self(self.0.cross(other.0))
+    }
+
+    // The plane this vector is normal to, self * xyz
+    #[inline]
+    pub fn dual(&self) -> Bivec3 {
+        let signs = F32x4::new(1.0, -1.0, 1.0, 1.0);
+        let [xy, xz, yz, _] = (shuffle!(self.0, [2, 1, 0, 3]) * signs).to_array();
+        Bivec3::new(xy, xz, yz)
+    }
+}
+
+impl Vector for Vec3A {
+    type Decimal = f32;
+    type Bivec = Bivec3;
+    type Rotor = Rot3A;
+
+    #[inline]
+    fn mag(&self) -> f32 {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> f32 {
+        self.dot(*self)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> f32 {
+        self.0.dot3(other.0).x()
+    }
+
+    #[inline]
+    fn wedge(&self, other: Self) -> Bivec3 {
+        self.cross(other).dual()
+    }
+
+    #[inline]
+    fn geom(&self, other: Self) -> Rot3A {
+        // In the rotor's quaternion layout the wedge is the negated cross
+        Rot3A((-self.0.cross(other.0)).with_w_of(self.0.dot3(other.0)))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn project(&mut self, other: Self) {
+        *self = other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn projected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.project(other);
+        v
+    }
+
+    #[inline]
+    fn reject(&mut self, other: Self) {
+        *self -= other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn rejected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reject(other);
+        v
+    }
+
+    #[inline]
+    fn reflect(&mut self, other: Self) {
+        *self -= other * (2.0 * (self.dot(other) / other.mag_sq()));
+    }
+
+    #[inline]
+    fn reflected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reflect(other);
+        v
+    }
+
+    #[inline]
+    fn reflect_normal(&mut self, normal: Self) {
+        *self -= normal * (2.0 * self.dot(normal));
+    }
+
+    #[inline]
+    fn reflected_normal(&self, normal: Self) -> Self {
+        let mut v = *self;
+        v.reflect_normal(normal);
+        v
+    }
+
+    #[inline]
+    fn to(&self, other: Self) -> Self {
+        other - *self
+    }
+
+    #[inline]
+    fn distance(&self, other: Self) -> f32 {
+        self.to(other).mag()
+    }
+
+    #[inline]
+    fn angle_between(&self, other: Self) -> f32 {
+        self.normalized().dot(other.normalized()).acos()
+    }
+
+    #[inline]
+    fn angle_between_normal(&self, normal: Self) -> f32 {
+        self.dot(normal).acos()
+    }
+
+    #[inline]
+    fn lerp(&self, to: Self, t: f32) -> Self {
+        *self * (1.0 - t) + to * t
+    }
+
+    #[inline]
+    fn slerp(&self, to: Self, t: f32) -> Self {
+        let theta = self.angle_between(to);
+        let self_coef = ((1.0 - t) * theta).sin() / theta.sin();
+        let to_coef = (t * theta).sin() / theta.sin();
+        *self * self_coef + to * to_coef
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: f32) -> Self {
+        self.lerp(to, t).normalized()
+    }
+}
+
+impl Default for Vec3A {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Vec3A {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_array() == other.to_array()
+    }
+}
+
+impl fmt::Debug for Vec3A {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Vec3A")
+            .field("x", &self.x())
+            .field("y", &self.y())
+            .field("z", &self.z())
+            .finish()
+    }
+}
+
+impl Add for Vec3A {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Vec3A {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 = self.0 + rhs.0;
+    }
+}
+
+impl Sub for Vec3A {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Vec3A {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = self.0 - rhs.0;
+    }
+}
+
+impl Neg for Vec3A {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul<f32> for Vec3A {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self {
+        Self(self.0 * F32x4::splat(rhs))
+    }
+}
+
+impl MulAssign<f32> for Vec3A {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div<f32> for Vec3A {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self {
+        // Keeps the padding lane at zero even when dividing by zero
+        Self((self.0 / F32x4::splat(rhs)).with_w_of(F32x4::zero()))
+    }
+}
+
+impl DivAssign<f32> for Vec3A {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+impl Mul<Vec3A> for f32 {
+    type Output = Vec3A;
+
+    #[inline]
+    fn mul(self, rhs: Vec3A) -> Vec3A {
+        rhs * self
+    }
+}
+
+impl From<Vec3> for Vec3A {
+    #[inline]
+    fn from(v: Vec3) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vec3A> for Vec3 {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        Self::from(v.to_array())
+    }
+}
+
+impl From<[f32; 3]> for Vec3A {
+    #[inline]
+    fn from(a: [f32; 3]) -> Self {
+        Self::new(a[0], a[1], a[2])
+    }
+}
+
+impl From<Vec3A> for [f32; 3] {
+    #[inline]
+    fn from(v: Vec3A) -> Self {
+        v.to_array()
+    }
+}
@@ -1,339 +1,433 @@
 use crate::*;
 use std::ops::*;
 
-macro_rules! impl_vec4 {
-    [$(($t:ident, $nam:ident, $v2:ident, $v3:ident)), +] => {
-        $(
-            #[derive(Clone, Copy, Debug, Default, PartialEq)]
-            #[repr(C)]
-            pub struct $nam {
-                pub x: $t,
-                pub y: $t,
-                pub z: $t,
-                pub w: $t,
-            }
-
-            impl $nam{
-                #[inline]
-                pub fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
-                    Self { x, y, z, w }
-                }
-
-                #[inline]
-                pub fn uni(val: $t) -> Self {
-                    Self::new(val, val, val, val)
-                }
-
-                #[inline]
-                pub fn zero() -> Self {
-                    Self::uni(0.0)
-                }
-
-                #[inline]
-                pub fn one() -> Self {
-                    Self::uni(1.0)
-                }
-
-                #[inline]
-                pub fn mag(&self) -> $t {
-                    self.mag_sq().sqrt()
-                }
-
-                #[inline]
-                pub fn mag_sq(&self) -> $t {
-                    (self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w)
-                }
-
-                #[inline]
-                pub fn dot(&self, other: Self) -> $t {
-                    self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
-                }
-
-                #[inline]
-                pub fn normalize(&mut self) {
-                    let mag = self.mag();
-                    *self /= mag;
-                }
-
-                #[inline]
-                pub fn normalized(&self) -> Self {
-                    let mut v = self.clone();
-                    v.normalize();
-                    v
-                }
-
-                #[inline]
-                pub fn project(&mut self, other: Self) {
-                    *self = (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                pub fn projected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. project(other);
-                    v
-                }
-
-                #[inline]
-                pub fn reject(&mut self, other: Self) {
-                    // self = self - self.project(other)
-                    *self -= (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                pub fn rejected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reject(other);
-                    v
-                }
-
-                #[inline]
-                pub fn reflect(&mut self, other: Self) {
-                    // self = self - 2 * self.project(other)
-                    *self -= 2.0 * (self.dot(other) / other.mag_sq()) * other;
-                }
-
-                #[inline]
-                pub fn reflected(&self, other: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect(other);
-                    v
-                }
-
-                #[inline]
-                pub fn reflect_normal(&mut self, normal: Self) {
-                    // self = self - 2 * self.project(normal)
-                    *self -= 2.0 * self.dot(normal) * normal;
-                }
-
-                #[inline]
-                pub fn reflected_normal(&self, normal: Self) -> Self {
-                    let mut v = self.clone();
-                    v. reflect_normal(normal);
-                    v
-                }
-
-                #[inline]
-                pub fn to(&self, other: Self) -> Self {
-                    other - *self
-                }
-
-                #[inline]
-                pub fn distance(&self, other: Self) -> $t {
-                    self.to(other).mag()
-                }
-
-                #[inline]
-                pub fn lerp(&self, to: Self, t: $t) -> Self {
-                    (1.0 - t) * *self + t * to
-                }
-
-                #[inline]
-                pub fn nlerp(&self, to: Self, t: $t) -> Self {
-                    self.lerp(to, t).normalized()
-                }
-            }
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[repr(C)]
+pub struct Vector4<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
 
-            impl Add for $nam {
-                type Output = $nam;
+pub type Vec4 = Vector4<f32>;
+pub type DVec4 = Vector4<f64>;
+
+impl<T: Scalar> Vector4<T> {
+    #[inline]
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    pub fn uni(val: T) -> Self {
+        Self::new(val, val, val, val)
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::uni(T::zero())
+    }
+
+    #[inline]
+    pub fn one() -> Self {
+        Self::uni(T::one())
+    }
+
+    pub fn normalize_all(vecs: &mut [Self]) {
+        for v in vecs {
+            v.normalize();
+        }
+    }
+}
 
-                #[inline]
-                fn add(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z, self.w + rhs.w)
-                }
-            }
+impl<T: Scalar> Vector for Vector4<T> {
+    type Decimal = T;
+    type Bivec = Bivector4<T>;
+    type Rotor = Rotor4<T>;
+
+    #[inline]
+    fn mag(&self) -> Self::Decimal {
+        self.mag_sq().sqrt()
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> Self::Decimal {
+        (self.x * self.x) + (self.y * self.y) + (self.z * self.z) + (self.w * self.w)
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> Self::Decimal {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    #[inline]
+    fn wedge(&self, other: Self) -> Self::Bivec {
+        Self::Bivec::new(
+            (self.x * other.y) - (self.y * other.x),
+            (self.x * other.z) - (self.z * other.x),
+            (self.x * other.w) - (self.w * other.x),
+            (self.y * other.z) - (self.z * other.y),
+            (self.y * other.w) - (self.w * other.y),
+            (self.z * other.w) - (self.w * other.z),
+        )
+    }
+
+    #[inline]
+    fn geom(&self, other: Self) -> Self::Rotor {
+        Self::Rotor::new(self.dot(other), self.wedge(other), T::zero())
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        let mag = self.mag();
+        *self /= mag;
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        let mut v = *self;
+        v.normalize();
+        v
+    }
+
+    #[inline]
+    fn project(&mut self, other: Self) {
+        *self = other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn projected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.project(other);
+        v
+    }
+
+    #[inline]
+    fn reject(&mut self, other: Self) {
+        // self = self - self.project(other)
+        *self -= other * (self.dot(other) / other.mag_sq());
+    }
+
+    #[inline]
+    fn rejected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reject(other);
+        v
+    }
+
+    #[inline]
+    fn reflect(&mut self, other: Self) {
+        // self = self - 2 * self.project(other)
+        *self -= other * (T::two() * (self.dot(other) / other.mag_sq()));
+    }
+
+    #[inline]
+    fn reflected(&self, other: Self) -> Self {
+        let mut v = *self;
+        v.reflect(other);
+        v
+    }
+
+    #[inline]
+    fn reflect_normal(&mut self, normal: Self) {
+        // self = self - 2 * self.project(normal)
+        *self -= normal * (T::two() * self.dot(normal));
+    }
+
+    #[inline]
+    fn reflected_normal(&self, normal: Self) -> Self {
+        let mut v = *self;
+        v.reflect_normal(normal);
+        v
+    }
+
+    #[inline]
+    fn to(&self, other: Self) -> Self {
+        other - *self
+    }
+
+    #[inline]
+    fn distance(&self, other: Self) -> Self::Decimal {
+        self.to(other).mag()
+    }
+
+    #[inline]
+    fn angle_between(&self, other: Self) -> Self::Decimal {
+        self.normalized().dot(other.normalized()).acos()
+    }
+
+    #[inline]
+    fn angle_between_normal(&self, normal: Self) -> Self::Decimal {
+        self.dot(normal).acos()
+    }
+
+    #[inline]
+    fn lerp(&self, to: Self, t: Self::Decimal) -> Self {
+        *self * (T::one() - t) + to * t
+    }
+
+    #[inline]
+    fn slerp(&self, to: Self, t: Self::Decimal) -> Self {
+        let theta = self.angle_between(to);
+        let self_coef = ((T::one() - t) * theta).sin() / theta.sin();
+        let to_coef = (t * theta).sin() / theta.sin();
+        *self * self_coef + to * to_coef
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: Self::Decimal) -> Self {
+        self.lerp(to, t).normalized()
+    }
+}
 
-            impl AddAssign for $nam {
-                #[inline]
-                fn add_assign(&mut self, rhs: Self) {
-                    self.x += rhs.x;
-                    self.y += rhs.y;
-                    self.z += rhs.z;
-                    self.w += rhs.w;
-                }
-            }
+impl<T: Scalar> Add for Vector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
 
-            impl Sub for $nam {
-                type Output = $nam;
+impl<T: Scalar> AddAssign for Vector4<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+        self.w += rhs.w;
+    }
+}
 
-                #[inline]
-                fn sub(self, rhs: Self) -> Self::Output {
-                    Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z, self.w - rhs.w)
-                }
-            }
+impl<T: Scalar> Sub for Vector4<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
 
-            impl SubAssign for $nam {
-                #[inline]
-                fn sub_assign(&mut self, rhs: Self) {
-                    self.x -= rhs.x;
-                    self.y -= rhs.y;
-                    self.z -= rhs.z;
-                    self.w -= rhs.w;
-                }
-            }
+impl<T: Scalar> SubAssign for Vector4<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+        self.w -= rhs.w;
+    }
+}
 
-            impl Neg for $nam{
-                type Output = $nam;
+impl<T: Scalar> Neg for Vector4<T> {
+    type Output = Self;
 
-                #[inline]
-                fn neg(self) -> $nam {
-                    self * -1.0
-                }
-            }
+    #[inline]
+    fn neg(self) -> Self {
+        self * -T::one()
+    }
+}
 
-            impl Mul<$t> for $nam {
-                type Output = $nam;
+impl<T: Scalar> Mul<T> for Vector4<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
-                }
-            }
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs, self.w * rhs)
+    }
+}
 
-            impl MulAssign<$t> for $nam {
-                #[inline]
-                fn mul_assign(&mut self, rhs: $t) {
-                    self.x *= rhs;
-                    self.y *= rhs;
-                    self.z *= rhs;
-                    self.w *= rhs;
-                }
-            }
+impl<T: Scalar> MulAssign<T> for Vector4<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+        self.w *= rhs;
+    }
+}
 
-            impl Mul<$nam> for $t {
-                type Output = $nam;
+impl<T: Scalar> Div<T> for Vector4<T> {
+    type Output = Self;
 
-                #[inline]
-                fn mul(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self * rhs.x, self * rhs.y, self * rhs.z, self * rhs.w)
-                }
-            }
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
+    }
+}
 
-            impl Div<$t> for $nam {
-                type Output = $nam;
+impl<T: Scalar> DivAssign<T> for Vector4<T> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+        self.w /= rhs;
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $t) -> Self::Output {
-                    Self::new(self.x / rhs, self.y / rhs, self.z / rhs, self.w / rhs)
-                }
-            }
+impl<T> Index<usize> for Vector4<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            3 => &self.w,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-            impl DivAssign<$t> for $nam {
-                #[inline]
-                fn div_assign(&mut self, rhs: $t) {
-                    self.x /= rhs;
-                    self.y /= rhs;
-                    self.z /= rhs;
-                    self.w /= rhs;
-                }
-            }
+impl<T> IndexMut<usize> for Vector4<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            3 => &mut self.w,
+            _i => panic!(
+                "{} is not a valid index for {}",
+                _i,
+                std::any::type_name::<Self>()
+            ),
+        }
+    }
+}
 
-            impl Div<$nam> for $t {
-                type Output = $nam;
+impl<T> From<Vector4<T>> for [T; 4] {
+    #[inline]
+    fn from(v: Vector4<T>) -> Self {
+        [v.x, v.y, v.z, v.w]
+    }
+}
 
-                #[inline]
-                fn div(self, rhs: $nam) -> Self::Output {
-                    $nam::new(self / rhs.x, self / rhs.y, self / rhs.z, self / rhs.w)
-                }
-            }
+impl<T: Scalar> From<[T; 4]> for Vector4<T> {
+    #[inline]
+    fn from(comps: [T; 4]) -> Self {
+        Self::new(comps[0], comps[1], comps[2], comps[3])
+    }
+}
 
-            impl Index<usize> for $nam {
-                type Output =  $t;
-
-                fn index(&self, index: usize) -> &Self::Output {
-                    match index {
-                        0 => &self.x,
-                        1 => &self.y,
-                        2 => &self.z,
-                        3 => &self.w,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&[T; 4]> for Vector4<T> {
+    #[inline]
+    fn from(comps: &[T; 4]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl IndexMut<usize> for $nam {
-                fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                    match index {
-                        0 => &mut self.x,
-                        1 => &mut self.y,
-                        2 => &mut self.z,
-                        3 => &mut self.w,
-                        _i => panic!("{} is not a valid index for {}", _i, std::any::type_name::<$nam>()),
-                    }
-                }
-            }
+impl<T: Scalar> From<&mut [T; 4]> for Vector4<T> {
+    #[inline]
+    fn from(comps: &mut [T; 4]) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl Into<[$t; 4]> for $nam {
-                #[inline]
-                fn into(self) -> [$t; 4] {
-                    [self.x, self.y, self.z, self.w]
-                }
-            }
+impl<T: Scalar> From<(T, T, T, T)> for Vector4<T> {
+    #[inline]
+    fn from(comps: (T, T, T, T)) -> Self {
+        Self::new(comps.0, comps.1, comps.2, comps.3)
+    }
+}
 
-            impl From<[$t; 4]> for $nam {
-                #[inline]
-                fn from(comps: [$t; 4]) -> Self {
-                    Self::new(comps[0], comps[1], comps[2], comps[3])
-                }
-            }
+impl<T: Scalar> From<&(T, T, T, T)> for Vector4<T> {
+    #[inline]
+    fn from(comps: &(T, T, T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl From<&[$t; 4]> for $nam {
-                #[inline]
-                fn from(comps: &[$t; 4]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+impl<T: Scalar> From<&mut (T, T, T, T)> for Vector4<T> {
+    #[inline]
+    fn from(comps: &mut (T, T, T, T)) -> Self {
+        Self::from(*comps)
+    }
+}
 
-            impl From<&mut [$t; 4]> for $nam {
-                #[inline]
-                fn from(comps: &mut [$t; 4]) -> Self {
-                    Self::from(*comps)
-                }
-            }
+impl<T> From<Vector4<T>> for (T, T) {
+    #[inline]
+    fn from(v: Vector4<T>) -> Self {
+        (v.x, v.y)
+    }
+}
 
-            impl From<($t, $t, $t, $t)> for $nam {
-                #[inline]
-                fn from(comps: ($t, $t, $t, $t)) -> Self {
-                    Self::new(comps.0, comps.1, comps.2, comps.3)
-                }
-            }
+impl<T: Scalar> From<Vector2<T>> for Vector4<T> {
+    #[inline]
+    fn from(vec2: Vector2<T>) -> Self {
+        Self::new(vec2.x, vec2.y, T::zero(), T::zero())
+    }
+}
 
-            impl From<&($t, $t, $t, $t)> for $nam {
-                #[inline]
-                fn from(comps: &($t, $t, $t, $t)) -> Self {
-                    Self::from(*comps)
-                }
-            }
+impl<T: Scalar> From<Vector3<T>> for Vector4<T> {
+    #[inline]
+    fn from(vec4: Vector3<T>) -> Self {
+        Self::new(vec4.x, vec4.y, vec4.z, T::zero())
+    }
+}
 
-            impl From<&mut ($t, $t, $t, $t)> for $nam {
-                #[inline]
-                fn from(comps: &mut ($t, $t, $t, $t)) -> Self {
-                    Self::from(*comps)
-                }
-            }
+macro_rules! impl_scalar_vec4 {
+    [$($t:ident), +] => {
+        $(
+            impl Mul<Vector4<$t>> for $t {
+                type Output = Vector4<$t>;
 
-            impl From<$nam> for ($t, $t) {
                 #[inline]
-                fn from(v: $nam) -> Self {
-                    (v.x, v.y)
+                fn mul(self, rhs: Vector4<$t>) -> Self::Output {
+                    Vector4::new(self * rhs.x, self * rhs.y, self * rhs.z, self * rhs.w)
                 }
             }
 
-            impl From<$v2> for $nam {
-                #[inline]
-                fn from(vec2: $v2) -> Self {
-                    Self::new(vec2.x, vec2.y, 0.0, 0.0)
-                }
-            }
+            impl Div<Vector4<$t>> for $t {
+                type Output = Vector4<$t>;
 
-            impl From<$v3> for $nam {
                 #[inline]
-                fn from(vec4: $v3) -> Self {
-                    Self::new(vec4.x, vec4.y, vec4.z, 0.0)
+                fn div(self, rhs: Vector4<$t>) -> Self::Output {
+                    Vector4::new(self / rhs.x, self / rhs.y, self / rhs.z, self / rhs.w)
                 }
             }
         )+
     };
 }
 
-impl_vec4![(f32, Vec4, Vec2, Vec3)];
+impl_scalar_vec4![f32, f64];
+
+impl From<Vec4> for DVec4 {
+    #[inline]
+    fn from(v: Vec4) -> Self {
+        Self::new(
+            f64::from(v.x),
+            f64::from(v.y),
+            f64::from(v.z),
+            f64::from(v.w),
+        )
+    }
+}
+
+impl Vec4 {
+    #[inline]
+    pub fn as_dvec4(&self) -> DVec4 {
+        DVec4::from(*self)
+    }
+}
+
+impl DVec4 {
+    #[inline]
+    pub fn as_vec4(&self) -> Vec4 {
+        Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
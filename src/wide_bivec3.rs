@@ -0,0 +1,226 @@
+use crate::wide_vec3::lanes;
+use crate::*;
+use std::ops::*;
+
+// N Bivector3s stored as one array per component, see WideVector3
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct WideBivector3<T, const N: usize> {
+    pub xy: [T; N],
+    pub xz: [T; N],
+    pub yz: [T; N],
+}
+
+pub type Bivec3x4 = WideBivector3<f32, 4>;
+pub type Bivec3x8 = WideBivector3<f32, 8>;
+pub type DBivec3x4 = WideBivector3<f64, 4>;
+
+impl<T: Scalar, const N: usize> WideBivector3<T, N> {
+    #[inline]
+    pub fn new(xy: [T; N], xz: [T; N], yz: [T; N]) -> Self {
+        Self { xy, xz, yz }
+    }
+
+    #[inline]
+    pub fn splat(bv: Bivector3<T>) -> Self {
+        Self::new([bv.xy; N], [bv.xz; N], [bv.yz; N])
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Bivector3::zero())
+    }
+
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> Bivector3<T>>(mut f: F) -> Self {
+        let mut w = Self::zero();
+        for i in 0..N {
+            w.set_lane(i, f(i));
+        }
+        w
+    }
+
+    #[inline]
+    pub fn lane(&self, i: usize) -> Bivector3<T> {
+        Bivector3::new(self.xy[i], self.xz[i], self.yz[i])
+    }
+
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, bv: Bivector3<T>) {
+        self.xy[i] = bv.xy;
+        self.xz[i] = bv.xz;
+        self.yz[i] = bv.yz;
+    }
+
+    // Loads up to N bivectors, lanes past the end of the slice are zero
+    #[inline]
+    pub fn gather(bivecs: &[Bivector3<T>]) -> Self {
+        let mut w = Self::zero();
+        for (i, bv) in bivecs.iter().take(N).enumerate() {
+            w.set_lane(i, *bv);
+        }
+        w
+    }
+
+    // Stores up to N lanes, as many as fit in the slice
+    #[inline]
+    pub fn scatter(&self, bivecs: &mut [Bivector3<T>]) {
+        for (i, bv) in bivecs.iter_mut().take(N).enumerate() {
+            *bv = self.lane(i);
+        }
+    }
+
+    #[inline]
+    pub fn dual(&self) -> WideVector3<T, N> {
+        WideVector3::from_fn(|i| self.lane(i).dual())
+    }
+}
+
+impl<T: Scalar, const N: usize> Bivector for WideBivector3<T, N> {
+    type Decimal = [T; N];
+    type Rotor = WideRotor3<T, N>;
+
+    #[inline]
+    fn mag(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag())
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag_sq())
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> [T; N] {
+        lanes(|i| self.lane(i).dot(other.lane(i)))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        Self::from_fn(|i| self.lane(i).normalized())
+    }
+
+    #[inline]
+    fn exp(&self) -> Self::Rotor {
+        WideRotor3::from_fn(|i| self.lane(i).exp())
+    }
+}
+
+impl<T: Scalar, const N: usize> Default for WideBivector3<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.lane(i) + rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> AddAssign for WideBivector3<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.lane(i) - rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> SubAssign for WideBivector3<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Neg for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|i| -self.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<[T; N]> for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> MulAssign<T> for WideBivector3<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) / rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<[T; N]> for WideBivector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i) / rhs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> DivAssign<T> for WideBivector3<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> From<[Bivector3<T>; N]> for WideBivector3<T, N> {
+    #[inline]
+    fn from(bivecs: [Bivector3<T>; N]) -> Self {
+        Self::from_fn(|i| bivecs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> From<WideBivector3<T, N>> for [Bivector3<T>; N] {
+    #[inline]
+    fn from(w: WideBivector3<T, N>) -> Self {
+        let mut bivecs = [Bivector3::zero(); N];
+        w.scatter(&mut bivecs);
+        bivecs
+    }
+}
@@ -0,0 +1,277 @@
+use crate::wide_vec3::lanes;
+use crate::*;
+use std::ops::*;
+
+// N Rotor3s stored as one array per component, see WideVector3
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct WideRotor3<T, const N: usize> {
+    pub s: [T; N],
+    pub bv: WideBivector3<T, N>,
+}
+
+pub type Rot3x4 = WideRotor3<f32, 4>;
+pub type Rot3x8 = WideRotor3<f32, 8>;
+pub type DRot3x4 = WideRotor3<f64, 4>;
+
+impl<T: Scalar, const N: usize> WideRotor3<T, N> {
+    #[inline]
+    pub fn new(s: [T; N], bv: WideBivector3<T, N>) -> Self {
+        Self { s, bv }
+    }
+
+    #[inline]
+    pub fn splat(r: Rotor3<T>) -> Self {
+        Self::new([r.s; N], WideBivector3::splat(r.bv))
+    }
+
+    #[inline]
+    pub fn identity() -> Self {
+        Self::splat(Rotor3::identity())
+    }
+
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> Rotor3<T>>(mut f: F) -> Self {
+        let mut w = Self::splat(Rotor3::default());
+        for i in 0..N {
+            w.set_lane(i, f(i));
+        }
+        w
+    }
+
+    #[inline]
+    pub fn lane(&self, i: usize) -> Rotor3<T> {
+        Rotor3::new(self.s[i], self.bv.lane(i))
+    }
+
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, r: Rotor3<T>) {
+        self.s[i] = r.s;
+        self.bv.set_lane(i, r.bv);
+    }
+
+    // Loads up to N rotors, lanes past the end of the slice are the identity
+    #[inline]
+    pub fn gather(rotors: &[Rotor3<T>]) -> Self {
+        let mut w = Self::identity();
+        for (i, r) in rotors.iter().take(N).enumerate() {
+            w.set_lane(i, *r);
+        }
+        w
+    }
+
+    // The Rotor::nlerp default needs a scalar t, so lanes go through Rotor3
+    #[inline]
+    pub fn nlerp(&self, to: Self, t: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i).nlerp(to.lane(i), t[i]))
+    }
+
+    // Stores up to N lanes, as many as fit in the slice
+    #[inline]
+    pub fn scatter(&self, rotors: &mut [Rotor3<T>]) {
+        for (i, r) in rotors.iter_mut().take(N).enumerate() {
+            *r = self.lane(i);
+        }
+    }
+}
+
+impl<T: Scalar, const N: usize> Rotor for WideRotor3<T, N> {
+    type Decimal = [T; N];
+    type Bivec = WideBivector3<T, N>;
+    type Vector = WideVector3<T, N>;
+    type Matrix = [Matrix3<T>; N];
+
+    #[inline]
+    fn mag(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag())
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag_sq())
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> [T; N] {
+        lanes(|i| self.lane(i).dot(other.lane(i)))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        Self::from_fn(|i| self.lane(i).normalized())
+    }
+
+    #[inline]
+    fn reverse(&mut self) {
+        self.bv = -self.bv;
+    }
+
+    #[inline]
+    fn reversed(&self) -> Self {
+        let mut r = *self;
+        r.reverse();
+        r
+    }
+
+    #[inline]
+    fn rotate_by(&mut self, other: Self) {
+        *self = self.rotated_by(other);
+    }
+
+    #[inline]
+    fn rotate_vec(&self, vec: &mut Self::Vector) {
+        for i in 0..N {
+            let mut v = vec.lane(i);
+            self.lane(i).rotate_vec(&mut v);
+            vec.set_lane(i, v);
+        }
+    }
+
+    #[inline]
+    fn rotated_by(&self, other: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).rotated_by(other.lane(i)))
+    }
+
+    #[inline]
+    fn into_matrix(&self) -> [Matrix3<T>; N] {
+        let mut mats = [Matrix3::identity(); N];
+        for (i, m) in mats.iter_mut().enumerate() {
+            *m = self.lane(i).into_matrix();
+        }
+        mats
+    }
+
+    #[inline]
+    fn log(&self) -> Self::Bivec {
+        WideBivector3::from_fn(|i| self.lane(i).log())
+    }
+}
+
+impl<T: Scalar, const N: usize> Default for WideRotor3<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::splat(Rotor3::default())
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(lanes(|i| self.s[i] + rhs.s[i]), self.bv + rhs.bv)
+    }
+}
+
+impl<T: Scalar, const N: usize> AddAssign for WideRotor3<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(lanes(|i| self.s[i] - rhs.s[i]), self.bv - rhs.bv)
+    }
+}
+
+impl<T: Scalar, const N: usize> SubAssign for WideRotor3<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Neg for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|i| -self.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<WideVector3<T, N>> for WideRotor3<T, N> {
+    type Output = WideVector3<T, N>;
+
+    #[inline]
+    fn mul(self, mut rhs: WideVector3<T, N>) -> WideVector3<T, N> {
+        self.rotate_vec(&mut rhs);
+        rhs
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<WideBivector3<T, N>> for WideRotor3<T, N> {
+    type Output = WideBivector3<T, N>;
+
+    #[inline]
+    fn mul(self, rhs: WideBivector3<T, N>) -> WideBivector3<T, N> {
+        WideBivector3::from_fn(|i| self.lane(i) * rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> MulAssign<T> for WideRotor3<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for WideRotor3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) / rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> DivAssign<T> for WideRotor3<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> From<[Rotor3<T>; N]> for WideRotor3<T, N> {
+    #[inline]
+    fn from(rotors: [Rotor3<T>; N]) -> Self {
+        Self::from_fn(|i| rotors[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> From<WideRotor3<T, N>> for [Rotor3<T>; N] {
+    #[inline]
+    fn from(w: WideRotor3<T, N>) -> Self {
+        let mut rotors = [Rotor3::default(); N];
+        w.scatter(&mut rotors);
+        rotors
+    }
+}
@@ -0,0 +1,337 @@
+use crate::*;
+use std::ops::*;
+
+// N Vector3s stored as one array per component. Every operation runs the
+// scalar Vector3 code lane by lane over fixed size arrays, which the compiler
+// turns into SIMD instructions, and gives bit for bit the scalar results.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct WideVector3<T, const N: usize> {
+    pub x: [T; N],
+    pub y: [T; N],
+    pub z: [T; N],
+}
+
+pub type Vec3x4 = WideVector3<f32, 4>;
+pub type Vec3x8 = WideVector3<f32, 8>;
+pub type DVec3x4 = WideVector3<f64, 4>;
+
+impl<T: Scalar, const N: usize> WideVector3<T, N> {
+    #[inline]
+    pub fn new(x: [T; N], y: [T; N], z: [T; N]) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn splat(v: Vector3<T>) -> Self {
+        Self::new([v.x; N], [v.y; N], [v.z; N])
+    }
+
+    #[inline]
+    pub fn zero() -> Self {
+        Self::splat(Vector3::zero())
+    }
+
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> Vector3<T>>(mut f: F) -> Self {
+        let mut w = Self::zero();
+        for i in 0..N {
+            w.set_lane(i, f(i));
+        }
+        w
+    }
+
+    #[inline]
+    pub fn lane(&self, i: usize) -> Vector3<T> {
+        Vector3::new(self.x[i], self.y[i], self.z[i])
+    }
+
+    #[inline]
+    pub fn set_lane(&mut self, i: usize, v: Vector3<T>) {
+        self.x[i] = v.x;
+        self.y[i] = v.y;
+        self.z[i] = v.z;
+    }
+
+    // Loads up to N vectors, lanes past the end of the slice are zero
+    #[inline]
+    pub fn gather(vecs: &[Vector3<T>]) -> Self {
+        let mut w = Self::zero();
+        for (i, v) in vecs.iter().take(N).enumerate() {
+            w.set_lane(i, *v);
+        }
+        w
+    }
+
+    // Stores up to N lanes, as many as fit in the slice
+    #[inline]
+    pub fn scatter(&self, vecs: &mut [Vector3<T>]) {
+        for (i, v) in vecs.iter_mut().take(N).enumerate() {
+            *v = self.lane(i);
+        }
+    }
+
+    #[inline]
+    pub fn cross(&self, other: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).cross(other.lane(i)))
+    }
+
+    #[inline]
+    pub fn dual(&self) -> WideBivector3<T, N> {
+        WideBivector3::from_fn(|i| self.lane(i).dual())
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector for WideVector3<T, N> {
+    type Decimal = [T; N];
+    type Bivec = WideBivector3<T, N>;
+    type Rotor = WideRotor3<T, N>;
+
+    #[inline]
+    fn mag(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag())
+    }
+
+    #[inline]
+    fn mag_sq(&self) -> [T; N] {
+        lanes(|i| self.lane(i).mag_sq())
+    }
+
+    #[inline]
+    fn dot(&self, other: Self) -> [T; N] {
+        lanes(|i| self.lane(i).dot(other.lane(i)))
+    }
+
+    #[inline]
+    fn wedge(&self, other: Self) -> Self::Bivec {
+        WideBivector3::from_fn(|i| self.lane(i).wedge(other.lane(i)))
+    }
+
+    #[inline]
+    fn geom(&self, other: Self) -> Self::Rotor {
+        WideRotor3::from_fn(|i| self.lane(i).geom(other.lane(i)))
+    }
+
+    #[inline]
+    fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    #[inline]
+    fn normalized(&self) -> Self {
+        Self::from_fn(|i| self.lane(i).normalized())
+    }
+
+    #[inline]
+    fn project(&mut self, other: Self) {
+        *self = self.projected(other);
+    }
+
+    #[inline]
+    fn projected(&self, other: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).projected(other.lane(i)))
+    }
+
+    #[inline]
+    fn reject(&mut self, other: Self) {
+        *self = self.rejected(other);
+    }
+
+    #[inline]
+    fn rejected(&self, other: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).rejected(other.lane(i)))
+    }
+
+    #[inline]
+    fn reflect(&mut self, other: Self) {
+        *self = self.reflected(other);
+    }
+
+    #[inline]
+    fn reflected(&self, other: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).reflected(other.lane(i)))
+    }
+
+    #[inline]
+    fn reflect_normal(&mut self, normal: Self) {
+        *self = self.reflected_normal(normal);
+    }
+
+    #[inline]
+    fn reflected_normal(&self, normal: Self) -> Self {
+        Self::from_fn(|i| self.lane(i).reflected_normal(normal.lane(i)))
+    }
+
+    #[inline]
+    fn to(&self, other: Self) -> Self {
+        other - *self
+    }
+
+    #[inline]
+    fn distance(&self, other: Self) -> [T; N] {
+        self.to(other).mag()
+    }
+
+    #[inline]
+    fn angle_between(&self, other: Self) -> [T; N] {
+        lanes(|i| self.lane(i).angle_between(other.lane(i)))
+    }
+
+    #[inline]
+    fn angle_between_normal(&self, normal: Self) -> [T; N] {
+        lanes(|i| self.lane(i).angle_between_normal(normal.lane(i)))
+    }
+
+    #[inline]
+    fn lerp(&self, to: Self, t: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i).lerp(to.lane(i), t[i]))
+    }
+
+    #[inline]
+    fn slerp(&self, to: Self, t: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i).slerp(to.lane(i), t[i]))
+    }
+
+    #[inline]
+    fn nlerp(&self, to: Self, t: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i).nlerp(to.lane(i), t[i]))
+    }
+}
+
+// Builds a lane array from a function of the lane index
+#[inline]
+pub(crate) fn lanes<T: Scalar, F: FnMut(usize) -> T, const N: usize>(mut f: F) -> [T; N] {
+    let mut a = [T::zero(); N];
+    for (i, v) in a.iter_mut().enumerate() {
+        *v = f(i);
+    }
+    a
+}
+
+impl<T: Scalar, const N: usize> Default for WideVector3<T, N> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Scalar, const N: usize> Add for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.lane(i) + rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> AddAssign for WideVector3<T, N> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Sub for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::from_fn(|i| self.lane(i) - rhs.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> SubAssign for WideVector3<T, N> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Neg for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_fn(|i| -self.lane(i))
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<T> for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> Mul<[T; N]> for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i) * rhs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> MulAssign<T> for WideVector3<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> MulAssign<[T; N]> for WideVector3<T, N> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: [T; N]) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<T> for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::from_fn(|i| self.lane(i) / rhs)
+    }
+}
+
+impl<T: Scalar, const N: usize> Div<[T; N]> for WideVector3<T, N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: [T; N]) -> Self {
+        Self::from_fn(|i| self.lane(i) / rhs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> DivAssign<T> for WideVector3<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> DivAssign<[T; N]> for WideVector3<T, N> {
+    #[inline]
+    fn div_assign(&mut self, rhs: [T; N]) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Scalar, const N: usize> From<[Vector3<T>; N]> for WideVector3<T, N> {
+    #[inline]
+    fn from(vecs: [Vector3<T>; N]) -> Self {
+        Self::from_fn(|i| vecs[i])
+    }
+}
+
+impl<T: Scalar, const N: usize> From<WideVector3<T, N>> for [Vector3<T>; N] {
+    #[inline]
+    fn from(w: WideVector3<T, N>) -> Self {
+        let mut vecs = [Vector3::zero(); N];
+        w.scatter(&mut vecs);
+        vecs
+    }
+}
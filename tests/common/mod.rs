@@ -0,0 +1,112 @@
+// Fixtures and checks shared by the glam, mint, cgmath and nalgebra tests
+#![allow(dead_code)]
+
+use cliffy::*;
+use std::fmt::Debug;
+use std::ops::Mul;
+
+pub fn rotor() -> Rot3 {
+    Rot3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 0.9)
+}
+
+pub fn drotor() -> DRot3 {
+    DRot3::from_axis_angle(DVec3::new(1.0, -2.0, 0.5), 0.9)
+}
+
+pub fn point() -> Vec3 {
+    Vec3::new(0.5, 1.0, -1.5)
+}
+
+pub fn vectors() -> [Vec4; 2] {
+    [Vec4::new(1.0, -2.0, 3.5, 4.0), Vec4::zero()]
+}
+
+pub fn mat2() -> Mat2 {
+    Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0))
+}
+
+pub fn mat3() -> Mat3 {
+    Mat3::new(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(4.0, 5.0, 6.0),
+        Vec3::new(7.0, 8.0, 10.0),
+    )
+}
+
+pub fn mat4() -> Mat4 {
+    Mat4::new(
+        Vec4::new(1.0, 2.0, 3.0, 4.0),
+        Vec4::new(5.0, 6.0, 7.0, 8.0),
+        Vec4::new(9.0, 10.0, 11.0, 12.0),
+        Vec4::new(13.0, 14.0, 15.0, 17.0),
+    )
+}
+
+// Converting to U and back gives x exactly
+pub fn assert_round_trip<U, T>(x: T)
+where
+    U: From<T>,
+    T: From<U> + Copy + PartialEq + Debug,
+{
+    assert_eq!(T::from(U::from(x)), x);
+}
+
+pub fn assert_vectors_round_trip<V2, V3, V4, T: Scalar>(vectors: &[Vector4<T>])
+where
+    V2: From<Vector2<T>>,
+    V3: From<Vector3<T>>,
+    V4: From<Vector4<T>>,
+    Vector2<T>: From<V2>,
+    Vector3<T>: From<V3>,
+    Vector4<T>: From<V4>,
+{
+    for &v in vectors {
+        assert_round_trip::<V2, _>(Vector2::new(v.x, v.y));
+        assert_round_trip::<V3, _>(Vector3::new(v.x, v.y, v.z));
+        assert_round_trip::<V4, _>(v);
+    }
+}
+
+pub fn assert_matrices_round_trip<M2, M3, M4, T: Scalar>(
+    m2: Matrix2<T>,
+    m3: Matrix3<T>,
+    m4: Matrix4<T>,
+) where
+    M2: From<Matrix2<T>>,
+    M3: From<Matrix3<T>>,
+    M4: From<Matrix4<T>>,
+    Matrix2<T>: From<M2>,
+    Matrix3<T>: From<M3>,
+    Matrix4<T>: From<M4>,
+{
+    assert_round_trip::<M2, _>(m2);
+    assert_round_trip::<M3, _>(m3);
+    assert_round_trip::<M4, _>(m4);
+}
+
+// The fixtures are small integers, so products agree exactly
+pub fn assert_products_agree<M2, M3, M4, T: Scalar>(m2: Matrix2<T>, m3: Matrix3<T>, m4: Matrix4<T>)
+where
+    M2: From<Matrix2<T>> + Mul<Output = M2> + Copy,
+    M3: From<Matrix3<T>> + Mul<Output = M3> + Copy,
+    M4: From<Matrix4<T>> + Mul<Output = M4> + Copy,
+    Matrix2<T>: From<M2>,
+    Matrix3<T>: From<M3>,
+    Matrix4<T>: From<M4>,
+{
+    let (n2, n3, n4) = (M2::from(m2), M3::from(m3), M4::from(m4));
+    assert_eq!(Matrix2::from(n2 * n2), m2 * m2);
+    assert_eq!(Matrix3::from(n3 * n3), m3 * m3);
+    assert_eq!(Matrix4::from(n4 * n4), m4 * m4);
+}
+
+// The quaternions from r and -r both rotate v like r, rotate applies one to v
+pub fn assert_rotates_like<Q, T, F>(r: Rotor3<T>, v: Vector3<T>, epsilon: T, rotate: F)
+where
+    Q: From<Rotor3<T>>,
+    T: Scalar + ApproxEq<Epsilon = T>,
+    F: Fn(Q, Vector3<T>) -> Vector3<T>,
+{
+    assert_approx_eq!(rotate(Q::from(r), v), r * v, epsilon = epsilon);
+    assert_approx_eq!(rotate(Q::from(-r), v), r * v, epsilon = epsilon);
+}
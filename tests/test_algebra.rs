@@ -0,0 +1,173 @@
+use cliffy::*;
+
+algebra!(Sta, 1, 3, 0);
+algebra!(Ga3, 3, 0, 0);
+algebra!(Pga3, 3, 0, 1);
+algebra!(Cga3, 4, 1, 0);
+algebra!(Ga5, 5, 0, 0);
+
+#[test]
+fn test_metric() {
+    assert_eq!(Sta::DIM, 4);
+    assert_eq!(Sta::LEN, 16);
+    assert_eq!(Sta::METRIC, [1, -1, -1, -1]);
+    assert_eq!(Pga3::METRIC, [1, 1, 1, 0]);
+
+    let g0 = Sta::Multivector::<f32>::basis(0);
+    let g1 = Sta::Multivector::<f32>::basis(1);
+    assert_eq!(g0 * g0, Sta::Multivector::scalar(1.0));
+    assert_eq!(g1 * g1, Sta::Multivector::scalar(-1.0));
+    assert_eq!(g0 * g1, -(g1 * g0));
+    assert_eq!(g0 * g1, Sta::Multivector::blade(0b0011));
+
+    let e0 = Pga3::Multivector::<f32>::basis(3);
+    assert_eq!(e0 * e0, Pga3::Multivector::zero());
+
+    // The pseudoscalar of spacetime squares to -1
+    let i = Sta::Multivector::<f32>::pseudoscalar();
+    assert_eq!(i * i, Sta::Multivector::scalar(-1.0));
+}
+
+#[test]
+fn test_matches_multivec3() {
+    let a = Multivec3::new(
+        1.0,
+        Vec3::new(2.0, -1.0, 0.5),
+        Bivec3::new(0.5, 3.0, -2.0),
+        Trivec3::new(1.5),
+    );
+    let b = Multivec3::new(
+        -0.5,
+        Vec3::new(1.0, 0.0, 2.0),
+        Bivec3::new(-1.0, 1.0, 0.5),
+        Trivec3::new(-2.0),
+    );
+    let to_ga3 = |m: Multivec3| {
+        let mut c = [0.0; 8];
+        c[0b000] = m.s;
+        c[0b001] = m.v.x;
+        c[0b010] = m.v.y;
+        c[0b100] = m.v.z;
+        c[0b011] = m.bv.xy;
+        c[0b101] = m.bv.xz;
+        c[0b110] = m.bv.yz;
+        c[0b111] = m.tv.xyz;
+        Ga3::Multivector::new(c)
+    };
+
+    assert_eq!(to_ga3(a * b), to_ga3(a) * to_ga3(b));
+    assert_eq!(to_ga3(a.wedge(b)), to_ga3(a).wedge(to_ga3(b)));
+    assert_eq!(to_ga3(a.left_contract(b)), to_ga3(a).inner(to_ga3(b)));
+    assert_eq!(to_ga3(a.reversed()), to_ga3(a).reversed());
+    assert_eq!(to_ga3(a.dual()), to_ga3(a).dual());
+    assert_eq!(to_ga3(a.regressive(b)), to_ga3(a).regressive(to_ga3(b)));
+    assert_eq!(a.scalar_product(b), to_ga3(a).scalar_product(to_ga3(b)));
+}
+
+#[test]
+fn test_matches_cga3d() {
+    let a = cga3d::Multivector::up(Vec3::new(1.0, 2.0, 3.0));
+    let b = cga3d::Multivector::sphere(Vec3::new(-1.0, 0.0, 2.0), 1.5);
+    let ab = Cga3::Multivector::new(a.c) * Cga3::Multivector::new(b.c);
+    assert_eq!(ab.c, (a * b).c);
+}
+
+#[test]
+fn test_dual() {
+    let m = Ga5::Multivector::<f32>::new([1.0; 32]) + Ga5::Multivector::basis(2);
+    assert_eq!(m.dual().undual(), m);
+
+    // e12 ∨ e23 is their shared e2
+    let e12 = Ga3::Multivector::<f32>::blade(0b011);
+    let e23 = Ga3::Multivector::<f32>::blade(0b110);
+    assert_eq!(e12.regressive(e23), Ga3::Multivector::basis(1));
+
+    // Works without a metric, the point e123 and the plane e0 of PGA have a
+    // nonzero regressive product even though e0 squares to zero
+    let e1 = Pga3::Multivector::<f32>::basis(0);
+    let e2 = Pga3::Multivector::<f32>::basis(1);
+    let e3 = Pga3::Multivector::<f32>::basis(2);
+    let e123 = Pga3::Multivector::<f32>::blade(0b0111);
+    assert_eq!(e1.wedge(e2).wedge(e3), e123);
+    let e0 = Pga3::Multivector::<f32>::basis(3);
+    assert_eq!(e123.regressive(e0).c[0].abs(), 1.0);
+    assert_eq!(e0.dual().undual(), e0);
+}
+
+#[test]
+fn test_grades() {
+    assert_eq!(
+        Sta::Bivector::<f32>::BLADES,
+        [0b0011, 0b0101, 0b0110, 0b1001, 0b1010, 0b1100]
+    );
+    assert_eq!(Ga5::Trivector::<f32>::zero().components().len(), 10);
+    assert_eq!(Ga5::Pseudoscalar::<f32>::LEN, 1);
+    assert_eq!(Ga5::Pseudoscalar::<f32>::BLADES[0], 0b11111);
+
+    let u = Sta::Vector::new([1.0, 2.0, 0.0, -1.0]);
+    let v = Sta::Vector::new([0.5, 0.0, 1.0, 1.0]);
+    let uv = u * v;
+    assert_eq!(
+        Sta::Multivector::from(Sta::Bivector::from_multivector(uv)),
+        u.wedge(v)
+    );
+    assert_eq!(uv.grade(0), u.inner(v));
+    assert_eq!(Sta::Vector::from_multivector(Sta::Multivector::from(u)), u);
+}
+
+#[test]
+fn test_grade4() {
+    assert_eq!(
+        Ga5::Grade::<f32, 4>::BLADES[..Ga5::Grade::<f32, 4>::LEN],
+        [0b01111, 0b10111, 0b11011, 0b11101, 0b11110]
+    );
+    assert_eq!(Sta::Pseudoscalar::<f32>::BLADES[0], 0b1111);
+
+    // Four vectors span a grade 4 blade
+    let w = [
+        Ga5::Vector::new([1.0, 0.0, 0.0, 0.0, 2.0]),
+        Ga5::Vector::new([0.0, 1.0, 0.0, 0.0, 0.0]),
+        Ga5::Vector::new([0.0, 0.0, 1.0, 0.0, 0.0]),
+        Ga5::Vector::new([0.0, 0.0, 0.0, 1.0, 0.0]),
+    ]
+    .iter()
+    .fold(Ga5::Multivector::scalar(1.0), |w, &v| w.wedge(v.into()));
+    let g = Ga5::Grade::<_, 4>::from_multivector(w);
+    assert_eq!(g.components(), [1.0, 0.0, 0.0, 0.0, -2.0]);
+    assert_eq!(Ga5::Multivector::from(g), w);
+    assert_eq!(w.grade(4), w);
+    assert_eq!((g * g).c[0], 5.0);
+    assert_eq!(
+        Ga5::Grade::<_, 0>::from_multivector(g * g).components(),
+        [5.0]
+    );
+
+    // The dual of a vector is its grade 4 complement
+    let v = Ga5::Vector::new([1.0, 2.0, 3.0, 4.0, 5.0]);
+    let d = Ga5::Grade::<_, 4>::from_multivector(Ga5::Multivector::from(v).dual());
+    assert_eq!(Ga5::Multivector::from(d), Ga5::Multivector::from(v).dual());
+    assert_eq!(
+        Ga5::Vector::from_multivector(Ga5::Multivector::from(d).undual()),
+        v
+    );
+    assert_eq!(
+        Ga5::Pseudoscalar::from_multivector(Ga5::Multivector::from(v).wedge(d.into())).components(),
+        [55.0]
+    );
+}
+
+#[test]
+fn test_versors() {
+    // A boost along g1 keeps the spacetime interval
+    let rapidity = 0.7f32;
+    let b = Sta::Multivector::blade(0b0011) * (rapidity / 2.0).sinh()
+        + Sta::Multivector::scalar((rapidity / 2.0).cosh());
+    let x = Sta::Multivector::from(Sta::Vector::new([2.0, 1.0, 0.5, -1.0]));
+    let y = b.transform(x);
+    assert_approx_eq!(x.scalar_product(x), y.scalar_product(y), epsilon = 1e-4);
+    assert_approx_eq!(
+        y.c[0b0001],
+        2.0 * rapidity.cosh() - rapidity.sinh(),
+        epsilon = 1e-4
+    );
+}
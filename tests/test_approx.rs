@@ -0,0 +1,179 @@
+#![cfg(feature = "approx")]
+
+use approx::{
+    assert_abs_diff_eq, assert_abs_diff_ne, assert_relative_eq, assert_relative_ne, assert_ulps_eq,
+    assert_ulps_ne,
+};
+use cliffy::*;
+
+#[test]
+fn test_vectors() {
+    let v = Vec3::new(3.0, 4.0, 12.0).normalized();
+    assert_relative_eq!(v, Vec3::new(3.0 / 13.0, 4.0 / 13.0, 12.0 / 13.0));
+    assert_abs_diff_eq!(Vec2::new(1.0, 2.0), Vec2::new(1.001, 2.0), epsilon = 0.01);
+    assert_ulps_eq!(
+        DBivec3::new(0.1 + 0.2, 0.0, 1.0),
+        DBivec3::new(0.3, 0.0, 1.0)
+    );
+    assert_relative_ne!(Vec4::one(), Vec4::zero());
+}
+
+#[test]
+fn test_rotors() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    assert_relative_ne!(r, -r);
+    assert_relative_eq!(AsRotation(r), AsRotation(-r));
+}
+
+#[test]
+fn test_matrices() {
+    let m = Rot3::from_axis_angle(Vec3::right(), 0.3).into_matrix();
+    assert_abs_diff_eq!(m * m.transposed(), Mat3::identity(), epsilon = 1e-6);
+    let t = Transform3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    assert_relative_eq!(t * t.inverse(), Transform3::identity());
+}
+
+fn edge_values() -> Vec<f32> {
+    let one_up = f32::from_bits(1.0f32.to_bits() + 1);
+    let three_up = f32::from_bits(1.0f32.to_bits() + 3);
+    vec![
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        one_up,
+        three_up,
+        f32::from_bits(1),
+        -f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+        -f32::NAN,
+    ]
+}
+
+#[test]
+fn test_edge_cases_match_scalars() {
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+    // A vector compares like its components through the approx crate's own
+    // float impls
+    for &a in &edge_values() {
+        for &b in &edge_values() {
+            let (va, vb) = (Vec2::new(a, 1.0), Vec2::new(b, 1.0));
+            for &(epsilon, ulps) in &[(0.0, 0), (0.0, 4), (f32::EPSILON, 4), (0.0, u32::MAX)] {
+                let msg = format!("{:?} {:?} {} {}", a, b, epsilon, ulps);
+                assert_eq!(
+                    va.abs_diff_eq(&vb, epsilon),
+                    a.abs_diff_eq(&b, epsilon),
+                    "{}",
+                    msg
+                );
+                assert_eq!(
+                    va.relative_eq(&vb, epsilon, f32::EPSILON),
+                    a.relative_eq(&b, epsilon, f32::EPSILON),
+                    "{}",
+                    msg
+                );
+                assert_eq!(
+                    va.ulps_eq(&vb, epsilon, ulps),
+                    a.ulps_eq(&b, epsilon, ulps),
+                    "{}",
+                    msg
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ulps() {
+    let one_up = f32::from_bits(1.0f32.to_bits() + 1);
+    let five_up = f32::from_bits(1.0f32.to_bits() + 5);
+    assert_ulps_eq!(Vec3::new(1.0, 0.0, 0.0), Vec3::new(one_up, 0.0, 0.0));
+    assert_ulps_ne!(Vec3::new(1.0, 0.0, 0.0), Vec3::new(five_up, 0.0, 0.0));
+    assert_ulps_eq!(Vec3::one(), Vec3::new(1.0, 1.0, five_up), max_ulps = 5);
+
+    // Signed zeros are equal, the smallest subnormals of either sign only
+    // within epsilon
+    assert_ulps_eq!(Vec2::new(0.0, -0.0), Vec2::new(-0.0, 0.0), epsilon = 0.0);
+    let tiny = f32::from_bits(1);
+    assert_ulps_ne!(Vec2::new(tiny, 0.0), Vec2::new(-tiny, 0.0), epsilon = 0.0);
+    assert_ulps_eq!(Vec2::new(tiny, 0.0), Vec2::new(-tiny, 0.0));
+
+    // Infinity is one step past MAX, NaN is never equal
+    assert_ulps_eq!(
+        Vec2::new(f32::MAX, 0.0),
+        Vec2::new(f32::INFINITY, 0.0),
+        max_ulps = 1
+    );
+    assert_ulps_ne!(
+        Vec2::new(f32::NAN, 0.0),
+        Vec2::new(f32::NAN, 0.0),
+        max_ulps = u32::MAX
+    );
+    assert_relative_ne!(Vec2::new(f32::NAN, 0.0), Vec2::new(f32::NAN, 0.0));
+
+    let d = DVec2::new(0.1 + 0.2, 1.0);
+    assert_ulps_eq!(d, DVec2::new(0.3, 1.0), epsilon = 0.0, max_ulps = 1);
+    assert_ulps_ne!(d, DVec2::new(0.3, 1.0), epsilon = 0.0, max_ulps = 0);
+}
+
+#[test]
+fn test_rotations_and_f64() {
+    let r = DRot3::from_axis_angle(DVec3::new(1.0, 2.0, 3.0), 0.7);
+    assert_ulps_eq!(AsRotation(r), AsRotation(-r));
+    assert_abs_diff_eq!(AsRotation(r), AsRotation(-r), epsilon = 0.0);
+    assert_relative_ne!(AsRotation(r), AsRotation(r.reversed()));
+
+    // Identity and its negation are the same rotation, a zero rotor is not
+    let zero = Rot3::new(0.0, Bivec3::zero());
+    assert_relative_eq!(AsRotation(Rot3::identity()), AsRotation(-Rot3::identity()));
+    assert_relative_ne!(AsRotation(Rot3::identity()), AsRotation(zero));
+
+    let m = r.into_matrix();
+    assert_relative_eq!(m * m.inverse(), DMat3::identity(), epsilon = 1e-15);
+    assert_relative_ne!(
+        m * m.inverse(),
+        DMat3::identity(),
+        epsilon = 0.0,
+        max_relative = 0.0
+    );
+    // Relative tolerance follows the magnitude where an absolute one can't
+    let big = DMat4::from_rot3_translation(r, DVec3::one()) * 1e12;
+    assert_relative_eq!(big, big * (1.0 + 1e-9), max_relative = 1e-8);
+    assert_relative_ne!(big, big * (1.0 + 1e-9), max_relative = 1e-10);
+    assert_abs_diff_ne!(big, big * (1.0 + 1e-9), epsilon = 1e-3);
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd_types() {
+    let v = Vec3A::new(3.0, 4.0, 12.0).normalized();
+    assert_relative_eq!(v, Vec3A::new(3.0 / 13.0, 4.0 / 13.0, 12.0 / 13.0));
+    assert_abs_diff_eq!(Vec4A::one(), Vec4A::one() * 1.001, epsilon = 0.01);
+    assert_ulps_ne!(Vec3A::one(), Vec3A::zero());
+
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    assert_ulps_eq!(Rot3A::from(r) * Rot3A::identity(), Rot3A::from(r));
+    assert_relative_ne!(Rot3A::from(r), Rot3A::from(-r));
+    assert_relative_eq!(AsRotation(Rot3A::from(r)), AsRotation(Rot3A::from(-r)));
+}
+
+#[test]
+fn test_wide_types() {
+    let v = Vec3x4::from_fn(|i| Vec3::new(3.0, 4.0, 12.0 + i as f32));
+    let mut n = v;
+    n.normalize();
+    assert_relative_eq!(n, Vec3x4::from_fn(|i| v.lane(i).normalized()));
+    assert_abs_diff_eq!(v, v * 1.0001, epsilon = 0.01);
+    assert_ulps_ne!(v, Vec3x4::zero());
+
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    assert_ulps_eq!(DRot3x4::splat(r.as_drot3()), DRot3x4::splat(r.as_drot3()));
+    assert_relative_ne!(Rot3x8::splat(r), Rot3x8::splat(-r));
+    assert_relative_eq!(AsRotation(Rot3x8::splat(r)), AsRotation(Rot3x8::splat(-r)));
+    assert_abs_diff_eq!(Bivec3x4::splat(r.bv), Bivec3x4::splat(r.bv));
+}
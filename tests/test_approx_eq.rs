@@ -0,0 +1,80 @@
+use cliffy::*;
+
+#[test]
+fn test_floats() {
+    assert_ne!(0.1f64 + 0.2, 0.3);
+    assert!((0.1f64 + 0.2).approx_eq(&0.3));
+    assert!(1.0f32.approx_eq_abs(&1.05, 0.1));
+    assert!(!1.0f32.approx_eq_abs(&1.2, 0.1));
+    assert!(100.0f32.approx_eq_rel(&101.0, 0.0, 0.01));
+    assert!(!100.0f32.approx_eq_rel(&102.0, 0.0, 0.01));
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY));
+    assert!(!f64::NAN.approx_eq(&f64::NAN));
+}
+
+#[test]
+fn test_ulps() {
+    let a = 1.0f32;
+    let b = f32::from_bits(a.to_bits() + 3);
+    assert!(a.approx_eq_ulps(&b, 0.0, 4));
+    assert!(!a.approx_eq_ulps(&b, 0.0, 2));
+    assert!(!1e-40f32.approx_eq_ulps(&-1e-40, 0.0, 4));
+    assert!(1e-40f32.approx_eq_ulps(&-1e-40, f32::EPSILON, 4));
+    assert!(!f32::NAN.approx_eq_ulps(&f32::NAN, 0.0, u32::MAX));
+}
+
+#[test]
+fn test_vectors() {
+    let v = Vec3::new(3.0, 4.0, 12.0).normalized();
+    assert_approx_eq!(v, Vec3::new(3.0 / 13.0, 4.0 / 13.0, 12.0 / 13.0));
+    assert_approx_eq!(v.mag(), 1.0);
+    assert_approx_eq!(Vec2::new(1.0, 2.0), Vec2::new(1.001, 2.0), epsilon = 0.01);
+    assert_approx_ne!(Vec2::new(1.0, 2.0), Vec2::new(1.1, 2.0), epsilon = 0.01);
+    assert_approx_eq!(
+        DVec4::new(1000.0, 0.0, 0.0, 1.0),
+        DVec4::new(1000.1, 0.0, 0.0, 1.0),
+        max_relative = 1e-3
+    );
+    assert_approx_eq!(
+        Bivec3::new(0.1 + 0.2, 0.0, 1.0),
+        Bivec3::new(0.3, 0.0, 1.0),
+        max_ulps = 4
+    );
+}
+
+#[test]
+fn test_rotors() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    let mut v = Vec3::new(-1.0, 0.5, 2.0);
+    r.rotate_vec(&mut v);
+    r.reversed().rotate_vec(&mut v);
+    assert_approx_eq!(v, Vec3::new(-1.0, 0.5, 2.0), epsilon = 1e-6);
+
+    // r and -r perform the same rotation
+    assert_approx_ne!(r, -r);
+    assert_approx_eq!(AsRotation(r), AsRotation(-r));
+    assert_approx_ne!(
+        AsRotation(r),
+        AsRotation(Rot3::from_axis_angle(Vec3::up(), 0.7))
+    );
+}
+
+#[test]
+fn test_matrices() {
+    let m = Mat4::perspective(
+        1.0,
+        1.5,
+        0.1,
+        100.0,
+        Handedness::Right,
+        DepthRange::ZeroToOne,
+    );
+    assert_approx_eq!(m * m.inverse(), Mat4::identity(), epsilon = 1e-5);
+    assert_approx_ne!(m, Mat4::identity());
+}
+
+#[test]
+#[should_panic(expected = "left ≈ right")]
+fn test_assert_panics() {
+    assert_approx_eq!(Vec3::new(1.0, 2.0, 3.0), Vec3::new(1.0, 2.0, 3.1));
+}
@@ -98,3 +98,12 @@ fn test_normalized() {
 
     assert_eq!(bv.normalized(), Bivec2::new(1.0));
 }
+
+#[test]
+fn test_exp() {
+    let r = Bivec2::new(std::f32::consts::FRAC_PI_2).exp();
+
+    assert_approx_eq!(r.s, 0.0, epsilon = 1e-6);
+    assert_eq!(r.bv, Bivec2::new(1.0));
+    assert_eq!(Bivec2::zero().exp(), Rot2::identity());
+}
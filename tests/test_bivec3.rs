@@ -69,7 +69,7 @@ fn test_div() {
     let mut bv = Bivec3::new(-1.0, 1.0, 5.0);
     let f = 5.0;
 
-    assert_eq!(bv / f, Bivec3::new(-0.2, 0.2, 1.0));
+    assert_approx_eq!(bv / f, Bivec3::new(-0.2, 0.2, 1.0));
     assert_eq!(f / bv, Bivec3::new(-5.0, 5.0, 1.0));
 
     bv /= 1.0;
@@ -111,7 +111,7 @@ fn test_normalize() {
     let mag = 13.0;
     bv.normalize();
 
-    assert_eq!(bv, Bivec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
+    assert_approx_eq!(bv, Bivec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
 }
 
 #[test]
@@ -119,8 +119,30 @@ fn test_normalized() {
     let bv = Bivec3::new(3.0, 4.0, 12.0);
     let mag = 13.0;
 
-    assert_eq!(
+    assert_approx_eq!(
         bv.normalized(),
         Bivec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag)
     );
 }
+
+#[test]
+fn test_exp() {
+    let bv = Bivec3::new(0.0, 0.0, std::f32::consts::FRAC_PI_2);
+    let r = bv.exp();
+
+    assert_approx_eq!(
+        r,
+        Rot3::new(0.0, Bivec3::new(0.0, 0.0, 1.0)),
+        epsilon = 1e-6
+    );
+    assert_eq!(Bivec3::zero().exp(), Rot3::identity());
+}
+
+#[test]
+fn test_dual() {
+    let bv = Bivec3::new(3.0, 4.0, 5.0);
+
+    assert_eq!(bv.dual(), Vec3::new(5.0, -4.0, 3.0));
+    assert_eq!(bv.dual().dual(), bv);
+    assert_eq!(Bivec3::new(1.0, 0.0, 0.0).dual(), Vec3::forward());
+}
@@ -0,0 +1,73 @@
+use cliffy::*;
+
+#[test]
+fn test_constructions() {
+    assert_eq!(
+        Bivec4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0),
+        Bivec4 {
+            xy: 1.0,
+            xz: 2.0,
+            xw: 3.0,
+            yz: 4.0,
+            yw: 5.0,
+            zw: 6.0
+        }
+    );
+    assert_eq!(Bivec4::zero(), Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+    assert_eq!(Bivec4::one(), Bivec4::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0));
+    assert_eq!(
+        Bivec4::from(Bivec3::new(1.0, 2.0, 3.0)),
+        Bivec4::new(1.0, 2.0, 0.0, 3.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_arithmetic() {
+    let b1 = Bivec4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    let b2 = Bivec4::new(1.0, 1.0, 1.0, 1.0, 1.0, 1.0);
+
+    assert_eq!(b1 + b2, Bivec4::new(2.0, 3.0, 4.0, 5.0, 6.0, 7.0));
+    assert_eq!(b1 - b2, Bivec4::new(0.0, 1.0, 2.0, 3.0, 4.0, 5.0));
+    assert_eq!(b1 * 2.0, 2.0 * b1);
+    assert_eq!(b1 * 2.0 / 2.0, b1);
+    assert_eq!(-b1 + b1, Bivec4::zero());
+    assert_eq!(b2.mag_sq(), 6.0);
+    assert_eq!(b1.dot(b2), 21.0);
+}
+
+#[test]
+fn test_dual_and_wedge() {
+    let xy = Bivec4::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let zw = Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0);
+
+    assert_eq!(xy.dual(), -zw);
+    assert_eq!(xy.wedge(zw), 1.0);
+    assert_eq!(xy.wedge(Bivec4::new(0.0, 1.0, 0.0, 0.0, 0.0, 0.0)), 0.0);
+
+    let b = Bivec4::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+    assert_eq!(b.dual().dual(), b);
+    assert!(xy.is_simple());
+    assert!(!(xy + zw).is_simple());
+
+    let (plus, minus) = b.split_isoclinic();
+    assert_eq!(plus + minus, b);
+    assert_eq!(plus.dual(), plus);
+    assert_eq!(minus.dual(), -minus);
+}
+
+#[test]
+fn test_exp() {
+    // Simple planes exponentiate like their 3D counterparts
+    let bv3 = Bivec3::new(0.3, -0.2, 0.5);
+    let r3 = bv3.exp();
+    let r4 = Bivec4::from(bv3).exp();
+    assert_approx_eq!(r4, Rot4::from(r3), epsilon = 1e-6);
+
+    // Orthogonal planes commute, so the exponential factors
+    let b1 = Bivec4::new(0.4, 0.0, 0.0, 0.0, 0.0, 0.0);
+    let b2 = Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, -1.1);
+    assert_approx_eq!((b1 + b2).exp(), b1.exp() * b2.exp(), epsilon = 1e-6);
+
+    assert_eq!(Bivec4::zero().exp(), Rot4::identity());
+    assert_approx_eq!((b1 + b2).exp().mag(), 1.0, epsilon = 1e-6);
+}
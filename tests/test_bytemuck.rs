@@ -0,0 +1,76 @@
+#![cfg(feature = "bytemuck")]
+
+use cliffy::pga3d::*;
+use cliffy::*;
+
+#[test]
+fn test_cast_slices() {
+    let vertices = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 5.0, 6.0)];
+    let floats: &[f32] = bytemuck::cast_slice(&vertices);
+    assert_eq!(floats, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    let back: &[Vec3] = bytemuck::cast_slice(floats);
+    assert_eq!(back, &vertices);
+
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.5);
+    let floats: [f32; 4] = bytemuck::cast(r);
+    assert_eq!(floats, [r.s, r.bv.xy, r.bv.xz, r.bv.yz]);
+}
+
+#[test]
+fn test_bytes() {
+    let m = DMat4::identity();
+    let bytes = bytemuck::bytes_of(&m);
+    assert_eq!(bytes.len(), 16 * 8);
+    assert_eq!(bytemuck::pod_read_unaligned::<DMat4>(bytes), m);
+
+    let motor = Motor3::from_translation(Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(bytemuck::bytes_of(&motor).len(), 8 * 4);
+}
+
+#[test]
+fn test_zeroed() {
+    assert_eq!(<Vec4 as bytemuck::Zeroable>::zeroed(), Vec4::zero());
+    assert_eq!(<DBivec3 as bytemuck::Zeroable>::zeroed(), DBivec3::zero());
+    assert_eq!(<Mat3 as bytemuck::Zeroable>::zeroed(), Mat3::zero());
+}
+
+#[test]
+fn test_wide() {
+    let v = Vec3x4::new([1.0, 2.0, 3.0, 4.0], [5.0; 4], [6.0, 7.0, 8.0, 9.0]);
+    let floats: [f32; 12] = bytemuck::cast(v);
+    assert_eq!(floats[..4], v.x);
+    assert_eq!(floats[8..], v.z);
+    assert_eq!(bytemuck::cast::<_, Vec3x4>(floats), v);
+
+    let r = Rot3x8::splat(Rot3::from_axis_angle(Vec3::up(), 0.5));
+    assert_eq!(bytemuck::bytes_of(&r).len(), 4 * 8 * 4);
+    assert_eq!(
+        bytemuck::pod_read_unaligned::<Rot3x8>(bytemuck::bytes_of(&r)),
+        r
+    );
+    assert_eq!(<DRot3x4 as bytemuck::Zeroable>::zeroed().s, [0.0; 4]);
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd() {
+    let v = Vec3A::new(1.0, 2.0, 3.0);
+    let floats: [f32; 4] = bytemuck::cast(v);
+    assert_eq!(floats, [1.0, 2.0, 3.0, 0.0]);
+    assert_eq!(bytemuck::cast::<_, Vec3A>(floats), v);
+
+    // The padding lane doesn't leak into the vector
+    let v: Vec3A = bytemuck::cast([1.0f32, 2.0, 3.0, 7.0]);
+    assert_eq!(v.mag_sq(), 14.0);
+    assert_eq!(Vec3::from(v / 2.0), Vec3::new(0.5, 1.0, 1.5));
+
+    let v = Vec4A::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(bytemuck::cast::<_, [f32; 4]>(v), [1.0, 2.0, 3.0, 4.0]);
+
+    let r = Rot3A::from(Rot3::from_axis_angle(Vec3::up(), 0.5));
+    let rotors = [r, Rot3A::identity()];
+    let floats: &[f32] = bytemuck::cast_slice(&rotors);
+    assert_eq!(floats[4..], [0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(<Rot3A as bytemuck::Zeroable>::zeroed(), Rot3A::default());
+}
@@ -0,0 +1,161 @@
+use cliffy::cga3d::*;
+use cliffy::*;
+
+type Mv = Multivector<f32>;
+
+fn assert_on(x: Vec3, object: Mv) {
+    let inner = Mv::up(x).inner(object);
+    assert_approx_eq!(inner, Mv::zero(), epsilon = 1e-3);
+}
+
+#[test]
+fn test_up_down() {
+    let x = Vec3::new(1.0, -2.0, 3.0);
+    let p = Mv::up(x);
+    assert_approx_eq!(p.down(), x, epsilon = 1e-3);
+    assert_approx_eq!((p * 4.0).down(), x, epsilon = 1e-3);
+
+    // Points are null and their inner product is half the squared distance
+    let y = Vec3::new(-1.0, 0.5, 2.0);
+    assert_approx_eq!(p.scalar_product(p), 0.0, epsilon = 1e-3);
+    assert_approx_eq!(
+        p.scalar_product(Mv::up(y)),
+        -0.5 * (x - y).mag_sq(),
+        epsilon = 1e-3
+    );
+    assert_approx_eq!(Mv::no().scalar_product(Mv::ni()), -1.0, epsilon = 1e-3);
+}
+
+#[test]
+fn test_sphere() {
+    let center = Vec3::new(1.0, 2.0, -1.0);
+    let s = Mv::sphere(center, 2.0);
+    assert_approx_eq!(s.center(), center, epsilon = 1e-3);
+    assert_approx_eq!(s.radius_sq(), 4.0, epsilon = 1e-3);
+    assert_on(center + Vec3::new(0.0, 2.0, 0.0), s);
+    assert_on(center + Vec3::new(2.0, 0.0, 0.0).normalized() * 2.0, s);
+
+    let s = Mv::sphere_through(
+        Vec3::new(3.0, 0.0, 0.0),
+        Vec3::new(-1.0, 0.0, 0.0),
+        Vec3::new(1.0, 2.0, 0.0),
+        Vec3::new(1.0, 0.0, 2.0),
+    );
+    assert_approx_eq!(s.center(), Vec3::new(1.0, 0.0, 0.0), epsilon = 1e-3);
+    assert_approx_eq!(s.radius_sq(), 4.0, epsilon = 1e-3);
+}
+
+#[test]
+fn test_circle() {
+    // Spheres of radius 2 around (0, 0, 0) and (1, 0, 0) meet in the circle
+    // x = 0.5 with radius sqrt(4 - 0.25)
+    let a = Mv::sphere(Vec3::zero(), 2.0);
+    let b = Mv::sphere(Vec3::new(1.0, 0.0, 0.0), 2.0);
+    let c = a.meet(b);
+    assert_approx_eq!(c.center(), Vec3::new(0.5, 0.0, 0.0), epsilon = 1e-3);
+    assert_approx_eq!(c.radius_sq(), 3.75, epsilon = 1e-3);
+    assert_on(Vec3::new(0.5, 3.75f32.sqrt(), 0.0), c);
+
+    let c = Mv::circle_through(
+        Vec3::new(1.0, 0.0, 5.0),
+        Vec3::new(0.0, 1.0, 5.0),
+        Vec3::new(-1.0, 0.0, 5.0),
+    );
+    assert_approx_eq!(c.center(), Vec3::new(0.0, 0.0, 5.0), epsilon = 1e-3);
+    assert_approx_eq!(c.radius_sq(), 1.0, epsilon = 1e-3);
+
+    // Disjoint spheres meet in an imaginary circle
+    let far = Mv::sphere(Vec3::new(5.0, 0.0, 0.0), 1.0);
+    assert!(a.meet(far).radius_sq() < 0.0);
+}
+
+#[test]
+fn test_point_pair() {
+    let a = Mv::sphere(Vec3::zero(), 2.0);
+    let b = Mv::sphere(Vec3::new(1.0, 0.0, 0.0), 2.0);
+    let c = Mv::sphere(Vec3::new(0.0, 1.0, 0.0), 2.0);
+    let pair = a.meet(b).meet(c);
+
+    let z = 3.5f32.sqrt();
+    assert_approx_eq!(pair.center(), Vec3::new(0.5, 0.5, 0.0), epsilon = 1e-3);
+    assert_approx_eq!(pair.radius_sq(), 3.5, epsilon = 1e-3);
+
+    let (p, q) = pair.point_pair_points();
+    let (p, q) = if p.z < q.z { (p, q) } else { (q, p) };
+    assert_approx_eq!(p, Vec3::new(0.5, 0.5, -z), epsilon = 1e-3);
+    assert_approx_eq!(q, Vec3::new(0.5, 0.5, z), epsilon = 1e-3);
+
+    let (p, q) =
+        Mv::point_pair(Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.0, 1.0)).point_pair_points();
+    let (p, q) = if p.x < q.x { (p, q) } else { (q, p) };
+    assert_approx_eq!(p, Vec3::new(-1.0, 0.0, 1.0), epsilon = 1e-3);
+    assert_approx_eq!(q, Vec3::new(1.0, 2.0, 3.0), epsilon = 1e-3);
+}
+
+#[test]
+fn test_flats() {
+    let plane = Mv::plane(Vec3::new(0.0, 0.0, 1.0), 2.0);
+    assert_on(Vec3::new(3.0, -1.0, 2.0), plane);
+
+    // A plane through the centre of a sphere cuts a great circle
+    let sphere = Mv::sphere(Vec3::new(0.0, 0.0, 2.0), 3.0);
+    let circle = sphere.meet(plane);
+    assert_approx_eq!(circle.center(), Vec3::new(0.0, 0.0, 2.0), epsilon = 1e-3);
+    assert_approx_eq!(circle.radius_sq(), 9.0, epsilon = 1e-3);
+
+    // Two planes meet in a line
+    let other = Mv::plane(Vec3::new(1.0, 0.0, 0.0), 1.0);
+    let line = plane.meet(other);
+    assert_on(Vec3::new(1.0, 5.0, 2.0), line);
+    assert_on(Vec3::new(1.0, -3.0, 2.0), line);
+
+    let line = Mv::line_through(Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 1.0, 0.0));
+    assert_on(Vec3::new(1.0, 7.0, 0.0), line);
+}
+
+#[test]
+fn test_versors() {
+    let x = Vec3::new(1.0, -2.0, 3.0);
+
+    let t = Vec3::new(0.5, 4.0, -1.0);
+    assert_approx_eq!(
+        Mv::translator(t).transform(Mv::up(x)).down(),
+        x + t,
+        epsilon = 1e-3
+    );
+
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 1.0, 0.0), 1.2);
+    assert_approx_eq!(
+        Mv::rotor(r).transform(Mv::up(x)).down(),
+        r * x,
+        epsilon = 1e-3
+    );
+
+    assert_approx_eq!(
+        Mv::dilator(3.0).transform(Mv::up(x)).down(),
+        x * 3.0,
+        epsilon = 1e-3
+    );
+
+    // Versors act on whole objects, not just points
+    let sphere = Mv::translator(t).transform(Mv::sphere(Vec3::zero(), 2.0));
+    assert_approx_eq!(sphere.center(), t, epsilon = 1e-3);
+    assert_approx_eq!(sphere.radius_sq(), 4.0, epsilon = 1e-3);
+
+    let sphere = Mv::dilator(0.5).transform(Mv::sphere(x, 2.0));
+    assert_approx_eq!(sphere.center(), x * 0.5, epsilon = 1e-3);
+    assert_approx_eq!(sphere.radius_sq(), 1.0, epsilon = 1e-3);
+}
+
+#[test]
+fn test_inversion() {
+    let center = Vec3::new(1.0, 0.0, 0.0);
+    let s = Mv::inversion(center, 2.0);
+    let x = Vec3::new(2.0, 3.0, -1.0);
+    let expected = center + (x - center) * (4.0 / (x - center).mag_sq());
+    assert_approx_eq!(s.transform(Mv::up(x)).down(), expected, epsilon = 1e-3);
+
+    // Points on the sphere are fixed
+    let on = center + Vec3::new(0.0, 2.0, 0.0);
+    assert_approx_eq!(s.transform(Mv::up(on)).down(), on, epsilon = 1e-3);
+}
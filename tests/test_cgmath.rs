@@ -0,0 +1,119 @@
+#![cfg(feature = "cgmath")]
+
+mod common;
+
+use cgmath::Rotation;
+use cliffy::*;
+use common::*;
+
+#[test]
+fn test_vectors() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        cgmath::Vector3::from(v),
+        cgmath::Vector3::new(1.0, 2.0, 3.0)
+    );
+
+    let vs = vectors();
+    assert_vectors_round_trip::<cgmath::Vector2<_>, cgmath::Vector3<_>, cgmath::Vector4<_>, _>(&vs);
+    assert_vectors_round_trip::<cgmath::Vector2<_>, cgmath::Vector3<_>, cgmath::Vector4<_>, _>(
+        &vs.map(|v| v.as_dvec4()),
+    );
+}
+
+#[test]
+fn test_rotors() {
+    assert_round_trip::<cgmath::Quaternion<_>, _>(rotor());
+    assert_rotates_like(rotor(), point(), 1e-5, |q: cgmath::Quaternion<_>, v| {
+        q.rotate_vector(v.into()).into()
+    });
+    assert_rotates_like(
+        drotor(),
+        point().as_dvec3(),
+        1e-12,
+        |q: cgmath::Quaternion<_>, v| q.rotate_vector(v.into()).into(),
+    );
+}
+
+#[test]
+fn test_matrices() {
+    let (m2, m3, m4) = (mat2(), mat3(), mat4());
+
+    // Columns stay columns
+    assert_eq!(cgmath::Matrix2::from(m2).x, cgmath::Vector2::new(1.0, 2.0));
+    assert_eq!(
+        cgmath::Matrix3::from(m3).y,
+        cgmath::Vector3::new(4.0, 5.0, 6.0)
+    );
+    let m = Mat4::from_rot3_translation(rotor(), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        cgmath::Matrix4::from(m).w,
+        cgmath::Vector4::new(1.0, 2.0, 3.0, 1.0)
+    );
+    assert_round_trip::<cgmath::Matrix4<_>, _>(m);
+
+    assert_matrices_round_trip::<cgmath::Matrix2<_>, cgmath::Matrix3<_>, cgmath::Matrix4<_>, _>(
+        m2, m3, m4,
+    );
+    assert_matrices_round_trip::<cgmath::Matrix2<_>, cgmath::Matrix3<_>, cgmath::Matrix4<_>, _>(
+        m2.as_dmat2(),
+        m3.as_dmat3(),
+        m4.as_dmat4(),
+    );
+    assert_products_agree::<cgmath::Matrix2<_>, cgmath::Matrix3<_>, cgmath::Matrix4<_>, _>(
+        m2.as_dmat2(),
+        m3.as_dmat3(),
+        m4.as_dmat4(),
+    );
+
+    // Rotation matrices transform points the same
+    let m = rotor().into_matrix();
+    assert_round_trip::<cgmath::Matrix3<_>, _>(m);
+    assert_approx_eq!(
+        Vec3::from(cgmath::Matrix3::from(m) * cgmath::Vector3::from(point())),
+        m * point(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_rotor_edge_cases() {
+    use cgmath::{InnerSpace, Rotation3};
+
+    assert_eq!(
+        cgmath::Quaternion::from(Rot3::identity()),
+        cgmath::Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    );
+
+    // r and -r map to q and -q
+    assert_eq!(
+        cgmath::Quaternion::from(-rotor()),
+        -cgmath::Quaternion::from(rotor())
+    );
+
+    // Nothing is normalised on the way through
+    let scaled = rotor() * 3.0;
+    assert_round_trip::<cgmath::Quaternion<_>, _>(scaled);
+    assert_approx_eq!(
+        cgmath::Quaternion::from(scaled).magnitude(),
+        3.0,
+        epsilon = 1e-5
+    );
+    assert_round_trip::<cgmath::Quaternion<_>, _>(Rot3::new(0.0, Bivec3::zero()));
+
+    // Rotations about each axis agree with cgmath's constructors
+    let v = point().as_dvec3();
+    for q in [
+        cgmath::Quaternion::from_angle_x(cgmath::Rad(0.8)),
+        cgmath::Quaternion::from_angle_y(cgmath::Rad(0.8)),
+        cgmath::Quaternion::from_angle_z(cgmath::Rad(0.8)),
+    ] {
+        let r = DRot3::from(q);
+        assert_approx_eq!(
+            DVec3::from(q.rotate_vector(v.into())),
+            r * v,
+            epsilon = 1e-12
+        );
+        assert_eq!(cgmath::Quaternion::from(r), q);
+    }
+}
@@ -0,0 +1,135 @@
+use cliffy::*;
+
+#[test]
+fn test_dvec2() {
+    let v1 = DVec2::new(3.0, 4.0);
+    let v2 = DVec2::new(-1.0, 2.0);
+
+    assert_eq!(v1 + v2, DVec2::new(2.0, 6.0));
+    assert_eq!(v1 - v2, DVec2::new(4.0, 2.0));
+    assert_eq!(2.0 * v1, DVec2::new(6.0, 8.0));
+    assert_eq!(v1.mag(), 5.0);
+    assert_eq!(v1.dot(v2), 5.0);
+    assert_eq!(v1.wedge(v2), DBivec2::new(10.0));
+    assert_eq!(v1.geom(v2), DRot2::new(5.0, DBivec2::new(10.0)));
+    assert_eq!(v1.normalized(), DVec2::new(0.6, 0.8));
+    assert_eq!(
+        DVec2::up().angle_between(DVec2::right()),
+        std::f64::consts::PI / 2.0
+    );
+}
+
+#[test]
+fn test_dvec3() {
+    let v1 = DVec3::new(3.0, 4.0, 5.0);
+    let v2 = DVec3::new(2.0, 1.0, 6.0);
+
+    assert_eq!(v1 + v2, DVec3::new(5.0, 5.0, 11.0));
+    assert_eq!(v1 - v2, DVec3::new(1.0, 3.0, -1.0));
+    assert_eq!(v1 * 2.0, DVec3::new(6.0, 8.0, 10.0));
+    assert_eq!(v1.dot(v2), 40.0);
+    assert_eq!(v1.cross(v2), DVec3::new(19.0, -8.0, -5.0));
+    assert_eq!(v1.wedge(v2), DBivec3::new(-5.0, 8.0, 19.0));
+    assert_eq!(v1.geom(v2), DRot3::new(40.0, DBivec3::new(-5.0, 8.0, 19.0)));
+    assert_eq!(DVec3::new(3.0, 4.0, 12.0).mag(), 13.0);
+    assert_eq!(
+        DVec3::new(2.0, 3.0, 8.0).projected(DVec3::new(5.0, 0.0, 0.0)),
+        DVec3::new(2.0, 0.0, 0.0)
+    );
+    assert_eq!(
+        DVec3::new(1.0, -1.0, 1.0).reflected(DVec3::up()),
+        DVec3::one()
+    );
+}
+
+#[test]
+fn test_dvec4() {
+    let v = DVec4::new(1.0, 2.0, 2.0, 4.0);
+
+    assert_eq!(v.mag(), 5.0);
+    assert_eq!(v + DVec4::one(), DVec4::new(2.0, 3.0, 3.0, 5.0));
+    assert_eq!(DVec3::from(v), DVec3::new(1.0, 2.0, 2.0));
+    assert_eq!(
+        DVec4::from(DVec2::new(1.0, 2.0)),
+        DVec4::new(1.0, 2.0, 0.0, 0.0)
+    );
+}
+
+#[test]
+fn test_dbivec() {
+    let bv = DBivec3::new(3.0, 4.0, 12.0);
+
+    assert_eq!(bv.mag(), 13.0);
+    assert_eq!(-bv, DBivec3::new(-3.0, -4.0, -12.0));
+    assert_eq!(DBivec2::new(-2.0).mag(), 2.0);
+}
+
+#[test]
+fn test_drot2() {
+    let r = DRot2::from_angle(std::f64::consts::PI / 2.0);
+    let v = r * DVec2::right();
+
+    assert_approx_eq!(v.x, 0.0, epsilon = 1e-12);
+    assert_approx_eq!(v.y, 1.0, epsilon = 1e-12);
+}
+
+#[test]
+fn test_drot3() {
+    let r = DRot3::from_angle_plane(std::f64::consts::PI / 2.0, DBivec3::new(1.0, 0.0, 0.0));
+    let v = r * DVec3::right();
+    let m = r.into_matrix();
+
+    assert_approx_eq!(v, DVec3::up(), epsilon = 1e-12);
+    assert_approx_eq!(m.cols[0], DVec3::up(), epsilon = 1e-12);
+    assert_eq!(r * r.reversed(), DRot3::identity());
+}
+
+#[test]
+fn test_lossless_conversions() {
+    let v: DVec3 = Vec3::new(1.5, -2.25, 3.0).into();
+    assert_eq!(v, DVec3::new(1.5, -2.25, 3.0));
+    assert_eq!(Vec2::new(1.5, -2.25).as_dvec2(), DVec2::new(1.5, -2.25));
+    assert_eq!(
+        Vec4::new(1.0, 2.0, 3.0, 4.0).as_dvec4(),
+        DVec4::new(1.0, 2.0, 3.0, 4.0)
+    );
+    assert_eq!(Bivec2::new(0.5).as_dbivec2(), DBivec2::new(0.5));
+    assert_eq!(
+        Bivec3::new(1.0, 2.0, 3.0).as_dbivec3(),
+        DBivec3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(DRot3::from(Rot3::identity()), DRot3::identity());
+    assert_eq!(DRot2::from(Rot2::identity()), DRot2::identity());
+
+    let m = Mat3::new(Vec3::right(), Vec3::up(), Vec3::forward()).as_dmat3();
+    assert_eq!(m.cols[0], DVec3::right());
+    assert_eq!(m.cols[1], DVec3::up());
+    assert_eq!(m.cols[2], DVec3::forward());
+}
+
+#[test]
+fn test_lossy_conversions() {
+    let v = DVec3::new(0.1, 0.2, 0.3);
+    assert_eq!(v.as_vec3(), Vec3::new(0.1, 0.2, 0.3));
+    assert_eq!(DVec2::new(0.1, 0.2).as_vec2(), Vec2::new(0.1, 0.2));
+    assert_eq!(
+        DVec4::new(0.1, 0.2, 0.3, 0.4).as_vec4(),
+        Vec4::new(0.1, 0.2, 0.3, 0.4)
+    );
+    assert_eq!(
+        DBivec3::new(0.1, 0.2, 0.3).as_bivec3(),
+        Bivec3::new(0.1, 0.2, 0.3)
+    );
+    assert_eq!(
+        DRot3::new(0.5, DBivec3::new(0.1, 0.2, 0.3)).as_rot3(),
+        Rot3::new(0.5, Bivec3::new(0.1, 0.2, 0.3))
+    );
+    assert_eq!(
+        DRot2::new(0.5, DBivec2::new(0.1)).as_rot2(),
+        Rot2::new(0.5, Bivec2::new(0.1))
+    );
+
+    let m = DMat2::new(DVec2::new(0.1, 0.2), DVec2::new(0.3, 0.4)).as_mat2();
+    assert_eq!(m.cols[0], Vec2::new(0.1, 0.2));
+    assert_eq!(m.cols[1], Vec2::new(0.3, 0.4));
+}
@@ -0,0 +1,123 @@
+#![cfg(feature = "glam")]
+
+mod common;
+
+use cliffy::*;
+use common::*;
+
+#[test]
+fn test_vectors() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(glam::Vec3::from(v), glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(
+        glam::Vec4::from(Vec4::new(1.0, 2.0, 3.0, 4.0)),
+        glam::Vec4::new(1.0, 2.0, 3.0, 4.0)
+    );
+
+    let vs = vectors();
+    assert_vectors_round_trip::<glam::Vec2, glam::Vec3, glam::Vec4, _>(&vs);
+    assert_vectors_round_trip::<glam::DVec2, glam::DVec3, glam::DVec4, _>(
+        &vs.map(|v| v.as_dvec4()),
+    );
+}
+
+#[test]
+fn test_rotors() {
+    assert_round_trip::<glam::Quat, _>(rotor());
+    assert_rotates_like(rotor(), point(), 1e-5, |q: glam::Quat, v| {
+        (q * glam::Vec3::from(v)).into()
+    });
+    assert_rotates_like(drotor(), point().as_dvec3(), 1e-12, |q: glam::DQuat, v| {
+        (q * glam::DVec3::from(v)).into()
+    });
+
+    let q = glam::DQuat::from_axis_angle(glam::DVec3::new(0.0, 1.0, 0.0), 0.7);
+    let v = point().as_dvec3();
+    assert_approx_eq!(
+        DVec3::from(q * glam::DVec3::from(v)),
+        DRot3::from(q) * v,
+        epsilon = 1e-12
+    );
+}
+
+#[test]
+fn test_matrices() {
+    let (m2, m3, m4) = (mat2(), mat3(), mat4());
+
+    // Columns stay columns
+    assert_eq!(glam::Mat2::from(m2).x_axis, glam::Vec2::new(1.0, 2.0));
+    assert_eq!(glam::Mat3::from(m3).y_axis, glam::Vec3::new(4.0, 5.0, 6.0));
+    assert_eq!(
+        glam::Mat4::from(m4).w_axis,
+        glam::Vec4::new(13.0, 14.0, 15.0, 17.0)
+    );
+    assert_eq!(
+        glam::DMat2::from(m2.as_dmat2()).col(1),
+        glam::DVec2::new(3.0, 4.0)
+    );
+
+    assert_matrices_round_trip::<glam::Mat2, glam::Mat3, glam::Mat4, _>(m2, m3, m4);
+    assert_matrices_round_trip::<glam::DMat2, glam::DMat3, glam::DMat4, _>(
+        m2.as_dmat2(),
+        m3.as_dmat3(),
+        m4.as_dmat4(),
+    );
+    assert_products_agree::<glam::Mat2, glam::Mat3, glam::Mat4, _>(m2, m3, m4);
+    assert_eq!(
+        DMat3::from(glam::DMat3::from(m3.as_dmat3()).inverse()),
+        m3.as_dmat3().inverse()
+    );
+
+    // Rotation matrices agree with glam's, and transform points the same
+    let m = rotor().into_matrix();
+    assert_round_trip::<glam::Mat3, _>(m);
+    assert_approx_eq!(
+        Mat3::from(glam::Mat3::from_quat(glam::Quat::from(rotor()))),
+        m,
+        epsilon = 1e-6
+    );
+    assert_approx_eq!(
+        Vec3::from(glam::Mat3::from(m) * glam::Vec3::from(point())),
+        m * point(),
+        epsilon = 1e-5
+    );
+    let m4 = Mat4::from_rot3_translation(rotor(), Vec3::new(1.0, 2.0, 3.0));
+    assert_round_trip::<glam::Mat4, _>(m4);
+}
+
+#[test]
+fn test_rotor_edge_cases() {
+    let v = point();
+    assert_eq!(glam::Quat::from(Rot3::identity()), glam::Quat::IDENTITY);
+    assert_eq!(glam::DQuat::from(DRot3::identity()), glam::DQuat::IDENTITY);
+
+    // r and -r map to q and -q
+    assert_eq!(glam::Quat::from(-rotor()), -glam::Quat::from(rotor()));
+    assert_eq!(Rot3::from(-glam::Quat::from(rotor())), -rotor());
+
+    // Nothing is normalised on the way through
+    let scaled = rotor() * 3.0;
+    assert_round_trip::<glam::Quat, _>(scaled);
+    assert_approx_eq!(glam::Quat::from(scaled).length(), 3.0, epsilon = 1e-5);
+    assert_round_trip::<glam::Quat, _>(Rot3::new(0.0, Bivec3::zero()));
+
+    // Rotations about each axis agree with glam's constructors
+    for &(q, axis) in &[
+        (glam::Quat::from_rotation_x(0.8), Vec3::right()),
+        (glam::Quat::from_rotation_y(0.8), Vec3::up()),
+        (glam::Quat::from_rotation_z(0.8), Vec3::forward()),
+    ] {
+        let r = Rot3::from(q);
+        assert_approx_eq!(Vec3::from(q * glam::Vec3::from(v)), r * v, epsilon = 1e-5);
+        assert_approx_eq!(r * axis, axis, epsilon = 1e-6);
+    }
+
+    let d = drotor();
+    let dq = glam::DQuat::from(d);
+    assert_round_trip::<glam::DQuat, _>(d);
+    assert_approx_eq!(
+        DMat3::from(glam::DMat3::from_quat(dq)),
+        d.into_matrix(),
+        epsilon = 1e-12
+    );
+}
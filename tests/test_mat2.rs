@@ -0,0 +1,114 @@
+use cliffy::*;
+
+#[test]
+fn test_constructions() {
+    assert_eq!(
+        Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)),
+        Mat2 {
+            cols: [Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)]
+        }
+    );
+    assert_eq!(Mat2::identity(), Mat2::new(Vec2::right(), Vec2::up()));
+    assert_eq!(Mat2::zero(), Mat2::new(Vec2::zero(), Vec2::zero()));
+    assert_eq!(Mat2::default(), Mat2::zero());
+}
+
+#[test]
+fn test_accessors() {
+    let mut m = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+
+    assert_eq!(m.col(1), Vec2::new(3.0, 4.0));
+    assert_eq!(m.row(1), Vec2::new(2.0, 4.0));
+    assert_eq!(m[0], Vec2::new(1.0, 2.0));
+
+    m[1].x = 5.0;
+    assert_eq!(m.row(0), Vec2::new(1.0, 5.0));
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_range() {
+    let m = Mat2::identity();
+
+    let _ = m[2];
+}
+
+#[test]
+fn test_add_sub_neg() {
+    let m1 = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+    let m2 = Mat2::new(Vec2::new(4.0, 3.0), Vec2::new(2.0, 1.0));
+
+    assert_eq!(m1 + m2, Mat2::new(Vec2::uni(5.0), Vec2::uni(5.0)));
+    assert_eq!(m1 - m1, Mat2::zero());
+    assert_eq!(-m1, m1 * -1.0);
+}
+
+#[test]
+fn test_mul() {
+    let m1 = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+    let m2 = Mat2::new(Vec2::new(5.0, 6.0), Vec2::new(7.0, 8.0));
+
+    assert_eq!(
+        m1 * m2,
+        Mat2::new(Vec2::new(23.0, 34.0), Vec2::new(31.0, 46.0))
+    );
+    assert_eq!(m1 * Vec2::new(1.0, 1.0), Vec2::new(4.0, 6.0));
+    assert_eq!(m1 * Mat2::identity(), m1);
+    assert_eq!(2.0 * m1, m1 + m1);
+
+    let mut m = m1;
+    m *= m2;
+    assert_eq!(m, m1 * m2);
+}
+
+#[test]
+fn test_transpose() {
+    let mut m = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+
+    assert_eq!(
+        m.transposed(),
+        Mat2::new(Vec2::new(1.0, 3.0), Vec2::new(2.0, 4.0))
+    );
+
+    m.transpose();
+    m.transpose();
+    assert_eq!(m, Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0)));
+}
+
+#[test]
+fn test_determinant() {
+    let m = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+
+    assert_eq!(m.determinant(), -2.0);
+    assert_eq!(Mat2::identity().determinant(), 1.0);
+}
+
+#[test]
+fn test_inverse() {
+    let m = Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(3.0, 4.0));
+
+    assert_eq!(
+        m.inverse(),
+        Mat2::new(Vec2::new(-2.0, 1.0), Vec2::new(1.5, -0.5))
+    );
+    assert_eq!(m * m.inverse(), Mat2::identity());
+    assert_eq!(
+        Mat2::new(Vec2::new(1.0, 2.0), Vec2::new(2.0, 4.0)).try_inverse(),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_inverse_singular() {
+    let _ = Mat2::zero().inverse();
+}
+
+#[test]
+fn test_rotor_matrix() {
+    let r = Rot2::from_angle(0.7);
+    let v = Vec2::new(2.0, -3.0);
+    let rv = r.into_matrix() * v;
+
+    assert_approx_eq!(rv, r * v, epsilon = 1e-6);
+}
@@ -0,0 +1,101 @@
+use cliffy::*;
+
+fn mat() -> Mat3 {
+    Mat3::new(
+        Vec3::new(2.0, 0.0, 1.0),
+        Vec3::new(1.0, 3.0, 0.0),
+        Vec3::new(0.0, 1.0, 4.0),
+    )
+}
+
+#[test]
+fn test_constructions() {
+    assert_eq!(
+        Mat3::identity(),
+        Mat3::new(Vec3::right(), Vec3::up(), Vec3::forward())
+    );
+    assert_eq!(
+        Mat3::zero(),
+        Mat3 {
+            cols: [Vec3::zero(); 3]
+        }
+    );
+
+    let a: [[f32; 3]; 3] = mat().into();
+    assert_eq!(a, [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]);
+    assert_eq!(Mat3::from(a), mat());
+}
+
+#[test]
+fn test_accessors() {
+    let m = mat();
+
+    assert_eq!(m.col(0), Vec3::new(2.0, 0.0, 1.0));
+    assert_eq!(m.row(0), Vec3::new(2.0, 1.0, 0.0));
+    assert_eq!(m.row(2), Vec3::new(1.0, 0.0, 4.0));
+    assert_eq!(m[2], Vec3::new(0.0, 1.0, 4.0));
+}
+
+#[test]
+fn test_add_sub() {
+    let m = mat();
+
+    assert_eq!(m + m, m * 2.0);
+    assert_eq!(m - m, Mat3::zero());
+    assert_eq!(m / 2.0, 0.5 * m);
+}
+
+#[test]
+fn test_mul() {
+    let m = mat();
+
+    assert_eq!(m * Vec3::new(1.0, 2.0, 3.0), Vec3::new(4.0, 9.0, 13.0));
+    assert_eq!(m * Mat3::identity(), m);
+    assert_eq!(Mat3::identity() * m, m);
+    assert_eq!(
+        m * m,
+        Mat3::new(
+            Vec3::new(4.0, 1.0, 6.0),
+            Vec3::new(5.0, 9.0, 1.0),
+            Vec3::new(1.0, 7.0, 16.0),
+        )
+    );
+}
+
+#[test]
+fn test_transpose() {
+    let m = mat();
+
+    assert_eq!(m.transposed().transposed(), m);
+    assert_eq!(m.transposed().col(0), m.row(0));
+}
+
+#[test]
+fn test_determinant() {
+    assert_eq!(mat().determinant(), 25.0);
+    assert_eq!(Mat3::identity().determinant(), 1.0);
+    assert_eq!(Mat3::zero().determinant(), 0.0);
+}
+
+#[test]
+fn test_inverse() {
+    let m = mat();
+
+    assert_approx_eq!(m * m.inverse(), Mat3::identity(), epsilon = 1e-6);
+    assert_approx_eq!(m.inverse() * m, Mat3::identity(), epsilon = 1e-6);
+    assert_eq!(
+        Mat3::new(Vec3::right(), Vec3::right(), Vec3::up()).try_inverse(),
+        None
+    );
+}
+
+#[test]
+fn test_rotor_matrix() {
+    let r = Rot3::from_angle_plane(1.2, Bivec3::new(0.3, -0.4, 0.5).normalized());
+    let m = r.into_matrix();
+    let v = Vec3::new(2.0, -3.0, 0.5);
+
+    assert_approx_eq!(m * v, r * v, epsilon = 1e-5);
+    assert_approx_eq!(m.determinant(), 1.0, epsilon = 1e-5);
+    assert_approx_eq!(m.inverse(), m.transposed(), epsilon = 1e-6);
+}
@@ -0,0 +1,353 @@
+use cliffy::*;
+
+fn mat() -> Mat4 {
+    Mat4::new(
+        Vec4::new(2.0, 0.0, 1.0, 0.0),
+        Vec4::new(1.0, 3.0, 0.0, 0.0),
+        Vec4::new(0.0, 1.0, 4.0, 0.0),
+        Vec4::new(5.0, -2.0, 1.0, 1.0),
+    )
+}
+
+#[test]
+fn test_constructions() {
+    assert_eq!(
+        Mat4::identity(),
+        Mat4::new(
+            Vec4::new(1.0, 0.0, 0.0, 0.0),
+            Vec4::new(0.0, 1.0, 0.0, 0.0),
+            Vec4::new(0.0, 0.0, 1.0, 0.0),
+            Vec4::new(0.0, 0.0, 0.0, 1.0),
+        )
+    );
+    assert_eq!(Mat4::zero(), Mat4::default());
+}
+
+#[test]
+fn test_accessors() {
+    let m = mat();
+
+    assert_eq!(m.col(3), Vec4::new(5.0, -2.0, 1.0, 1.0));
+    assert_eq!(m.row(0), Vec4::new(2.0, 1.0, 0.0, 5.0));
+    assert_eq!(m.row(3), Vec4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_mul() {
+    let m = mat();
+
+    assert_eq!(
+        m * Vec4::new(1.0, 2.0, 3.0, 1.0),
+        Vec4::new(9.0, 7.0, 14.0, 1.0)
+    );
+    assert_eq!(m * Mat4::identity(), m);
+    assert_eq!(Mat4::identity() * m, m);
+    assert_eq!((m * m).col(3), m * m.col(3));
+}
+
+#[test]
+fn test_transpose() {
+    let m = mat();
+
+    assert_eq!(m.transposed().transposed(), m);
+    assert_eq!(m.transposed().row(3), m.col(3));
+}
+
+#[test]
+fn test_determinant() {
+    assert_eq!(mat().determinant(), 25.0);
+    assert_eq!(mat().transposed().determinant(), 25.0);
+    assert_eq!(Mat4::identity().determinant(), 1.0);
+    assert_eq!((Mat4::identity() * 2.0).determinant(), 16.0);
+}
+
+#[test]
+fn test_inverse() {
+    let m = mat();
+    let full = Mat4::new(
+        Vec4::new(1.0, 2.0, 0.0, 1.0),
+        Vec4::new(0.0, 1.0, 3.0, -1.0),
+        Vec4::new(2.0, 0.0, 1.0, 1.0),
+        Vec4::new(1.0, 1.0, 1.0, 2.0),
+    );
+
+    assert_approx_eq!(m * m.inverse(), Mat4::identity(), epsilon = 1e-5);
+    assert_approx_eq!(full * full.inverse(), Mat4::identity(), epsilon = 1e-5);
+    assert_approx_eq!(full.inverse() * full, Mat4::identity(), epsilon = 1e-5);
+    assert_eq!(Mat4::zero().try_inverse(), None);
+}
+
+fn cols(c: [[f32; 4]; 4]) -> Mat4 {
+    Mat4::from(c)
+}
+
+fn project(m: Mat4, v: Vec3) -> Vec3 {
+    let p = m * Vec4::new(v.x, v.y, v.z, 1.0);
+    Vec3::new(p.x, p.y, p.z) / p.w
+}
+
+#[test]
+fn test_perspective() {
+    let fov = std::f32::consts::FRAC_PI_2;
+    let (n, f) = (1.0, 10.0);
+    let rh = |depth| Mat4::perspective(fov, 2.0, n, f, Handedness::Right, depth);
+
+    assert_approx_eq!(
+        rh(DepthRange::ZeroToOne),
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -10.0 / 9.0, -1.0],
+            [0.0, 0.0, -10.0 / 9.0, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        rh(DepthRange::NegativeOneToOne),
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -11.0 / 9.0, -1.0],
+            [0.0, 0.0, -20.0 / 9.0, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        rh(DepthRange::OneToZero),
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0 / 9.0, -1.0],
+            [0.0, 0.0, 10.0 / 9.0, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        Mat4::perspective(fov, 2.0, n, f, Handedness::Left, DepthRange::ZeroToOne),
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 10.0 / 9.0, 1.0],
+            [0.0, 0.0, -10.0 / 9.0, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+
+    // The near and far planes land on the ends of the depth range
+    for &hand in &[Handedness::Left, Handedness::Right] {
+        let z = if hand == Handedness::Left { 1.0 } else { -1.0 };
+        for &(depth, near, far) in &[
+            (DepthRange::ZeroToOne, 0.0, 1.0),
+            (DepthRange::NegativeOneToOne, -1.0, 1.0),
+            (DepthRange::OneToZero, 1.0, 0.0),
+        ] {
+            let m = Mat4::perspective(fov, 2.0, n, f, hand, depth);
+            assert_approx_eq!(
+                project(m, Vec3::new(0.5, 0.5, n * z)).z,
+                near,
+                epsilon = 1e-5
+            );
+            assert_approx_eq!(
+                project(m, Vec3::new(-3.0, 2.0, f * z)).z,
+                far,
+                epsilon = 1e-5
+            );
+            let corner = project(m, Vec3::new(2.0 * 5.0, 5.0, 5.0 * z));
+            assert_approx_eq!(corner.x, 1.0, epsilon = 1e-5);
+            assert_approx_eq!(corner.y, 1.0, epsilon = 1e-5);
+        }
+    }
+}
+
+#[test]
+fn test_perspective_infinite() {
+    let fov = std::f32::consts::FRAC_PI_2;
+    let m = Mat4::perspective_infinite(fov, 1.0, 0.5, Handedness::Right, DepthRange::ZeroToOne);
+    assert_approx_eq!(
+        m,
+        cols([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -1.0, -1.0],
+            [0.0, 0.0, -0.5, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+
+    let m = Mat4::perspective_infinite(fov, 1.0, 0.5, Handedness::Right, DepthRange::OneToZero);
+    assert_approx_eq!(
+        m,
+        cols([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, -1.0],
+            [0.0, 0.0, 0.5, 0.0],
+        ]),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(project(m, Vec3::new(0.0, 0.0, -0.5)).z, 1.0, epsilon = 1e-6);
+    assert_approx_eq!(project(m, Vec3::new(0.0, 0.0, -1e6)).z, 0.0, epsilon = 1e-5);
+}
+
+#[test]
+fn test_orthographic() {
+    let m = Mat4::orthographic(
+        -2.0,
+        2.0,
+        -1.0,
+        1.0,
+        1.0,
+        11.0,
+        Handedness::Right,
+        DepthRange::ZeroToOne,
+    );
+    assert_approx_eq!(
+        m,
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, -0.1, 0.0],
+            [0.0, 0.0, -0.1, 1.0],
+        ]),
+        epsilon = 1e-5
+    );
+
+    let m = Mat4::orthographic(
+        0.0,
+        4.0,
+        0.0,
+        2.0,
+        1.0,
+        11.0,
+        Handedness::Left,
+        DepthRange::NegativeOneToOne,
+    );
+    assert_approx_eq!(
+        m,
+        cols([
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.2, 0.0],
+            [-1.0, -1.0, -1.2, 1.0],
+        ]),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        project(m, Vec3::new(4.0, 2.0, 11.0)),
+        Vec3::one(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_look_at() {
+    let m = Mat4::look_at(
+        Vec3::new(0.0, 0.0, 5.0),
+        Vec3::zero(),
+        Vec3::up(),
+        Handedness::Right,
+    );
+    assert_approx_eq!(
+        m,
+        cols([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, -5.0, 1.0],
+        ]),
+        epsilon = 1e-5
+    );
+
+    let eye = Vec3::new(1.0, 2.0, 3.0);
+    let target = Vec3::new(-2.0, 0.0, 7.0);
+    for &(hand, z) in &[(Handedness::Left, 1.0), (Handedness::Right, -1.0)] {
+        let m = Mat4::look_at(eye, target, Vec3::up(), hand);
+        let dist = (target - eye).mag();
+        assert_approx_eq!(project(m, eye), Vec3::zero(), epsilon = 1e-5);
+        assert_approx_eq!(
+            project(m, target),
+            Vec3::new(0.0, 0.0, dist * z),
+            epsilon = 1e-4
+        );
+        assert!(project(m, eye + Vec3::up()).y > 0.0);
+        assert_approx_eq!(m.determinant(), 1.0, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_look_at_parallel_up() {
+    // Looking straight down or up leaves up with no plane to define
+    let eye = Vec3::new(1.0, 5.0, 2.0);
+    for &target in &[Vec3::new(1.0, 0.0, 2.0), Vec3::new(1.0, 9.0, 2.0)] {
+        for &hand in &[Handedness::Left, Handedness::Right] {
+            let m = Mat4::look_at(eye, target, Vec3::up(), hand);
+            assert!(m.cols.iter().all(|c| c.mag_sq().is_finite()), "{:?}", m);
+            assert_approx_eq!(m.determinant(), 1.0, epsilon = 1e-5);
+            let z = match hand {
+                Handedness::Left => 1.0,
+                Handedness::Right => -1.0,
+            };
+            let dist = (target - eye).mag();
+            assert_approx_eq!(
+                project(m, target),
+                Vec3::new(0.0, 0.0, dist * z),
+                epsilon = 1e-4
+            );
+        }
+    }
+
+    // Along z the fallback can't be the forward axis itself
+    let m = Mat4::look_to(
+        Vec3::zero(),
+        Vec3::forward(),
+        Vec3::back(),
+        Handedness::Left,
+    );
+    assert_approx_eq!(m.determinant(), 1.0, epsilon = 1e-5);
+}
+
+#[test]
+fn test_from_rot3_translation() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.8);
+    let t = Vec3::new(1.0, -2.0, 3.0);
+    let m = Mat4::from_rot3_translation(r, t);
+    let p = Vec3::new(0.5, 1.0, -1.5);
+    assert_approx_eq!(project(m, p), (r * p + t), epsilon = 1e-5);
+    assert_eq!(m.row(3), Vec4::new(0.0, 0.0, 0.0, 1.0));
+}
+
+#[test]
+fn test_transform_point() {
+    let r = Rot3::from_axis_angle(Vec3::up(), 0.5);
+    let m = Mat4::from_rot3_translation(r, Vec3::new(1.0, 2.0, 3.0));
+    let p = Vec3::new(-1.0, 0.5, 2.0);
+    assert_approx_eq!(
+        m.transform_point(p),
+        r * p + Vec3::new(1.0, 2.0, 3.0),
+        epsilon = 1e-6
+    );
+    assert_approx_eq!(m.transform_vector(p), r * p, epsilon = 1e-6);
+
+    // The perspective divide puts the near plane at depth zero
+    let proj = Mat4::perspective(1.0, 1.0, 0.5, 10.0, Handedness::Left, DepthRange::ZeroToOne);
+    assert_approx_eq!(
+        proj.transform_point(Vec3::new(0.0, 0.0, 0.5)).z,
+        0.0,
+        epsilon = 1e-6
+    );
+    assert_approx_eq!(
+        proj.transform_point(Vec3::new(0.0, 0.0, 10.0)).z,
+        1.0,
+        epsilon = 1e-6
+    );
+
+    let mut points = [p, Vec3::zero()];
+    m.transform_points(&mut points);
+    assert_eq!(
+        points,
+        [m.transform_point(p), m.transform_point(Vec3::zero())]
+    );
+    let mut vectors = [p, Vec3::zero()];
+    m.transform_vectors(&mut vectors);
+    assert_eq!(vectors, [m.transform_vector(p), Vec3::zero()]);
+}
@@ -0,0 +1,95 @@
+#![cfg(feature = "mint")]
+
+mod common;
+
+use cliffy::*;
+use common::*;
+
+// v + 2w(q x v) + 2q x (q x v), the quaternion sandwich
+fn rotate<T: Scalar>(q: mint::Quaternion<T>, v: Vector3<T>) -> Vector3<T> {
+    let axis = Vector3::from(q.v);
+    let t = axis.cross(v) * T::two();
+    v + t * q.s + axis.cross(t)
+}
+
+#[test]
+fn test_vectors() {
+    let m: mint::Vector3<f32> = Vec3::new(1.0, 2.0, 3.0).into();
+    assert_eq!((m.x, m.y, m.z), (1.0, 2.0, 3.0));
+    let m = mint::Vector4::from(DVec4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!((m.x, m.y, m.z, m.w), (1.0, 2.0, 3.0, 4.0));
+
+    let vs = vectors();
+    assert_vectors_round_trip::<mint::Vector2<_>, mint::Vector3<_>, mint::Vector4<_>, _>(&vs);
+    assert_vectors_round_trip::<mint::Vector2<_>, mint::Vector3<_>, mint::Vector4<_>, _>(
+        &vs.map(|v| v.as_dvec4()),
+    );
+}
+
+#[test]
+fn test_rotors() {
+    assert_round_trip::<mint::Quaternion<_>, _>(rotor());
+    assert_round_trip::<mint::Quaternion<_>, _>(drotor());
+    assert_rotates_like(rotor(), point(), 1e-5, rotate);
+    assert_rotates_like(drotor(), point().as_dvec3(), 1e-12, rotate);
+}
+
+#[test]
+fn test_matrices() {
+    let (m2, m3, m4) = (mat2(), mat3(), mat4());
+
+    // Columns stay columns
+    let c = mint::ColumnMatrix2::from(m2);
+    assert_eq!((c.y.x, c.y.y), (3.0, 4.0));
+    assert_eq!(mint::ColumnMatrix3::from(m3).y.x, 4.0);
+    let c = mint::ColumnMatrix4::from(m4);
+    assert_eq!((c.w.x, c.w.w, c.x.w), (13.0, 17.0, 4.0));
+
+    assert_matrices_round_trip::<
+        mint::ColumnMatrix2<_>,
+        mint::ColumnMatrix3<_>,
+        mint::ColumnMatrix4<_>,
+        _,
+    >(m2, m3, m4);
+    assert_matrices_round_trip::<
+        mint::ColumnMatrix2<_>,
+        mint::ColumnMatrix3<_>,
+        mint::ColumnMatrix4<_>,
+        _,
+    >(m2.as_dmat2(), m3.as_dmat3(), m4.as_dmat4());
+}
+
+#[test]
+fn test_rotor_edge_cases() {
+    let q = mint::Quaternion::from(Rot3::identity());
+    assert_eq!((q.s, q.v.x, q.v.y, q.v.z), (1.0, 0.0, 0.0, 0.0));
+
+    // r and -r map to q and -q
+    let r = drotor();
+    let (q, nq) = (mint::Quaternion::from(r), mint::Quaternion::from(-r));
+    assert_eq!(
+        (nq.s, nq.v.x, nq.v.y, nq.v.z),
+        (-q.s, -q.v.x, -q.v.y, -q.v.z)
+    );
+    assert_round_trip::<mint::Quaternion<_>, _>(-r);
+
+    // Nothing is normalised on the way through
+    assert_round_trip::<mint::Quaternion<_>, _>(r * 3.0);
+    assert_round_trip::<mint::Quaternion<_>, _>(Rot3::new(0.0, Bivec3::zero()));
+
+    // A quarter turn about z in the quaternion convention turns x into y
+    let h = std::f64::consts::FRAC_1_SQRT_2;
+    let q = mint::Quaternion {
+        v: mint::Vector3 {
+            x: 0.0,
+            y: 0.0,
+            z: h,
+        },
+        s: h,
+    };
+    assert_approx_eq!(
+        DRot3::from(q) * DVec3::right(),
+        DVec3::up(),
+        epsilon = 1e-12
+    );
+}
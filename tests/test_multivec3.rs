@@ -0,0 +1,187 @@
+use cliffy::*;
+
+fn mv(c: [f32; 8]) -> Multivec3 {
+    Multivec3::new(
+        c[0],
+        Vec3::new(c[1], c[2], c[3]),
+        Bivec3::new(c[4], c[5], c[6]),
+        Trivec3::new(c[7]),
+    )
+}
+
+#[test]
+fn test_conversions() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    let bv = Bivec3::new(4.0, 5.0, 6.0);
+    let r = Rot3::new(0.5, bv);
+
+    assert_eq!(
+        Multivec3::from(2.0),
+        mv([2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        Multivec3::from(v),
+        mv([0.0, 1.0, 2.0, 3.0, 0.0, 0.0, 0.0, 0.0])
+    );
+    assert_eq!(
+        Multivec3::from(bv),
+        mv([0.0, 0.0, 0.0, 0.0, 4.0, 5.0, 6.0, 0.0])
+    );
+    assert_eq!(
+        Multivec3::from(r),
+        mv([0.5, 0.0, 0.0, 0.0, 4.0, 5.0, 6.0, 0.0])
+    );
+    assert_eq!(
+        Multivec3::from(Trivec3::new(7.0)),
+        mv([0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 7.0])
+    );
+    assert_eq!(Multivec3::one(), Multivec3::from(1.0));
+}
+
+#[test]
+fn test_geometric_product() {
+    let v1 = Vec3::new(3.0, 4.0, 5.0);
+    let v2 = Vec3::new(2.0, 1.0, 6.0);
+
+    // Agrees with the existing products
+    assert_eq!(
+        Multivec3::from(v1) * Multivec3::from(v2),
+        Multivec3::from(v1.geom(v2))
+    );
+
+    let r1 = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    let r2 = Rot3::from_axis_angle(Vec3::new(-1.0, 0.5, 2.0), 1.3);
+    assert_approx_eq!(
+        Multivec3::from(r1) * Multivec3::from(r2),
+        Multivec3::from(r1 * r2),
+        epsilon = 1e-5
+    );
+
+    let i = Multivec3::from(Trivec3::one());
+    assert_eq!(i * i, -Multivec3::one());
+    assert_eq!(
+        i * Multivec3::from(v1),
+        Multivec3::from(Trivec3::one() * v1)
+    );
+
+    // Associativity
+    let a = mv([1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 0.25]);
+    let b = mv([-0.5, 1.0, 2.0, -3.0, 0.5, 1.0, -1.0, 2.0]);
+    let c = mv([2.0, -1.0, 0.0, 1.0, -0.5, 0.0, 2.0, -1.0]);
+    assert_approx_eq!((a * b) * c, a * (b * c), epsilon = 1e-5);
+}
+
+#[test]
+fn test_outer_and_inner_products() {
+    let v1 = Vec3::new(3.0, 4.0, 5.0);
+    let v2 = Vec3::new(2.0, 1.0, 6.0);
+    let v3 = Vec3::new(-1.0, 2.0, 0.5);
+    let (a, b, c) = (
+        Multivec3::from(v1),
+        Multivec3::from(v2),
+        Multivec3::from(v3),
+    );
+
+    assert_eq!(a.wedge(b), Multivec3::from(v1.wedge(v2)));
+    assert_eq!(
+        a.wedge(b).wedge(c),
+        Multivec3::from(v1.wedge(v2).wedge_vec(v3))
+    );
+    assert_eq!(a.left_contract(b), Multivec3::from(v1.dot(v2)));
+    assert_eq!(a.right_contract(b), Multivec3::from(v1.dot(v2)));
+    assert_eq!(a.scalar_product(b), v1.dot(v2));
+
+    // For vectors the geometric product splits into inner and outer parts
+    assert_eq!(a * b, a.left_contract(b) + a.wedge(b));
+
+    // A vector contracted onto a plane lies in the plane, perpendicular to the vector
+    let plane = Multivec3::from(Bivec3::new(1.0, 0.0, 0.0));
+    let x = Multivec3::from(Vec3::right());
+    assert_eq!(x.left_contract(plane), Multivec3::from(Vec3::up()));
+    assert_eq!(plane.right_contract(x), Multivec3::from(-Vec3::up()));
+    assert_eq!(plane.left_contract(x), Multivec3::zero());
+}
+
+#[test]
+fn test_regressive_product() {
+    // Two planes meet in a line
+    let xy = Multivec3::from(Bivec3::new(1.0, 0.0, 0.0));
+    let yz = Multivec3::from(Bivec3::new(0.0, 0.0, 1.0));
+    assert_eq!(xy.regressive(yz), Multivec3::from(Vec3::up()));
+
+    // The pseudoscalar is the identity of the regressive product
+    let a = mv([1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 0.25]);
+    let i = Multivec3::from(Trivec3::one());
+    assert_eq!(i.regressive(a), a);
+    assert_eq!(a.dual().dual(), a);
+}
+
+#[test]
+fn test_grades_and_involutions() {
+    let a = mv([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+    assert_eq!(a.grade(0), Multivec3::from(1.0));
+    assert_eq!(a.grade(1), Multivec3::from(Vec3::new(2.0, 3.0, 4.0)));
+    assert_eq!(a.grade(2), Multivec3::from(Bivec3::new(5.0, 6.0, 7.0)));
+    assert_eq!(a.grade(3), Multivec3::from(Trivec3::new(8.0)));
+    assert_eq!(a.grade(4), Multivec3::zero());
+    assert_eq!(a.grade(0) + a.grade(1) + a.grade(2) + a.grade(3), a);
+
+    assert_eq!(
+        a.reversed(),
+        mv([1.0, 2.0, 3.0, 4.0, -5.0, -6.0, -7.0, -8.0])
+    );
+    assert_eq!(
+        a.involuted(),
+        mv([1.0, -2.0, -3.0, -4.0, 5.0, 6.0, 7.0, -8.0])
+    );
+    assert_eq!(
+        a.conjugated(),
+        mv([1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0, 8.0])
+    );
+    assert_eq!(a.conjugated(), a.reversed().involuted());
+
+    let b = mv([-0.5, 1.0, 2.0, -3.0, 0.5, 1.0, -1.0, 2.0]);
+    assert_approx_eq!(
+        (a * b).reversed(),
+        b.reversed() * a.reversed(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        (a * b).involuted(),
+        a.involuted() * b.involuted(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_inverse() {
+    let a = mv([1.0, 2.0, -1.0, 0.5, 3.0, -2.0, 1.5, 0.25]);
+    assert_approx_eq!(a * a.inverse(), Multivec3::one(), epsilon = 1e-5);
+    assert_approx_eq!(a.inverse() * a, Multivec3::one(), epsilon = 1e-5);
+
+    let v = Multivec3::from(Vec3::new(0.0, 2.0, 0.0));
+    assert_eq!(v.inverse(), Multivec3::from(Vec3::new(0.0, 0.5, 0.0)));
+
+    // 1 + x squares to a multiple of itself and has no inverse
+    let null = mv([1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    assert_eq!(null.try_inverse(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_inverse_of_zero() {
+    Multivec3::zero().inverse();
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = mv([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+
+    assert_eq!(a * 2.0, 2.0 * a);
+    assert_eq!(a * 2.0 / 2.0, a);
+    assert_eq!(a - a, Multivec3::zero());
+    assert_eq!(a + -a, Multivec3::zero());
+    assert_eq!(Multivec3::from(Vec3::new(3.0, 4.0, 0.0)).mag(), 5.0);
+    assert_eq!(a.as_dmultivec3().as_multivec3(), a);
+}
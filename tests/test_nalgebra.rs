@@ -0,0 +1,153 @@
+#![cfg(feature = "nalgebra")]
+
+mod common;
+
+use cliffy::*;
+use common::*;
+
+#[test]
+fn test_vectors() {
+    let v = DVec3::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        nalgebra::Vector3::from(v),
+        nalgebra::Vector3::new(1.0, 2.0, 3.0)
+    );
+    assert_eq!(v.dot(DVec3::one()), nalgebra::Vector3::from(v).sum());
+
+    let vs = vectors();
+    assert_vectors_round_trip::<nalgebra::Vector2<_>, nalgebra::Vector3<_>, nalgebra::Vector4<_>, _>(
+        &vs,
+    );
+    assert_vectors_round_trip::<nalgebra::Vector2<_>, nalgebra::Vector3<_>, nalgebra::Vector4<_>, _>(
+        &vs.map(|v| v.as_dvec4()),
+    );
+}
+
+#[test]
+fn test_rotors() {
+    assert_round_trip::<nalgebra::UnitQuaternion<_>, _>(drotor());
+    assert_rotates_like(
+        rotor(),
+        point(),
+        1e-5,
+        |q: nalgebra::UnitQuaternion<_>, v| (q * nalgebra::Vector3::from(v)).into(),
+    );
+    assert_rotates_like(
+        drotor(),
+        point().as_dvec3(),
+        1e-12,
+        |q: nalgebra::UnitQuaternion<_>, v| (q * nalgebra::Vector3::from(v)).into(),
+    );
+
+    let axis = nalgebra::Unit::new_normalize(nalgebra::Vector3::new(0.0f32, 1.0, 1.0));
+    let q = nalgebra::UnitQuaternion::from_axis_angle(&axis, 0.7);
+    assert_approx_eq!(
+        Vec3::from(q * nalgebra::Vector3::from(point())),
+        Rot3::from(q) * point(),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_unnormalized_rotor() {
+    let r = drotor() * 3.0;
+    let q = nalgebra::UnitQuaternion::from(r);
+    assert_approx_eq!(q.quaternion().norm(), 1.0);
+    assert_approx_eq!(DRot3::from(q), drotor(), epsilon = 1e-12);
+    assert_approx_eq!(
+        DRot3::from(q.inverse()),
+        drotor().reversed(),
+        epsilon = 1e-12
+    );
+
+    let v = point().as_dvec3();
+    let rotated = DVec3::from(q * nalgebra::Vector3::from(v));
+    assert_approx_eq!(rotated, drotor() * v, epsilon = 1e-12);
+
+    let drifted = drotor() + DRot3::new(1e-3, DBivec3::zero());
+    let q = nalgebra::UnitQuaternion::from(drifted);
+    assert_approx_eq!(DRot3::from(q), drifted.normalized(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_matrices() {
+    let (m2, m3, m4) = (mat2().as_dmat2(), mat3().as_dmat3(), mat4().as_dmat4());
+
+    // nalgebra indexes (row, column)
+    assert_eq!(nalgebra::Matrix2::from(m2)[(0, 1)], 3.0);
+    assert_eq!(nalgebra::Matrix3::from(m3)[(2, 1)], 6.0);
+    assert_eq!(nalgebra::Matrix4::from(m4)[(3, 0)], 4.0);
+    let m = DMat4::from_rot3_translation(drotor(), DVec3::new(1.0, 2.0, 3.0));
+    assert_eq!(nalgebra::Matrix4::from(m)[(0, 3)], 1.0);
+    assert_round_trip::<nalgebra::Matrix4<_>, _>(m);
+
+    assert_matrices_round_trip::<nalgebra::Matrix2<_>, nalgebra::Matrix3<_>, nalgebra::Matrix4<_>, _>(
+        m2, m3, m4,
+    );
+    assert_matrices_round_trip::<nalgebra::Matrix2<_>, nalgebra::Matrix3<_>, nalgebra::Matrix4<_>, _>(
+        mat2(),
+        mat3(),
+        mat4(),
+    );
+    assert_products_agree::<nalgebra::Matrix2<_>, nalgebra::Matrix3<_>, nalgebra::Matrix4<_>, _>(
+        m2, m3, m4,
+    );
+    let n = nalgebra::Matrix3::from(m3);
+    assert_approx_eq!(
+        DMat3::from(n.try_inverse().unwrap()),
+        m3.inverse(),
+        epsilon = 1e-12
+    );
+    assert_eq!(n.determinant(), m3.determinant());
+
+    // Rotation matrices agree with nalgebra's, and transform points the same
+    let m = drotor().into_matrix();
+    let v = point().as_dvec3();
+    assert_round_trip::<nalgebra::Matrix3<_>, _>(m);
+    assert_approx_eq!(
+        DVec3::from(nalgebra::Matrix3::from(m) * nalgebra::Vector3::from(v)),
+        m * v,
+        epsilon = 1e-12
+    );
+    let rotation = nalgebra::UnitQuaternion::from(drotor()).to_rotation_matrix();
+    assert_approx_eq!(DMat3::from(rotation.into_inner()), m, epsilon = 1e-12);
+}
+
+#[test]
+fn test_rotor_edge_cases() {
+    assert_eq!(
+        nalgebra::UnitQuaternion::from(DRot3::identity()),
+        nalgebra::UnitQuaternion::identity()
+    );
+
+    // r and -r are the same rotation
+    let q = nalgebra::UnitQuaternion::from(-drotor());
+    assert_approx_eq!(
+        q.angle_to(&nalgebra::UnitQuaternion::from(drotor())),
+        0.0,
+        epsilon = 1e-7
+    );
+    assert_eq!(DRot3::from(q), -drotor());
+
+    // A zero rotor has no rotation to normalise to
+    let q = nalgebra::UnitQuaternion::from(DRot3::new(0.0, DBivec3::zero()));
+    assert!(q.w.is_nan());
+
+    // f32 rotors, including scaled ones
+    assert_approx_eq!(
+        Rot3::from(nalgebra::UnitQuaternion::from(rotor() * 0.25)),
+        rotor(),
+        epsilon = 1e-6
+    );
+    for axis in [Vec3::right(), Vec3::up(), Vec3::forward()] {
+        let q = nalgebra::UnitQuaternion::from_axis_angle(
+            &nalgebra::Unit::new_normalize(axis.into()),
+            0.8,
+        );
+        assert_approx_eq!(
+            Rot3::from(q),
+            Rot3::from_axis_angle(axis, 0.8),
+            epsilon = 1e-6
+        );
+    }
+}
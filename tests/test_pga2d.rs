@@ -0,0 +1,178 @@
+use cliffy::pga2d::*;
+use cliffy::*;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+#[test]
+fn test_points() {
+    let p = Point2::new(3.0, 4.0);
+    assert_eq!(p.to_vec2(), Vec2::new(3.0, 4.0));
+    assert_eq!(Point2::new_homogeneous(6.0, 8.0, 2.0).normalized(), p);
+    assert_eq!(Point2::from(Vec2::new(3.0, 4.0)), p);
+    assert!(Point2::direction(1.0, 0.0).is_direction());
+    assert!(!p.is_direction());
+    assert_eq!(p.distance(Point2::new(0.0, 0.0)), 5.0);
+    assert_eq!(p.distance(Point2::new_homogeneous(0.0, 0.0, 3.0)), 5.0);
+}
+
+#[test]
+fn test_join_meet() {
+    let a = Point2::new(1.0, 1.0);
+    let b = Point2::new(3.0, 5.0);
+    let line = a.join(b);
+
+    // Both points lie on the line
+    assert_eq!(line.signed_distance(a), 0.0);
+    assert_eq!(line.signed_distance(b), 0.0);
+    assert_eq!(Line2::through(a, b), line);
+    assert_eq!(b.join(a), -line);
+
+    // The x axis and the line x = 2 meet at (2, 0)
+    let x_axis = Line2::new(0.0, 1.0, 0.0);
+    let vertical = Line2::new(1.0, 0.0, -2.0);
+    assert_eq!(x_axis.meet(vertical).to_vec2(), Vec2::new(2.0, 0.0));
+    assert_approx_eq!(
+        line.meet(x_axis).to_vec2(),
+        Point2::new(0.5, 0.0).to_vec2(),
+        epsilon = 1e-5
+    );
+
+    // Parallel lines meet at infinity, in their shared direction
+    let p = x_axis.meet(Line2::new(0.0, 2.0, -6.0));
+    assert!(p.is_direction());
+    assert_eq!(p.e01, 0.0);
+}
+
+#[test]
+fn test_distances_angles() {
+    let line = Line2::new(3.0, 4.0, -10.0);
+    assert_eq!(line.norm(), 5.0);
+    assert_eq!(line.normalized(), Line2::new(0.6, 0.8, -2.0));
+    assert_eq!(line.normal(), Vec2::new(3.0, 4.0));
+    assert_eq!(line.signed_distance(Point2::new(0.0, 0.0)), -2.0);
+    assert_eq!(line.signed_distance(Point2::new(6.0, 8.0)), 8.0);
+
+    let x_axis = Line2::new(0.0, 1.0, 0.0);
+    let diagonal = Point2::new(0.0, 0.0).join(Point2::new(1.0, 1.0));
+    assert_approx_eq!(x_axis.angle_between(diagonal), PI / 4.0, epsilon = 1e-6);
+    assert_approx_eq!(
+        x_axis.angle_between(-diagonal),
+        3.0 * PI / 4.0,
+        epsilon = 1e-6
+    );
+}
+
+#[test]
+fn test_motor_rotation_translation() {
+    let p = Point2::new(2.0, 1.0);
+
+    let r = Motor2::from_rotation(FRAC_PI_2);
+    assert_approx_eq!(
+        (r * p).to_vec2(),
+        Point2::new(-1.0, 2.0).to_vec2(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        (r * p).to_vec2(),
+        Point2::from(Rot2::from_angle(FRAC_PI_2) * Vec2::new(2.0, 1.0)).to_vec2(),
+        epsilon = 1e-5
+    );
+
+    let t = Motor2::from_translation(Vec2::new(3.0, -1.0));
+    assert_eq!(t * p, Point2::new(5.0, 0.0));
+
+    // Rotating about a point leaves it fixed
+    let about = Motor2::from_rotation_about(FRAC_PI_2, Point2::new(1.0, 1.0));
+    assert_approx_eq!(
+        (about * Point2::new(1.0, 1.0)).to_vec2(),
+        Point2::new(1.0, 1.0).to_vec2(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        (about * p).to_vec2(),
+        Point2::new(1.0, 2.0).to_vec2(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(about),
+        AsRotation(t_r_t(Vec2::new(1.0, 1.0), FRAC_PI_2)),
+        epsilon = 1e-5
+    );
+}
+
+fn t_r_t(center: Vec2, angle: f32) -> Motor2<f32> {
+    Motor2::from_translation(center)
+        * Motor2::from_rotation(angle)
+        * Motor2::from_translation(-center)
+}
+
+#[test]
+fn test_motor_lines() {
+    let m = Motor2::from_rot2_translation(Rot2::from_angle(0.7), Vec2::new(1.5, -2.0));
+    let (a, b) = (Point2::new(1.0, 1.0), Point2::new(3.0, 5.0));
+
+    // Moving a line is the same as moving the points on it
+    let moved = m * a.join(b);
+    assert_approx_eq!(moved.signed_distance(m * a), 0.0, epsilon = 1e-5);
+    assert_approx_eq!(moved.signed_distance(m * b), 0.0, epsilon = 1e-5);
+    assert_approx_eq!(moved.norm(), a.join(b).norm(), epsilon = 1e-5);
+}
+
+#[test]
+fn test_motor_composition() {
+    let m1 = Motor2::from_rot2_translation(Rot2::from_angle(0.7), Vec2::new(1.5, -2.0));
+    let m2 = Motor2::from_rot2_translation(Rot2::from_angle(-1.9), Vec2::new(-0.5, 4.0));
+    let p = Point2::new(2.0, -3.0);
+
+    assert_approx_eq!(
+        ((m1 * m2) * p).to_vec2(),
+        (m1 * (m2 * p)).to_vec2(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        (m1.reversed() * (m1 * p)).to_vec2(),
+        p.to_vec2(),
+        epsilon = 1e-5
+    );
+
+    let (r, t) = m1.to_rot2_translation();
+    assert_approx_eq!(r, Rot2::from_angle(0.7), epsilon = 1e-6);
+    assert_approx_eq!(t, Vec2::new(1.5, -2.0), epsilon = 1e-5);
+    assert_approx_eq!(
+        (m1 * p).to_vec2(),
+        (Point2::from(r * Vec2::new(2.0, -3.0) + t)).to_vec2(),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation((m1 * 2.0).normalized()),
+        AsRotation(m1),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_exp_log() {
+    let m = Motor2::from_rot2_translation(Rot2::from_angle(0.7), Vec2::new(1.5, -2.0));
+    assert_approx_eq!(AsRotation(m.log().exp()), AsRotation(m), epsilon = 1e-5);
+
+    let t = Motor2::from_translation(Vec2::new(3.0, -1.0));
+    assert_approx_eq!(AsRotation(t.log().exp()), AsRotation(t), epsilon = 1e-5);
+    assert_eq!(t.powf(0.5) * Point2::new(0.0, 0.0), Point2::new(1.5, -0.5));
+
+    // Half of a screw motion applied twice is the whole motion
+    let half = m.powf(0.5);
+    assert_approx_eq!(AsRotation(half * half), AsRotation(m), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(Motor2::identity().interpolate(m, 1.0)),
+        AsRotation(m),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(m.interpolate(m, 0.3)),
+        AsRotation(m),
+        epsilon = 1e-5
+    );
+    assert_eq!(
+        Point2::new_homogeneous(0.0, 0.0, 0.0).exp(),
+        Motor2::identity()
+    );
+}
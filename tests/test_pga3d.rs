@@ -0,0 +1,312 @@
+use cliffy::pga3d::*;
+use cliffy::*;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+fn motor() -> Motor3<f32> {
+    Motor3::from_rot3_translation(
+        Rot3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.8),
+        Vec3::new(1.0, -2.0, 3.0),
+    )
+}
+
+#[test]
+fn test_points_planes() {
+    let p = Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(p.to_vec3(), Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Point3::new_homogeneous(2.0, 4.0, 6.0, 2.0).normalized(), p);
+    assert_eq!(Point3::from(Vec3::new(1.0, 2.0, 3.0)), p);
+    assert!(Point3::direction(0.0, 0.0, 1.0).is_direction());
+    assert_eq!(Point3::origin().distance(Point3::new(2.0, 3.0, 6.0)), 7.0);
+
+    let plane = Plane3::from_point_normal(Vec3::new(0.0, 0.0, 2.0), Vec3::new(0.0, 0.0, 2.0));
+    assert_eq!(plane, Plane3::new(0.0, 0.0, 2.0, -4.0));
+    assert_eq!(plane.normalized(), Plane3::new(0.0, 0.0, 1.0, -2.0));
+    assert_eq!(plane.signed_distance(p), 1.0);
+    assert_eq!(plane.signed_distance(Point3::origin()), -2.0);
+    assert_eq!(
+        plane.angle_between(Plane3::new(1.0, 0.0, 0.0, 5.0)),
+        FRAC_PI_2
+    );
+}
+
+#[test]
+fn test_lines() {
+    let a = Point3::new(1.0, 0.0, 0.0);
+    let b = Point3::new(1.0, 0.0, 2.0);
+    let line = a.join(b);
+
+    // Plücker coordinates
+    assert_eq!(line.direction(), Vec3::new(0.0, 0.0, 2.0));
+    assert_eq!(
+        line.moment(),
+        Vec3::new(1.0, 0.0, 0.0).cross(line.direction())
+    );
+    assert_eq!(
+        Line3::from_point_direction(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 2.0)),
+        line
+    );
+    assert_eq!(Line3::through(a, b), line);
+    assert_eq!(b.join(a), -line);
+    assert_eq!(line.closest_to_origin(), Vec3::new(1.0, 0.0, 0.0));
+    assert_eq!(line.normalized().norm(), 1.0);
+    assert_eq!(line.distance_to_point(Point3::new(4.0, 4.0, 7.0)), 5.0);
+}
+
+#[test]
+fn test_join_meet() {
+    let (a, b, c) = (
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    let plane = Plane3::through(a, b, c);
+    for p in &[a, b, c] {
+        assert_eq!(plane.signed_distance(*p), 0.0);
+    }
+    assert_approx_eq!(
+        plane.normalized().normal(),
+        Vec3::one().normalized(),
+        epsilon = 1e-6
+    );
+
+    // The xy and xz planes meet in the x axis
+    let xy = Plane3::new(0.0, 0.0, 1.0, 0.0);
+    let xz = Plane3::new(0.0, 1.0, 0.0, 0.0);
+    let x_axis = xy.meet(xz);
+    assert_eq!(x_axis.moment(), Vec3::zero());
+    assert_eq!(x_axis.direction().cross(Vec3::right()), Vec3::zero());
+
+    // Which crosses x = 3 at (3, 0, 0)
+    let p = x_axis.meet_plane(Plane3::new(1.0, 0.0, 0.0, -3.0));
+    assert_eq!(p.to_vec3(), Vec3::new(3.0, 0.0, 0.0));
+
+    // A line and a point off it span a plane containing both
+    let line = a.join(b);
+    let spanned = line.join_point(c);
+    assert_eq!(spanned.signed_distance(c), 0.0);
+    assert_eq!(spanned.signed_distance(a), 0.0);
+}
+
+#[test]
+fn test_motor_matches_rot3() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.8);
+    let m = Motor3::from_rot3(r);
+    let v = Vec3::new(3.0, -1.0, 2.0);
+
+    assert_approx_eq!((m * Point3::from(v)).to_vec3(), r * v, epsilon = 1e-4);
+    assert_eq!(
+        Motor3::from_translation(Vec3::new(1.0, 2.0, 3.0)) * Point3::new(1.0, 1.0, 1.0),
+        Point3::new(2.0, 3.0, 4.0)
+    );
+
+    // Rotates first, then translates
+    let m = motor();
+    assert_approx_eq!(
+        (m * Point3::from(v)).to_vec3(),
+        r * v + Vec3::new(1.0, -2.0, 3.0),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_motor_planes_lines() {
+    let m = motor();
+    let (a, b, c) = (
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(0.0, 3.0, 0.0),
+        Point3::new(-1.0, 1.0, 1.0),
+    );
+
+    // Moving planes and lines is the same as moving the points that span them
+    let plane = m * Plane3::through(a, b, c);
+    let moved = Plane3::through(m * a, m * b, m * c);
+    assert_approx_eq!(plane, moved, epsilon = 1e-4);
+
+    let line = m * a.join(b);
+    let moved = (m * a).join(m * b);
+    assert_approx_eq!(line, moved, epsilon = 1e-4);
+}
+
+#[test]
+fn test_motor_composition() {
+    let m1 = motor();
+    let m2 = Motor3::from_rot3_translation(
+        Rot3::from_axis_angle(Vec3::new(-2.0, 0.5, 1.0), 2.1),
+        Vec3::new(0.5, 4.0, -1.0),
+    );
+    let p = Point3::new(2.0, -3.0, 1.0);
+
+    assert_approx_eq!(
+        ((m1 * m2) * p).to_vec3(),
+        (m1 * (m2 * p)).to_vec3(),
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(
+        (m1.reversed() * (m1 * p)).to_vec3(),
+        p.to_vec3(),
+        epsilon = 1e-4
+    );
+
+    let (r, t) = m1.to_rot3_translation();
+    let expected = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.8);
+    assert_approx_eq!(r, expected, epsilon = 1e-6);
+    assert_approx_eq!(t, Vec3::new(1.0, -2.0, 3.0), epsilon = 1e-4);
+}
+
+#[test]
+fn test_normalize() {
+    let m = motor();
+    assert_approx_eq!(
+        AsRotation((m * 2.5).normalized()),
+        AsRotation(m),
+        epsilon = 1e-4
+    );
+
+    // A blend of two motors is a motor again once normalized
+    let blend = (m + Motor3::from_translation(Vec3::new(0.0, 1.0, 0.0))).normalized();
+    let p = Point3::new(1.0, 2.0, 3.0);
+    let q = Point3::new(-1.0, 0.5, 2.0);
+    assert_approx_eq!(
+        (blend * p).distance(blend * q),
+        p.distance(q),
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(
+        AsRotation(blend * blend.reversed()),
+        AsRotation(Motor3::identity()),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_matrix() {
+    let m = motor();
+    let mat = m.into_matrix();
+    let v = Vec3::new(3.0, -1.0, 2.0);
+
+    let transformed = mat * Vec4::new(v.x, v.y, v.z, 1.0);
+    assert_approx_eq!(
+        Vec3::from(transformed),
+        (m * Point3::from(v)).to_vec3(),
+        epsilon = 1e-4
+    );
+    assert_eq!(Mat4::from(m), mat);
+    assert_approx_eq!(
+        AsRotation(Motor3::from_matrix(&mat)),
+        AsRotation(m),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_exp_log() {
+    let m = motor();
+    assert_approx_eq!(AsRotation(m.log().exp()), AsRotation(m), epsilon = 1e-4);
+
+    let b = Line3::from_plucker(Vec3::new(0.3, -0.2, 0.5), Vec3::new(1.0, 2.0, -0.5));
+    let log = b.exp().log();
+    assert_approx_eq!(log, b, epsilon = 1e-5);
+
+    let t = Motor3::from_translation(Vec3::new(2.0, 0.0, -4.0));
+    assert_approx_eq!(AsRotation(t.log().exp()), AsRotation(t), epsilon = 1e-4);
+    assert_eq!(
+        (t.powf(0.5) * Point3::origin()).to_vec3(),
+        Vec3::new(1.0, 0.0, -2.0)
+    );
+
+    let half = m.powf(0.5);
+    assert_approx_eq!(AsRotation(half * half), AsRotation(m), epsilon = 1e-4);
+    assert_approx_eq!(
+        AsRotation(Motor3::identity().interpolate(m, 1.0)),
+        AsRotation(m),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_log_near_negative_one() {
+    // A translation with a rotor of s close to -1 on either side of the small
+    // angle threshold
+    let t = Vec3::new(2.0, 0.0, -4.0);
+    let plane = Bivec3::new(0.3, -0.4, 0.5).normalized();
+    for &mag in &[0.0, 1e-8, 1e-6] {
+        let r = Rot3::new((1.0f32 - mag * mag).sqrt(), plane * mag);
+        let m = -(Motor3::from_translation(t) * Motor3::from_rot3(r));
+        assert!(m.s < 0.0);
+        assert_approx_eq!(AsRotation(m.log().exp()), AsRotation(m), epsilon = 1e-4);
+        assert_approx_eq!(
+            (m.powf(0.5) * Point3::origin()).to_vec3(),
+            t * 0.5,
+            epsilon = 1e-4
+        );
+        assert_approx_eq!(
+            (Motor3::identity().interpolate(m, 0.25) * Point3::origin()).to_vec3(),
+            t * 0.25,
+            epsilon = 1e-4
+        );
+
+        let (axis, angle, distance) = m.to_screw();
+        assert_approx_eq!(angle, 0.0, epsilon = 1e-5);
+        assert_approx_eq!(
+            AsRotation(Motor3::from_screw(axis, angle, distance)),
+            AsRotation(m),
+            epsilon = 1e-4
+        );
+    }
+}
+
+#[test]
+fn test_screw() {
+    // A quarter turn about the vertical line through (1, 0, 0), sliding 2 up it
+    let axis = Line3::from_point_direction(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 0.0, 3.0));
+    let m = Motor3::from_screw(axis, FRAC_PI_2, 2.0);
+
+    assert_approx_eq!(
+        (m * Point3::new(1.0, 0.0, 0.0)).to_vec3(),
+        Vec3::new(1.0, 0.0, 2.0),
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(
+        (m * Point3::new(2.0, 0.0, 0.0)).to_vec3(),
+        Vec3::new(1.0, 1.0, 2.0),
+        epsilon = 1e-4
+    );
+
+    let (found_axis, angle, distance) = m.to_screw();
+    assert_approx_eq!(angle, FRAC_PI_2, epsilon = 1e-5);
+    assert_approx_eq!(distance, 2.0, epsilon = 1e-5);
+    assert_approx_eq!(found_axis.direction(), Vec3::forward(), epsilon = 1e-4);
+    assert_approx_eq!(
+        found_axis.closest_to_origin(),
+        Vec3::new(1.0, 0.0, 0.0),
+        epsilon = 1e-4
+    );
+
+    // Any motor is a screw motion
+    let m = motor();
+    let (axis, angle, distance) = m.to_screw();
+    assert_approx_eq!(
+        AsRotation(Motor3::from_screw(axis, angle, distance)),
+        AsRotation(m),
+        epsilon = 1e-4
+    );
+
+    let t = Motor3::from_translation(Vec3::new(0.0, 3.0, 4.0));
+    let (axis, angle, distance) = t.to_screw();
+    assert_eq!(angle, 0.0);
+    assert_approx_eq!(distance, 5.0, epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(Motor3::from_screw(axis, angle, distance)),
+        AsRotation(t),
+        epsilon = 1e-4
+    );
+
+    let r = Motor3::from_rot3(Rot3::from_axis_angle(Vec3::up(), PI / 3.0));
+    let (axis, angle, distance) = r.to_screw();
+    assert_approx_eq!(distance, 0.0, epsilon = 1e-6);
+    assert_approx_eq!(
+        AsRotation(Motor3::from_screw(axis, angle, distance)),
+        AsRotation(r),
+        epsilon = 1e-4
+    );
+}
@@ -0,0 +1,194 @@
+#![cfg(feature = "rayon")]
+
+use cliffy::*;
+
+// Spans several chunks with a partial one at the end
+fn points() -> Vec<Vec3> {
+    (0..10_000)
+        .map(|i| {
+            let f = i as f32;
+            Vec3::new(f.sin(), f.cos() * 2.0, f * 0.001 - 3.0)
+        })
+        .collect()
+}
+
+#[test]
+fn test_par_rotate_slice() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    let mut expected = points();
+    r.rotate_slice(&mut expected);
+    let mut vecs = points();
+    r.par_rotate_slice(&mut vecs);
+    assert_eq!(vecs, expected);
+
+    let r = Rot2::from_angle(1.1);
+    let mut expected: Vec<Vec2> = points().into_iter().map(Vec2::from).collect();
+    let mut vecs = expected.clone();
+    r.rotate_slice(&mut expected);
+    r.par_rotate_slice(&mut vecs);
+    assert_eq!(vecs, expected);
+}
+
+#[test]
+fn test_par_transform() {
+    let t = Transform3::new(
+        Vec3::new(1.0, -2.0, 3.0),
+        Rot3::from_axis_angle(Vec3::up(), 0.3),
+        2.0,
+    );
+    let mut expected = points();
+    t.transform_points(&mut expected);
+    let mut vecs = points();
+    t.par_transform_points(&mut vecs);
+    assert_eq!(vecs, expected);
+
+    let m = t.into_matrix();
+    let mut expected = points();
+    m.transform_vectors(&mut expected);
+    let mut vecs = points();
+    m.par_transform_vectors(&mut vecs);
+    assert_eq!(vecs, expected);
+}
+
+#[test]
+fn test_par_normalize_all() {
+    let mut expected = points();
+    Vec3::normalize_all(&mut expected);
+    let mut vecs = points();
+    Vec3::par_normalize_all(&mut vecs);
+    assert_eq!(vecs, expected);
+}
+
+// Compares bit for bit so NaN lanes have to match too
+fn assert_same_bits(a: &[Vec3], b: &[Vec3]) {
+    assert_eq!(a.len(), b.len());
+    for (a, b) in a.iter().zip(b.iter()) {
+        let bits = |v: &Vec3| [v.x.to_bits(), v.y.to_bits(), v.z.to_bits()];
+        assert_eq!(bits(a), bits(b), "{:?} {:?}", a, b);
+    }
+}
+
+#[test]
+fn test_empty_and_small_slices() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    let t = Transform2::new(Vec2::new(1.0, 2.0), Rot2::from_angle(0.4), 3.0);
+    let m = Mat4::from_rot3_translation(r, Vec3::one());
+
+    let mut empty: Vec<Vec3> = Vec::new();
+    r.par_rotate_slice(&mut empty);
+    m.par_transform_points(&mut empty);
+    m.par_transform_vectors(&mut empty);
+    Vec3::par_normalize_all(&mut empty);
+    assert!(empty.is_empty());
+    let mut empty: Vec<Vec2> = Vec::new();
+    t.par_transform_points(&mut empty);
+    t.par_transform_vectors(&mut empty);
+    Vec2::par_normalize_all(&mut empty);
+    assert!(empty.is_empty());
+
+    // A single element and both sides of a chunk boundary
+    for &len in &[1, 4095, 4096, 4097, 8192] {
+        let mut expected = points();
+        expected.truncate(len);
+        let mut vecs = expected.clone();
+        r.rotate_slice(&mut expected);
+        r.par_rotate_slice(&mut vecs);
+        assert_eq!(vecs, expected, "{}", len);
+    }
+}
+
+#[test]
+fn test_degenerate_values() {
+    let mut vecs = points();
+    vecs[0] = Vec3::zero();
+    vecs[4096] = Vec3::new(f32::NAN, 1.0, 0.0);
+    vecs[9999] = Vec3::new(f32::INFINITY, 0.0, 0.0);
+    let mut expected = vecs.clone();
+    Vec3::normalize_all(&mut expected);
+    let mut normalized = vecs.clone();
+    Vec3::par_normalize_all(&mut normalized);
+    assert_same_bits(&normalized, &expected);
+    assert!(normalized[0].x.is_nan());
+    assert!(normalized[4096].y.is_nan());
+
+    // NaN stays in its own element
+    let r = Rot3::from_axis_angle(Vec3::up(), 0.5);
+    let mut rotated = vecs.clone();
+    r.par_rotate_slice(&mut rotated);
+    assert!(rotated[4096].x.is_nan());
+    assert!(!rotated[4095].x.is_nan() && !rotated[4097].x.is_nan());
+
+    // Points on the camera plane divide by a zero w
+    let m = Mat4::perspective(
+        1.0,
+        1.0,
+        0.1,
+        100.0,
+        Handedness::Left,
+        DepthRange::ZeroToOne,
+    );
+    let mut expected = vecs.clone();
+    expected[1] = Vec3::new(1.0, 1.0, 0.0);
+    let mut projected = expected.clone();
+    m.transform_points(&mut expected);
+    m.par_transform_points(&mut projected);
+    assert_same_bits(&projected, &expected);
+    assert!(!projected[1].x.is_finite());
+
+    // A non-unit rotor scales as it rotates, in parallel as well
+    let scaled = r * 2.0;
+    let mut expected = points();
+    scaled.rotate_slice(&mut expected);
+    let mut vecs = points();
+    scaled.par_rotate_slice(&mut vecs);
+    assert_eq!(vecs, expected);
+    assert_approx_eq!(vecs[7].mag(), points()[7].mag() * 4.0, epsilon = 1e-4);
+}
+
+#[test]
+fn test_f64() {
+    let points: Vec<DVec3> = points().iter().map(Vec3::as_dvec3).collect();
+    let r = DRot3::from_axis_angle(DVec3::new(1.0, 2.0, 3.0), 0.7);
+    let t = DTransform3::new(DVec3::new(1.0, -2.0, 3.0), r, 0.5);
+    let m = t.into_matrix();
+
+    let mut expected = points.clone();
+    r.rotate_slice(&mut expected);
+    let mut vecs = points.clone();
+    r.par_rotate_slice(&mut vecs);
+    assert_eq!(vecs, expected);
+
+    let mut expected = points.clone();
+    t.transform_vectors(&mut expected);
+    let mut vecs = points.clone();
+    t.par_transform_vectors(&mut vecs);
+    assert_eq!(vecs, expected);
+
+    let mut expected = points.clone();
+    m.transform_points(&mut expected);
+    let mut vecs = points.clone();
+    m.par_transform_points(&mut vecs);
+    assert_eq!(vecs, expected);
+    let mut by_transform = points.clone();
+    t.par_transform_points(&mut by_transform);
+    for (a, b) in vecs.iter().zip(by_transform.iter()) {
+        assert_approx_eq!(*a, *b, epsilon = 1e-12);
+    }
+
+    let t = DTransform2::new(DVec2::new(1.0, 2.0), DRot2::from_angle(0.4), 3.0);
+    let points: Vec<DVec2> = points.iter().map(|p| DVec2::new(p.x, p.y)).collect();
+    let mut expected = points.clone();
+    t.transform_points(&mut expected);
+    let mut vecs = points.clone();
+    t.par_transform_points(&mut vecs);
+    assert_eq!(vecs, expected);
+
+    let mut expected: Vec<DVec4> = points
+        .iter()
+        .map(|p| DVec4::new(p.x, p.y, 1.0, -2.0))
+        .collect();
+    let mut vecs = expected.clone();
+    DVec4::normalize_all(&mut expected);
+    DVec4::par_normalize_all(&mut vecs);
+    assert_eq!(vecs, expected);
+}
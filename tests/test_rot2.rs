@@ -0,0 +1,146 @@
+use cliffy::*;
+
+#[test]
+fn test_from_matrix_round_trip() {
+    for angle in [0.0, 0.1, 1.0, 2.5, std::f32::consts::PI, 4.0, -3.0].iter() {
+        let r = Rot2::from_angle(*angle);
+
+        assert_approx_eq!(
+            AsRotation(Rot2::from_matrix(&r.into_matrix())),
+            AsRotation(r),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_from_matrix_identity() {
+    assert_eq!(Rot2::from_matrix(&Mat2::identity()), Rot2::identity());
+}
+
+#[test]
+fn test_exp_log_round_trip() {
+    for angle in [0.0, 0.1, 1.0, 2.5, -3.0].iter() {
+        let r = Rot2::from_angle(*angle);
+
+        assert_approx_eq!(AsRotation(r.log().exp()), AsRotation(r), epsilon = 1e-5);
+        assert_approx_eq!(r.log().xy, -angle / 2.0, epsilon = 1e-6);
+    }
+}
+
+#[test]
+fn test_powf() {
+    let r = Rot2::from_angle(1.0);
+
+    assert_approx_eq!(
+        AsRotation(r.powf(0.5)),
+        AsRotation(Rot2::from_angle(0.5)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r.powf(3.0)),
+        AsRotation(Rot2::from_angle(3.0)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r.powf(0.0)),
+        AsRotation(Rot2::identity()),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_slerp() {
+    let r1 = Rot2::from_angle(0.2);
+    let r2 = Rot2::from_angle(1.8);
+
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 0.0)),
+        AsRotation(r1),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 1.0)),
+        AsRotation(r2),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 0.25)),
+        AsRotation(Rot2::from_angle(0.6)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(-r2, 0.5)),
+        AsRotation(Rot2::from_angle(1.0)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r1, 0.5)),
+        AsRotation(r1),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_nlerp() {
+    let r1 = Rot2::from_angle(0.2);
+    let r2 = Rot2::from_angle(1.8);
+
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(r2, 0.5)),
+        AsRotation(Rot2::from_angle(1.0)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(-r2, 0.5)),
+        AsRotation(Rot2::from_angle(1.0)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_interpolate() {
+    let r1 = Rot2::from_angle(-2.0);
+    let r2 = Rot2::from_angle(2.5);
+
+    for t in [0.0, 0.3, 0.5, 1.0].iter() {
+        assert_approx_eq!(
+            AsRotation(r1.interpolate(r2, *t)),
+            AsRotation(r1.slerp(r2, *t)),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_from_rotation_between() {
+    let a = Vec2::new(1.0, 2.0);
+    let b = Vec2::new(-3.0, 0.5);
+    let r = Rot2::from_rotation_between(a, b);
+
+    assert_approx_eq!(r * a.normalized(), b.normalized(), epsilon = 1e-6);
+    assert_approx_eq!(
+        AsRotation(Rot2::from_rotation_between(Vec2::right(), Vec2::up())),
+        AsRotation(Rot2::from_angle(std::f32::consts::FRAC_PI_2)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_from_rotation_between_antiparallel() {
+    let a = Vec2::new(1.0, 2.0);
+    let r = Rot2::from_rotation_between(a, -a);
+
+    assert_approx_eq!(r * a, -a, epsilon = 1e-6);
+}
+
+#[test]
+fn test_rotate_slice() {
+    let r = Rot2::from_angle(0.7);
+    let vecs = [Vec2::new(1.0, 2.0), Vec2::up(), Vec2::new(-4.0, 0.5)];
+    let mut rotated = vecs;
+    r.rotate_slice(&mut rotated);
+    for (v, rotated) in vecs.iter().zip(rotated.iter()) {
+        assert_approx_eq!(*rotated, r * *v, epsilon = 1e-6);
+    }
+}
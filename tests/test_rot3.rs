@@ -0,0 +1,634 @@
+use cliffy::*;
+
+fn rotors() -> Vec<Rot3> {
+    let planes = [
+        Bivec3::new(1.0, 0.0, 0.0),
+        Bivec3::new(0.0, 1.0, 0.0),
+        Bivec3::new(0.0, 0.0, 1.0),
+        Bivec3::new(0.3, -0.4, 0.5).normalized(),
+        Bivec3::new(-2.0, 1.0, 0.5).normalized(),
+    ];
+    let angles = [0.0, 0.1, 1.0, 2.5, std::f32::consts::PI, 4.0, -3.0];
+
+    let mut rotors = Vec::new();
+    for plane in planes.iter() {
+        for angle in angles.iter() {
+            rotors.push(Rot3::from_angle_plane(*angle, *plane));
+        }
+    }
+    rotors
+}
+
+#[test]
+fn test_from_matrix_round_trip() {
+    for r in rotors() {
+        assert_approx_eq!(
+            AsRotation(Rot3::from_matrix(&r.into_matrix())),
+            AsRotation(r),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_from_matrix_identity() {
+    assert_eq!(Rot3::from_matrix(&Mat3::identity()), Rot3::identity());
+}
+
+#[test]
+fn test_from_matrix_rotates_like_matrix() {
+    let m = Mat3::new(
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+        Vec3::new(1.0, 0.0, 0.0),
+    );
+    let r = Rot3::from_matrix(&m);
+    let v = Vec3::new(1.0, 2.0, 3.0);
+
+    assert_approx_eq!(r * v, m * v, epsilon = 1e-5);
+}
+
+#[test]
+fn test_from_matrix_orthonormalized() {
+    for r in rotors() {
+        let mut m = r.into_matrix() * 3.0;
+        m.cols[1] += m.cols[0] * 0.01;
+        m.cols[2] *= 0.5;
+
+        assert_approx_eq!(
+            AsRotation(Rot3::from_matrix_orthonormalized(&m)),
+            AsRotation(r),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_orthonormalize() {
+    let mut m = Mat3::new(
+        Vec3::new(2.0, 0.0, 0.0),
+        Vec3::new(1.0, 3.0, 0.0),
+        Vec3::new(0.0, 1.0, 4.0),
+    );
+    m.orthonormalize();
+
+    assert_eq!(m, Mat3::identity());
+}
+
+#[test]
+fn test_exp_log_round_trip() {
+    for r in rotors() {
+        assert_approx_eq!(AsRotation(r.log().exp()), AsRotation(r), epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_log_of_angle_plane() {
+    let plane = Bivec3::new(0.3, -0.4, 0.5).normalized();
+    let log = Rot3::from_angle_plane(1.2, plane).log();
+
+    assert_approx_eq!(log, plane * -0.6, epsilon = 1e-6);
+    assert_eq!(Rot3::identity().log(), Bivec3::zero());
+}
+
+#[test]
+fn test_log_small_angle() {
+    let bv = Bivec3::new(1e-9, -2e-9, 0.0);
+    let r = bv.exp();
+
+    assert_eq!(r.s, 1.0);
+    assert_eq!(r.log(), bv);
+}
+
+#[test]
+fn test_log_near_negative_one() {
+    // Either side of the small angle threshold r is the identity rotation
+    let plane = Bivec3::new(0.3, -0.4, 0.5).normalized();
+    for &mag in &[0.0, 1e-8, 1e-6] {
+        let r = Rot3::new(-(1.0f32 - mag * mag).sqrt(), plane * mag);
+        assert_approx_eq!(r.log(), Bivec3::zero(), epsilon = 1e-6);
+        assert_approx_eq!(AsRotation(r.log().exp()), AsRotation(r), epsilon = 1e-5);
+        assert_approx_eq!(
+            AsRotation(r.powf(0.5)),
+            AsRotation(Rot3::identity()),
+            epsilon = 1e-5
+        );
+    }
+
+    // Just past a full turn of the rotor the log takes the short way round
+    let r = Rot3::from_angle_plane(2.0 * std::f32::consts::PI + 0.2, plane);
+    assert!(r.s < 0.0);
+    assert_approx_eq!(r.log().mag(), 0.1, epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(r.powf(0.5)),
+        AsRotation(Rot3::from_angle_plane(0.1, plane)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_powf() {
+    for r in rotors() {
+        let half = r.powf(0.5);
+
+        assert_approx_eq!(AsRotation(half * half), AsRotation(r), epsilon = 1e-5);
+        assert_approx_eq!(AsRotation(r.powf(1.0)), AsRotation(r), epsilon = 1e-5);
+        assert_approx_eq!(
+            AsRotation(r.powf(0.0)),
+            AsRotation(Rot3::identity()),
+            epsilon = 1e-5
+        );
+        assert_approx_eq!(
+            AsRotation(r.powf(-1.0)),
+            AsRotation(r.reversed()),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_neg() {
+    let r = Rot3::new(0.5, Bivec3::new(0.5, -0.5, 0.5));
+
+    assert_eq!(-r, Rot3::new(-0.5, Bivec3::new(-0.5, 0.5, -0.5)));
+}
+
+#[test]
+fn test_slerp() {
+    let plane = Bivec3::new(0.3, -0.4, 0.5).normalized();
+    let r1 = Rot3::from_angle_plane(0.2, plane);
+    let r2 = Rot3::from_angle_plane(1.8, plane);
+
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 0.0)),
+        AsRotation(r1),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 1.0)),
+        AsRotation(r2),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 0.5)),
+        AsRotation(Rot3::from_angle_plane(1.0, plane)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r2, 0.25)),
+        AsRotation(Rot3::from_angle_plane(0.6, plane)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_slerp_shortest_path() {
+    let plane = Bivec3::new(0.0, 0.0, 1.0);
+    let r1 = Rot3::from_angle_plane(0.2, plane);
+    let r2 = Rot3::from_angle_plane(1.8, plane);
+
+    // -r2 is the same rotation, the result should not take the long way round
+    assert_approx_eq!(
+        AsRotation(r1.slerp(-r2, 0.5)),
+        AsRotation(Rot3::from_angle_plane(1.0, plane)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(-r2, 0.5)),
+        AsRotation(Rot3::from_angle_plane(1.0, plane)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.interpolate(-r2, 0.5)),
+        AsRotation(Rot3::from_angle_plane(1.0, plane)),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_slerp_nearly_identical() {
+    let plane = Bivec3::new(0.0, 1.0, 0.0);
+    let r1 = Rot3::from_angle_plane(0.5, plane);
+    let r2 = Rot3::from_angle_plane(0.5 + 1e-5, plane);
+    let r = r1.slerp(r2, 0.5);
+
+    assert!(r.s.is_finite());
+    assert_approx_eq!(AsRotation(r), AsRotation(r1), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(r1.slerp(r1, 0.3)),
+        AsRotation(r1),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_nlerp() {
+    let plane = Bivec3::new(1.0, 0.0, 0.0);
+    let r1 = Rot3::from_angle_plane(0.2, plane);
+    let r2 = Rot3::from_angle_plane(1.8, plane);
+
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(r2, 0.0)),
+        AsRotation(r1),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(r2, 1.0)),
+        AsRotation(r2),
+        epsilon = 1e-5
+    );
+    // nlerp is symmetric so the midpoint is exact
+    assert_approx_eq!(
+        AsRotation(r1.nlerp(r2, 0.5)),
+        AsRotation(Rot3::from_angle_plane(1.0, plane)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(r1.nlerp(r2, 0.3).mag(), 1.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_interpolate() {
+    let r1 = Rot3::from_angle_plane(0.4, Bivec3::new(1.0, 0.0, 0.0));
+    let r2 = Rot3::from_angle_plane(2.0, Bivec3::new(0.0, 0.6, 0.8));
+
+    for t in [0.0, 0.1, 0.5, 0.9, 1.0].iter() {
+        assert_approx_eq!(
+            AsRotation(r1.interpolate(r2, *t)),
+            AsRotation(r1.slerp(r2, *t)),
+            epsilon = 1e-5
+        );
+    }
+}
+
+#[test]
+fn test_from_rotation_between() {
+    let pairs = [
+        (Vec3::right(), Vec3::up()),
+        (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-2.0, 0.5, 1.0)),
+        (Vec3::new(0.0, 0.0, 5.0), Vec3::new(1.0, 1.0, 0.0)),
+        (Vec3::new(3.0, -1.0, 2.0), Vec3::new(3.0, -1.0, 2.0)),
+    ];
+
+    for (a, b) in pairs.iter() {
+        let r = Rot3::from_rotation_between(*a, *b);
+
+        assert_approx_eq!(r.mag(), 1.0, epsilon = 1e-6);
+        assert_approx_eq!(r * a.normalized(), b.normalized(), epsilon = 1e-5);
+    }
+
+    assert_approx_eq!(
+        AsRotation(Rot3::from_rotation_between(Vec3::right(), Vec3::up())),
+        AsRotation(Rot3::from_angle_plane(
+            std::f32::consts::FRAC_PI_2,
+            Bivec3::new(1.0, 0.0, 0.0)
+        )),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_from_rotation_between_minimal() {
+    // The rotation keeps vectors perpendicular to both inputs fixed
+    let a = Vec3::new(1.0, 2.0, 0.0);
+    let b = Vec3::new(-2.0, 1.0, 0.0);
+    let r = Rot3::from_rotation_between(a, b);
+
+    assert_approx_eq!(r * Vec3::forward(), Vec3::forward(), epsilon = 1e-5);
+}
+
+#[test]
+fn test_from_rotation_between_antiparallel() {
+    let vecs = [
+        Vec3::right(),
+        Vec3::up(),
+        Vec3::forward(),
+        Vec3::new(1.0, -2.0, 0.5),
+    ];
+
+    for a in vecs.iter() {
+        let r = Rot3::from_rotation_between(*a, -*a);
+
+        assert_approx_eq!(r.mag(), 1.0, epsilon = 1e-6);
+        assert_approx_eq!(r * a.normalized(), -a.normalized(), epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_from_axis_angle() {
+    let r = Rot3::from_axis_angle(Vec3::forward(), std::f32::consts::FRAC_PI_2);
+    assert_approx_eq!(r * Vec3::right(), Vec3::up(), epsilon = 1e-5);
+
+    let r = Rot3::from_axis_angle(Vec3::right(), std::f32::consts::FRAC_PI_2);
+    assert_approx_eq!(r * Vec3::up(), Vec3::forward(), epsilon = 1e-5);
+
+    let r = Rot3::from_axis_angle(Vec3::up() * 3.0, std::f32::consts::FRAC_PI_2);
+    assert_approx_eq!(r * Vec3::forward(), Vec3::right(), epsilon = 1e-5);
+
+    // The axis is left untouched
+    let axis = Vec3::new(1.0, -2.0, 0.5);
+    assert_approx_eq!(
+        Rot3::from_axis_angle(axis, 1.3) * axis,
+        axis,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_to_axis_angle() {
+    let axis = Vec3::new(1.0, -2.0, 0.5).normalized();
+    let (a, angle) = Rot3::from_axis_angle(axis, 1.3).to_axis_angle();
+
+    assert_approx_eq!(a, axis, epsilon = 1e-5);
+    assert_approx_eq!(angle, 1.3, epsilon = 1e-6);
+
+    let (a, angle) = Rot3::identity().to_axis_angle();
+    assert_eq!(a.mag(), 1.0);
+    assert_eq!(angle, 0.0);
+}
+
+#[test]
+fn test_to_angle_plane() {
+    for r in rotors() {
+        let (angle, plane) = r.to_angle_plane();
+
+        assert_approx_eq!(plane.mag(), 1.0, epsilon = 1e-6);
+        assert_approx_eq!(
+            AsRotation(Rot3::from_angle_plane(angle, plane)),
+            AsRotation(r),
+            epsilon = 1e-5
+        );
+    }
+}
+
+fn euler_angles(order: EulerOrder) -> Vec<(f32, f32, f32)> {
+    let proper = order.axes()[0] == order.axes()[2];
+    let middle = if proper {
+        [0.3, 1.0, 2.9]
+    } else {
+        [-1.2, 0.3, 1.4]
+    };
+
+    let mut angles = Vec::new();
+    for a in [-2.5, 0.4, 3.0].iter() {
+        for b in middle.iter() {
+            for c in [-0.7, 1.9].iter() {
+                angles.push((*a, *b, *c));
+            }
+        }
+    }
+    angles
+}
+
+#[test]
+fn test_euler_round_trip() {
+    for order in EulerOrder::ALL.iter() {
+        for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic].iter() {
+            for (a, b, c) in euler_angles(*order) {
+                let r = Rot3::from_euler(*order, *frame, a, b, c);
+                let angles = r.to_euler(*order, *frame);
+
+                assert_approx_eq!([angles.0, angles.1, angles.2], [a, b, c], epsilon = 1e-4);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_euler_gimbal_lock() {
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    for order in EulerOrder::ALL.iter() {
+        let proper = order.axes()[0] == order.axes()[2];
+        for b in if proper {
+            [0.0, PI]
+        } else {
+            [FRAC_PI_2, -FRAC_PI_2]
+        }
+        .iter()
+        {
+            for frame in [EulerFrame::Intrinsic, EulerFrame::Extrinsic].iter() {
+                let r = Rot3::from_euler(*order, *frame, 0.4, *b, 1.1);
+                let (a, b, c) = r.to_euler(*order, *frame);
+
+                assert!(a.is_finite() && b.is_finite() && c.is_finite());
+                assert_approx_eq!(
+                    AsRotation(Rot3::from_euler(*order, *frame, a, b, c)),
+                    AsRotation(r),
+                    epsilon = 1e-5
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_euler_frames() {
+    let x = Rot3::from_axis_angle(Vec3::right(), 0.3);
+    let y = Rot3::from_axis_angle(Vec3::up(), -1.1);
+    let z = Rot3::from_axis_angle(Vec3::forward(), 2.0);
+
+    assert_approx_eq!(
+        AsRotation(Rot3::from_euler(
+            EulerOrder::XYZ,
+            EulerFrame::Intrinsic,
+            0.3,
+            -1.1,
+            2.0
+        )),
+        AsRotation(x * y * z),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(Rot3::from_euler(
+            EulerOrder::XYZ,
+            EulerFrame::Extrinsic,
+            0.3,
+            -1.1,
+            2.0
+        )),
+        AsRotation(z * y * x),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(Rot3::from_euler(
+            EulerOrder::XYZ,
+            EulerFrame::Extrinsic,
+            0.3,
+            -1.1,
+            2.0
+        )),
+        AsRotation(Rot3::from_euler(
+            EulerOrder::ZYX,
+            EulerFrame::Intrinsic,
+            2.0,
+            -1.1,
+            0.3
+        )),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_rotate_bivector() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 1.2);
+    let a = Vec3::new(3.0, 4.0, 5.0);
+    let b = Vec3::new(2.0, 1.0, 6.0);
+
+    // Rotating a plane is the same as rotating the vectors spanning it
+    assert_approx_eq!((r * a.wedge(b)), (r * a).wedge(r * b), epsilon = 1e-4);
+    assert_approx_eq!((r * a.dual()), (r * a).dual(), epsilon = 1e-5);
+}
+
+#[test]
+fn test_quaternion() {
+    let angle = 0.6f32;
+    let (sin, cos) = (angle / 2.0).sin_cos();
+    let r = Rot3::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), angle);
+    assert_eq!(r.to_quaternion(), Vec4::new(0.0, 0.0, sin, cos));
+
+    let axis = Vec3::new(1.0, -2.0, 0.5).normalized();
+    let q = Rot3::from_axis_angle(axis, angle).to_quaternion();
+    assert_approx_eq!(Vec3::new(q.x, q.y, q.z), axis * sin, epsilon = 1e-6);
+    assert_eq!(Rot3::from_quaternion(q).to_quaternion(), q);
+}
+
+#[test]
+fn test_rotate_slice() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7);
+    let vecs = [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::up(),
+        Vec3::new(-4.0, 0.5, 2.0),
+    ];
+    let mut rotated = vecs;
+    r.rotate_slice(&mut rotated);
+    for (v, rotated) in vecs.iter().zip(rotated.iter()) {
+        assert_approx_eq!(*rotated, r * *v, epsilon = 1e-6);
+    }
+}
+
+// A rotor defined outside the crate, implementing only the required methods
+#[derive(Clone, Copy, Debug)]
+struct Wrapped(Rot3);
+
+impl From<Rot3> for Wrapped {
+    fn from(r: Rot3) -> Self {
+        Wrapped(r)
+    }
+}
+
+impl std::ops::Neg for Wrapped {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Wrapped(-self.0)
+    }
+}
+
+impl std::ops::Add for Wrapped {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Wrapped(self.0 + rhs.0)
+    }
+}
+
+impl std::ops::Mul for Wrapped {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Wrapped(self.0 * rhs.0)
+    }
+}
+
+impl std::ops::Mul<f32> for Wrapped {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self {
+        Wrapped(self.0 * rhs)
+    }
+}
+
+impl Rotor for Wrapped {
+    type Decimal = f32;
+    type Bivec = Bivec3;
+    type Vector = Vec3;
+    type Matrix = Mat3;
+
+    fn mag(&self) -> f32 {
+        self.0.mag()
+    }
+
+    fn mag_sq(&self) -> f32 {
+        self.0.mag_sq()
+    }
+
+    fn dot(&self, other: Self) -> f32 {
+        self.0.dot(other.0)
+    }
+
+    fn normalize(&mut self) {
+        self.0.normalize()
+    }
+
+    fn normalized(&self) -> Self {
+        Wrapped(self.0.normalized())
+    }
+
+    fn reverse(&mut self) {
+        self.0.reverse()
+    }
+
+    fn reversed(&self) -> Self {
+        Wrapped(self.0.reversed())
+    }
+
+    fn rotate_by(&mut self, other: Self) {
+        self.0.rotate_by(other.0)
+    }
+
+    fn rotated_by(&self, other: Self) -> Self {
+        Wrapped(self.0.rotated_by(other.0))
+    }
+
+    fn rotate_vec(&self, vec: &mut Vec3) {
+        self.0.rotate_vec(vec)
+    }
+
+    fn into_matrix(&self) -> Mat3 {
+        self.0.into_matrix()
+    }
+
+    fn log(&self) -> Bivec3 {
+        self.0.log()
+    }
+}
+
+#[test]
+fn test_provided_methods() {
+    let plane = Bivec3::new(0.3, -0.4, 0.5).normalized();
+    let r1 = Rot3::from_angle_plane(0.2, plane);
+    let r2 = Rot3::from_angle_plane(1.8, plane);
+    let (w1, w2) = (Wrapped(r1), Wrapped(r2));
+
+    assert_approx_eq!(
+        AsRotation(w1.powf(0.5).0),
+        AsRotation(r1.powf(0.5)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(w1.nlerp(-w2, 0.3).0),
+        AsRotation(r1.nlerp(r2, 0.3)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(w1.slerp(-w2, 0.3).0),
+        AsRotation(r1.slerp(r2, 0.3)),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation(w1.interpolate(-w2, 0.3).0),
+        AsRotation(r1.interpolate(r2, 0.3)),
+        epsilon = 1e-5
+    );
+}
@@ -0,0 +1,133 @@
+use cliffy::*;
+use std::f32::consts::{FRAC_PI_2, PI};
+
+fn xy() -> Bivec4 {
+    Bivec4::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+}
+
+fn zw() -> Bivec4 {
+    Bivec4::new(0.0, 0.0, 0.0, 0.0, 0.0, 1.0)
+}
+
+fn general() -> Rot4 {
+    Bivec4::new(0.3, -0.7, 0.2, 0.5, 1.1, -0.4).exp()
+}
+
+#[test]
+fn test_matches_rot3() {
+    let r3 = Rot3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 1.3);
+    let r4 = Rot4::from(r3);
+    let v = Vec3::new(3.0, 1.0, -2.0);
+
+    let rotated = r4 * Vec4::from(v);
+    assert_approx_eq!(Vec3::from(rotated), r3 * v, epsilon = 1e-5);
+    assert_approx_eq!(rotated.w, 0.0, epsilon = 1e-6);
+}
+
+#[test]
+fn test_double_rotation() {
+    let r = Rot4::from_double_rotation(FRAC_PI_2, xy(), PI, zw());
+
+    assert_approx_eq!(
+        r * Vec4::new(1.0, 0.0, 0.0, 0.0),
+        Vec4::new(0.0, 1.0, 0.0, 0.0),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        r * Vec4::new(0.0, 0.0, 1.0, 0.0),
+        Vec4::new(0.0, 0.0, -1.0, 0.0),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        r * Vec4::new(0.0, 0.0, 0.0, 1.0),
+        Vec4::new(0.0, 0.0, 0.0, -1.0),
+        epsilon = 1e-5
+    );
+
+    // A double rotation has a quadvector part and no fixed vectors
+    assert!(r.xyzw.abs() > 0.1);
+    assert_approx_eq!(
+        AsRotation(r),
+        AsRotation(Rot4::from_angle_plane(PI, zw()) * Rot4::from_angle_plane(FRAC_PI_2, xy())),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_composition_and_matrix() {
+    let r1 = general();
+    let r2 = Rot4::from_double_rotation(0.4, xy(), -1.2, zw());
+    let v = Vec4::new(1.0, -2.0, 0.5, 3.0);
+
+    assert_approx_eq!((r1 * r2) * v, r1 * (r2 * v), epsilon = 1e-5);
+    assert_approx_eq!(r1.into_matrix() * v, r1 * v, epsilon = 1e-5);
+    assert_approx_eq!(r1.reversed() * (r1 * v), v, epsilon = 1e-5);
+    assert_approx_eq!((r1 * v).mag(), v.mag(), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(r1.rotated_by(r2)),
+        AsRotation(r2 * r1 * r2.reversed()),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_log_pow() {
+    let b = Bivec4::new(0.3, -0.7, 0.2, 0.5, 1.1, -0.4);
+    assert_approx_eq!(b.exp().log(), b, epsilon = 1e-5);
+
+    let r = general();
+    let half = r.powf(0.5);
+    assert_approx_eq!(AsRotation(half * half), AsRotation(r), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(Rot4::identity().powf(0.5)),
+        AsRotation(Rot4::identity()),
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_interpolation() {
+    let a = Rot4::from_double_rotation(0.2, xy(), 0.3, zw());
+    let b = Rot4::from_double_rotation(1.0, xy(), 1.5, zw());
+    let mid = Rot4::from_double_rotation(0.6, xy(), 0.9, zw());
+
+    assert_approx_eq!(AsRotation(a.slerp(b, 0.5)), AsRotation(mid), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(a.interpolate(b, 0.5)),
+        AsRotation(mid),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(AsRotation(a.nlerp(b, 0.0)), AsRotation(a), epsilon = 1e-5);
+    assert_approx_eq!(AsRotation(a.slerp(-b, 1.0)), AsRotation(b), epsilon = 1e-5);
+}
+
+#[test]
+fn test_conversions() {
+    let r = general();
+    assert_eq!(r.as_drot4().as_rot4(), r);
+    assert_eq!(
+        Rot4::identity().as_drot4(),
+        DRot4::new(1.0, DBivec4::zero(), 0.0)
+    );
+}
+
+#[test]
+fn test_normalize() {
+    let a = Rot4::from_double_rotation(0.2, xy(), 1.3, zw());
+    let b = general();
+    let r = (a + b).normalized();
+
+    // The result is a rotation again, so it preserves lengths
+    let v = Vec4::new(1.0, -2.0, 0.5, 3.0);
+    assert_approx_eq!((r * v).mag(), v.mag(), epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(r * r.reversed()),
+        AsRotation(Rot4::identity()),
+        epsilon = 1e-5
+    );
+    assert_approx_eq!(
+        AsRotation((a * 3.0).normalized()),
+        AsRotation(a),
+        epsilon = 1e-5
+    );
+}
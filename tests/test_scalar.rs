@@ -0,0 +1,321 @@
+use cliffy::*;
+use std::ops::*;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+struct Real(f64);
+
+impl Add for Real {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Real(self.0 + rhs.0)
+    }
+}
+
+impl Sub for Real {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Real(self.0 - rhs.0)
+    }
+}
+
+impl Mul for Real {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Real(self.0 * rhs.0)
+    }
+}
+
+impl Div for Real {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Real(self.0 / rhs.0)
+    }
+}
+
+impl Neg for Real {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Real(-self.0)
+    }
+}
+
+impl AddAssign for Real {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl SubAssign for Real {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl MulAssign for Real {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl DivAssign for Real {
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl Scalar for Real {
+    fn zero() -> Self {
+        Real(0.0)
+    }
+
+    fn one() -> Self {
+        Real(1.0)
+    }
+
+    fn epsilon() -> Self {
+        Real(f64::EPSILON)
+    }
+
+    fn pi() -> Self {
+        Real(std::f64::consts::PI)
+    }
+
+    fn abs(self) -> Self {
+        Real(self.0.abs())
+    }
+
+    fn signum(self) -> Self {
+        Real(self.0.signum())
+    }
+
+    fn sqrt(self) -> Self {
+        Real(self.0.sqrt())
+    }
+
+    fn sin(self) -> Self {
+        Real(self.0.sin())
+    }
+
+    fn cos(self) -> Self {
+        Real(self.0.cos())
+    }
+
+    fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+
+    fn tan(self) -> Self {
+        Real(self.0.tan())
+    }
+
+    fn asin(self) -> Self {
+        Real(self.0.asin())
+    }
+
+    fn acos(self) -> Self {
+        Real(self.0.acos())
+    }
+
+    fn atan2(self, other: Self) -> Self {
+        Real(self.0.atan2(other.0))
+    }
+
+    fn sinh(self) -> Self {
+        Real(self.0.sinh())
+    }
+
+    fn cosh(self) -> Self {
+        Real(self.0.cosh())
+    }
+
+    fn exp(self) -> Self {
+        Real(self.0.exp())
+    }
+
+    fn ln(self) -> Self {
+        Real(self.0.ln())
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Real(self.0.mul_add(a.0, b.0))
+    }
+
+    fn min(self, other: Self) -> Self {
+        Real(self.0.min(other.0))
+    }
+
+    fn max(self, other: Self) -> Self {
+        Real(self.0.max(other.0))
+    }
+}
+
+impl ApproxEq for Real {
+    type Epsilon = Real;
+
+    fn default_epsilon() -> Real {
+        Real(f64::default_epsilon())
+    }
+
+    fn default_max_relative() -> Real {
+        Real(f64::default_max_relative())
+    }
+
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn approx_eq_abs(&self, other: &Self, epsilon: Real) -> bool {
+        self.0.approx_eq_abs(&other.0, epsilon.0)
+    }
+
+    fn approx_eq_rel(&self, other: &Self, epsilon: Real, max_relative: Real) -> bool {
+        self.0.approx_eq_rel(&other.0, epsilon.0, max_relative.0)
+    }
+
+    fn approx_eq_ulps(&self, other: &Self, epsilon: Real, max_ulps: u32) -> bool {
+        self.0.approx_eq_ulps(&other.0, epsilon.0, max_ulps)
+    }
+}
+
+fn triple<T: Scalar>(a: Vector3<T>, b: Vector3<T>, c: Vector3<T>) -> T {
+    a.cross(b).dot(c)
+}
+
+#[test]
+fn test_provided_constants() {
+    assert_eq!(f32::two(), 2.0);
+    assert_eq!(f64::half(), 0.5);
+    assert_eq!(Real::two(), Real(2.0));
+    assert_eq!(Real::half(), Real(0.5));
+}
+
+#[test]
+fn test_generic_functions() {
+    assert_eq!(triple(Vec3::right(), Vec3::up(), Vec3::forward()), 1.0);
+    assert_eq!(triple(DVec3::right(), DVec3::up(), DVec3::forward()), 1.0);
+    assert_eq!(
+        triple(Vector3::<Real>::right(), Vector3::up(), Vector3::forward()),
+        Real(1.0)
+    );
+}
+
+#[test]
+fn test_custom_scalar() {
+    let v = Vector3::new(Real(3.0), Real(4.0), Real(12.0));
+
+    assert_eq!(v.mag(), Real(13.0));
+    assert_eq!(
+        v + Vector3::one(),
+        Vector3::new(Real(4.0), Real(5.0), Real(13.0))
+    );
+    assert_eq!(
+        Vector2::new(Real(1.0), Real(0.0)).wedge(Vector2::new(Real(0.0), Real(1.0))),
+        Bivector2::new(Real(1.0))
+    );
+
+    let r = Rotor3::<Real>::identity();
+    assert_eq!(r * v, v);
+    assert_eq!(r.reversed() * r, r);
+
+    let r = Rotor2::from_angle(Real::pi());
+    let v = r * Vector2::right();
+    assert_approx_eq!(v.x, Real(-1.0), epsilon = Real(1e-12));
+    assert_approx_eq!(v.y, Real(0.0), epsilon = Real(1e-12));
+}
+
+fn real(v: DVec3) -> Vector3<Real> {
+    Vector3::new(Real(v.x), Real(v.y), Real(v.z))
+}
+
+#[test]
+fn test_float_impls() {
+    assert_eq!(f32::epsilon(), f32::EPSILON);
+    assert_eq!(f64::epsilon(), f64::EPSILON);
+    assert_eq!(f32::pi(), std::f32::consts::PI);
+    assert_eq!(f64::pi(), std::f64::consts::PI);
+    assert_eq!(Scalar::sin_cos(0.5f64), 0.5f64.sin_cos());
+    assert_eq!(Scalar::mul_add(2.0f32, 3.0, 1.0), 7.0);
+    assert_eq!(Scalar::atan2(1.0f64, -1.0), 0.75 * std::f64::consts::PI);
+    assert_eq!(Scalar::signum(-0.0f32), -1.0);
+
+    // min and max pick the number over a NaN like the std methods
+    assert_eq!(Scalar::min(f32::NAN, 1.0), 1.0);
+    assert_eq!(Scalar::max(2.0f64, f64::NAN), 2.0);
+    assert!(Scalar::sqrt(-1.0f64).is_nan());
+}
+
+#[test]
+fn test_matches_f64() {
+    let axis = DVec3::new(1.0, -2.0, 0.5);
+    let v = DVec3::new(0.3, 4.0, -1.5);
+    let r = DRot3::from_axis_angle(axis, 1.1);
+    let rr = Rotor3::from_axis_angle(real(axis), Real(1.1));
+
+    // Real runs the same f64 operations in the same order
+    assert_eq!(rr * real(v), real(r * v));
+    assert_eq!(rr.s, Real(r.s));
+    assert_eq!(rr.log().xy, Real(r.log().xy));
+    assert_eq!(
+        rr.slerp(Rotor3::identity(), Real(0.25)).s,
+        Real(r.slerp(DRot3::identity(), 0.25).s)
+    );
+
+    let m = r.into_matrix();
+    let mr = rr.into_matrix();
+    assert_eq!(mr.determinant(), Real(m.determinant()));
+    assert_eq!(mr.inverse() * real(v), real(m.inverse() * v));
+
+    let t = Transformation3::new(real(v), rr, Real(2.0));
+    let td = DTransform3::new(v, r, 2.0);
+    assert_eq!(
+        t.transform_point(real(axis)),
+        real(td.transform_point(axis))
+    );
+    assert_eq!(
+        t.inverse().transform_point(real(axis)),
+        real(td.inverse().transform_point(axis))
+    );
+}
+
+#[test]
+fn test_f32_agrees_with_f64() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, -2.0, 0.5), 1.1);
+    let v = Vec3::new(0.3, 4.0, -1.5);
+    let dr = r.as_drot3();
+    let dv = v.as_dvec3();
+    let rotated = r * v;
+    let expected = dr * dv;
+    assert_approx_eq!(rotated.as_dvec3(), expected, epsilon = 1e-5);
+}
+
+#[test]
+fn test_degenerate() {
+    // A zero vector has no direction
+    let n = Vector3::<Real>::zero().normalized();
+    assert!(n.x.0.is_nan() && n.y.0.is_nan() && n.z.0.is_nan());
+    assert!(DVec2::zero().normalized().x.is_nan());
+
+    let singular = Matrix3::new(real(DVec3::one()), real(DVec3::one()), real(DVec3::up()));
+    assert_eq!(singular.determinant(), Real(0.0));
+    assert!(singular.try_inverse().is_none());
+
+    // r and -r rotate the same, exactly
+    let r = Rotor3::from_axis_angle(real(DVec3::new(3.0, 1.0, -2.0)), Real(2.2));
+    let v = real(DVec3::new(1.0, 2.0, 3.0));
+    assert_eq!(-r * v, r * v);
+
+    // A scaled rotor scales by its squared magnitude
+    let scaled = (r * Real(2.0)) * v;
+    let expected = (r * v) * Real(4.0);
+    assert_approx_eq!(scaled, expected, epsilon = Real(1e-12));
+    assert_approx_eq!(
+        (r * Real(2.0)).normalized().mag(),
+        Real(1.0),
+        epsilon = Real(1e-12)
+    );
+}
@@ -0,0 +1,214 @@
+#![cfg(feature = "serde")]
+
+use cliffy::pga3d::*;
+use cliffy::sta::*;
+use cliffy::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt::Debug;
+
+fn round_trip<V: Serialize + DeserializeOwned + PartialEq + Debug>(value: V) {
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<V>(&json).unwrap(), value, "{}", json);
+
+    let bytes = bincode::serialize(&value).unwrap();
+    assert_eq!(bincode::deserialize::<V>(&bytes).unwrap(), value);
+}
+
+fn json<V: Serialize>(value: &V) -> String {
+    serde_json::to_string(value).unwrap()
+}
+
+#[test]
+fn test_compact_json() {
+    assert_eq!(json(&Vec3::new(1.0, 2.0, 3.0)), "[1.0,2.0,3.0]");
+    assert_eq!(json(&Rot3::identity()), "[1.0,[0.0,0.0,0.0]]");
+    assert_eq!(json(&Mat2::identity()), "[[[1.0,0.0],[0.0,1.0]]]");
+    assert_eq!(json(&EulerOrder::ZYX), "\"ZYX\"");
+
+    assert!(serde_json::from_str::<Vec3>("[1.0,2.0]").is_err());
+    assert!(serde_json::from_str::<Vec3>("{\"x\":1.0}").is_err());
+}
+
+#[test]
+fn test_compact_binary() {
+    let bytes = bincode::serialize(&DVec3::new(1.0, 2.0, 3.0)).unwrap();
+    assert_eq!(bytes.len(), 3 * 8);
+    let bytes = bincode::serialize(&Mat4::identity()).unwrap();
+    assert_eq!(bytes.len(), 16 * 4);
+}
+
+#[test]
+fn test_round_trips() {
+    round_trip(Vec2::new(1.0, -2.0));
+    round_trip(DVec3::new(1.0, -2.0, 0.5));
+    round_trip(Vec4::new(1.0, -2.0, 0.5, 4.0));
+    round_trip(Bivec2::new(0.5));
+    round_trip(Bivec3::new(0.5, -1.0, 2.0));
+    round_trip(Bivec4::new(0.5, -1.0, 2.0, 3.0, 4.0, 5.0));
+    round_trip(Trivec3::new(1.5));
+    round_trip(Rot2::from_angle(0.3));
+    round_trip(Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7));
+    round_trip(Rot4::from_angle_plane(
+        0.4,
+        Bivec4::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0),
+    ));
+    round_trip(Multivec3::new(
+        1.0,
+        Vec3::new(1.0, 2.0, 3.0),
+        Bivec3::new(4.0, 5.0, 6.0),
+        Trivec3::new(7.0),
+    ));
+    round_trip(Mat3::identity() * 2.0);
+    round_trip(DMat4::identity());
+    round_trip(Transform3::new(
+        Vec3::new(1.0, 2.0, 3.0),
+        Rot3::identity(),
+        2.0,
+    ));
+    round_trip(Motor3::from_translation(Vec3::new(1.0, 2.0, 3.0)));
+    round_trip(Point3::new(1.0, 2.0, 3.0));
+    round_trip(cga3d::Multivector::up(Vec3::new(1.0, 2.0, 3.0)));
+    round_trip(LorentzRotor::from_boost(0.5, Vec3::new(1.0, 0.0, 0.0)));
+    round_trip(Handedness::Right);
+    round_trip(DepthRange::OneToZero);
+}
+
+#[test]
+fn test_round_trips_remaining_types() {
+    round_trip(DVec2::new(1.0, -2.0));
+    round_trip(DVec4::new(1.0, -2.0, 0.5, 4.0));
+    round_trip(DBivec3::new(0.5, -1.0, 2.0));
+    round_trip(DRot2::from_angle(-2.5));
+    round_trip(DRot3::from_axis_angle(DVec3::new(1.0, 2.0, 3.0), 0.7));
+    round_trip(Mat2::identity() * -3.0);
+    round_trip(DMat3::identity());
+    round_trip(Mat4::identity() * 0.5);
+    round_trip(Transform2::new(
+        Vec2::new(1.0, 2.0),
+        Rot2::from_angle(0.3),
+        2.0,
+    ));
+    round_trip(DTransform3::new(
+        DVec3::new(1.0, 2.0, 3.0),
+        DRot3::from_axis_angle(DVec3::up(), 1.0),
+        0.5,
+    ));
+    round_trip(pga2d::Point2::new(1.0, 2.0));
+    round_trip(pga2d::Line2::new(1.0, -1.0, 0.5));
+    round_trip(pga2d::Motor2::from_rotation(0.5f32));
+    round_trip(Plane3::new(1.0, 0.0, 0.0, -2.0));
+    round_trip(Line3::from_point_direction(
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::up(),
+    ));
+    round_trip(Motor3::<f64>::identity());
+    round_trip(FourVector::new(1.0, 0.5, 0.0, -0.5));
+    round_trip(SpacetimeBivector::new(Vec3::right(), Vec3::up()));
+    for &order in EulerOrder::ALL.iter() {
+        round_trip(order);
+    }
+    round_trip(EulerFrame::Extrinsic);
+    round_trip(Handedness::Left);
+    round_trip(DepthRange::ZeroToOne);
+    round_trip(DepthRange::NegativeOneToOne);
+}
+
+#[test]
+fn test_values_kept_exactly() {
+    // Nothing is normalised on the way through, rotors keep their magnitude
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7) * 3.0;
+    round_trip(r);
+    round_trip(-r);
+    round_trip(Rot3::new(0.0, Bivec3::zero()));
+    round_trip(Vec3::zero());
+
+    round_trip(DVec3::new(f64::MAX, f64::MIN_POSITIVE, -0.0));
+    round_trip(Vec3::new(f32::MIN, 1e-45, f32::EPSILON));
+    round_trip(DVec2::new(0.1, 1.0 / 3.0));
+    let json = json(&Vec2::new(-0.0, 0.0));
+    assert_eq!(json, "[-0.0,0.0]");
+    assert!(serde_json::from_str::<Vec2>(&json)
+        .unwrap()
+        .x
+        .is_sign_negative());
+}
+
+#[test]
+fn test_non_finite() {
+    // JSON has no NaN or infinity, serde_json writes null and can't read it back
+    let v = Vec3::new(f32::NAN, f32::INFINITY, 1.0);
+    assert_eq!(json(&v), "[null,null,1.0]");
+    assert!(serde_json::from_str::<Vec3>(&json(&v)).is_err());
+
+    // A binary format keeps them as they are
+    let bytes = bincode::serialize(&v).unwrap();
+    let back: Vec3 = bincode::deserialize(&bytes).unwrap();
+    assert!(back.x.is_nan());
+    assert_eq!(back.y, f32::INFINITY);
+    assert_eq!(back.z, 1.0);
+}
+
+#[test]
+fn test_malformed() {
+    assert_eq!(
+        json(&Transform2::new(Vec2::new(1.0, 2.0), Rot2::identity(), 2.0)),
+        "[[1.0,2.0],[1.0,[0.0]],2.0]"
+    );
+    assert!(serde_json::from_str::<Vec3>("[1.0,2.0,3.0,4.0]").is_err());
+    assert!(serde_json::from_str::<Vec3>("[]").is_err());
+    assert!(serde_json::from_str::<Vec3>("[1.0,\"2\",3.0]").is_err());
+    assert!(serde_json::from_str::<Rot3>("[1.0,0.0,0.0,0.0]").is_err());
+    assert!(serde_json::from_str::<Rot3>("[1.0,[0.0,0.0]]").is_err());
+    assert!(serde_json::from_str::<Mat2>("[[1.0,0.0],[0.0,1.0]]").is_err());
+    assert!(serde_json::from_str::<Handedness>("\"Up\"").is_err());
+
+    // A truncated binary value runs out of bytes
+    let bytes = bincode::serialize(&Mat3::identity()).unwrap();
+    assert!(bincode::deserialize::<Mat3>(&bytes[..bytes.len() - 1]).is_err());
+    assert!(bincode::deserialize::<Mat3>(&[]).is_err());
+}
+
+#[cfg(feature = "simd")]
+#[test]
+fn test_simd_types() {
+    let v = Vec3A::new(1.0, -2.0, 0.5);
+    assert_eq!(json(&v), json(&Vec3::from(v)));
+    round_trip(v);
+    round_trip(Vec4A::new(1.0, -2.0, 0.5, 4.0));
+    let r = Rot3A::from(Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7));
+    assert_eq!(json(&r), json(&Rot3::from(r)));
+    round_trip(r);
+
+    // The padding lane isn't written
+    assert_eq!(bincode::serialize(&v).unwrap().len(), 3 * 4);
+    assert!(serde_json::from_str::<Vec3A>("[1.0,2.0,3.0,0.0]").is_err());
+}
+
+#[test]
+fn test_wide_types() {
+    let v = Vec3x4::new([1.0, 2.0, 3.0, 4.0], [5.0; 4], [-1.0, 0.5, 0.0, 2.0]);
+    assert_eq!(
+        json(&v),
+        "[[1.0,2.0,3.0,4.0],[5.0,5.0,5.0,5.0],[-1.0,0.5,0.0,2.0]]"
+    );
+    round_trip(v);
+    round_trip(DVec3x4::splat(DVec3::new(1.0, -2.0, 0.5)));
+    round_trip(Bivec3x8::splat(Bivec3::new(0.5, -1.0, 2.0)));
+
+    // Lane counts past serde's array impls work too
+    let r = WideRotor3::<f32, 64>::from_fn(|i| Rot3::from_axis_angle(Vec3::up(), i as f32));
+    round_trip(r);
+    assert_eq!(bincode::serialize(&r).unwrap().len(), 4 * 64 * 4);
+    let r = Rot3x4::splat(Rot3::identity());
+    assert_eq!(
+        json(&r),
+        "[[1.0,1.0,1.0,1.0],[[0.0,0.0,0.0,0.0],[0.0,0.0,0.0,0.0],[0.0,0.0,0.0,0.0]]]"
+    );
+
+    assert!(
+        serde_json::from_str::<Vec3x4>("[[1.0,2.0,3.0],[5.0,5.0,5.0,5.0],[0.0,0.0,0.0,0.0]]")
+            .is_err()
+    );
+    assert!(serde_json::from_str::<Vec3x4>("[[1.0,2.0,3.0,4.0],[5.0,5.0,5.0,5.0]]").is_err());
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "simd")]
+
+use cliffy::*;
+
+fn vectors() -> Vec<Vec3> {
+    vec![
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-0.5, 0.25, 4.0),
+        Vec3::new(0.0, -1.0, 0.0),
+        Vec3::new(3.5, -2.5, -0.75),
+    ]
+}
+
+fn rotors() -> Vec<Rot3> {
+    vec![
+        Rot3::identity(),
+        Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7),
+        Rot3::from_axis_angle(Vec3::up(), -2.5),
+        Rot3::from_angle_plane(1.2, Bivec3::new(0.3, -0.4, 0.5)),
+    ]
+}
+
+#[test]
+fn test_layout() {
+    assert_eq!(std::mem::size_of::<Vec3A>(), 16);
+    assert_eq!(std::mem::align_of::<Vec3A>(), 16);
+    assert_eq!(std::mem::align_of::<Vec4A>(), 16);
+    assert_eq!(std::mem::align_of::<Rot3A>(), 16);
+}
+
+#[test]
+fn test_conversions() {
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_eq!(Vec3::from(Vec3A::from(v)), v);
+    assert_eq!(Vec3A::from(v).to_array(), [1.0, 2.0, 3.0]);
+    let v = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    assert_eq!(Vec4::from(Vec4A::from(v)), v);
+    for r in rotors() {
+        assert_eq!(Rot3::from(Rot3A::from(r)), r);
+        assert_eq!(Rot3A::from(r).s(), r.s);
+        assert_eq!(Rot3A::from(r).bv(), r.bv);
+    }
+}
+
+#[test]
+fn test_vec3a() {
+    for a in vectors() {
+        let sa = Vec3A::from(a);
+        assert_approx_eq!(sa.mag(), a.mag());
+        assert_approx_eq!(Vec3::from(sa.normalized()), a.normalized());
+        assert_approx_eq!(Vec3::from(sa * 2.5 - sa / 2.0), a * 2.5 - a / 2.0);
+        assert_approx_eq!(Vec3::from(-sa), -a);
+        assert_eq!(sa.dual(), a.dual());
+        for b in vectors() {
+            let sb = Vec3A::from(b);
+            assert_approx_eq!(sa.dot(sb), a.dot(b), epsilon = 1e-6);
+            assert_approx_eq!(Vec3::from(sa.cross(sb)), a.cross(b), epsilon = 1e-6);
+            assert_approx_eq!(sa.wedge(sb), a.wedge(b), epsilon = 1e-6);
+            assert_approx_eq!(Rot3::from(sa.geom(sb)), a.geom(b), epsilon = 1e-6);
+            assert_approx_eq!(Vec3::from(sa.reflected(sb)), a.reflected(b), epsilon = 1e-6);
+            assert_approx_eq!(Vec3::from(sa.lerp(sb, 0.3)), a.lerp(b, 0.3), epsilon = 1e-6);
+        }
+    }
+}
+
+#[test]
+fn test_vec4a() {
+    let a = Vec4::new(1.0, -2.0, 0.5, 3.0);
+    let b = Vec4::new(0.25, 4.0, -1.0, 2.0);
+    let (sa, sb) = (Vec4A::from(a), Vec4A::from(b));
+    assert_approx_eq!(sa.dot(sb), a.dot(b));
+    assert_approx_eq!(sa.mag(), a.mag());
+    assert_approx_eq!(Vec4::from(sa.normalized()), a.normalized());
+    assert_approx_eq!(sa.wedge(sb), a.wedge(b));
+    assert_approx_eq!(Vec4::from(sa.projected(sb)), a.projected(b), epsilon = 1e-6);
+}
+
+#[test]
+fn test_rotate_vec() {
+    for r in rotors() {
+        for v in vectors() {
+            let expected = r * v;
+            assert_approx_eq!(
+                Vec3::from(Rot3A::from(r) * Vec3A::from(v)),
+                expected,
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    // Unnormalized rotors scale like the scalar sandwich product
+    let r = Rot3::new(2.0, Bivec3::new(0.5, -1.0, 0.25));
+    let v = Vec3::new(1.0, 2.0, 3.0);
+    assert_approx_eq!(
+        Vec3::from(Rot3A::from(r) * Vec3A::from(v)),
+        r * v,
+        epsilon = 1e-5
+    );
+}
+
+#[test]
+fn test_rotor_ops() {
+    for a in rotors() {
+        let sa = Rot3A::from(a);
+        assert_approx_eq!(sa.mag(), a.mag());
+        assert_approx_eq!(Rot3::from(sa.reversed()), a.reversed());
+        assert_approx_eq!(Rot3::from(-sa * 0.5), -a * 0.5);
+        assert_approx_eq!(sa.into_matrix(), a.into_matrix(), epsilon = 1e-6);
+        // Scaled rotors scale the matrix by the squared magnitude
+        assert_approx_eq!(
+            (sa * 2.0).into_matrix(),
+            (a * 2.0).into_matrix(),
+            epsilon = 1e-5
+        );
+        assert_approx_eq!(sa.log(), a.log(), epsilon = 1e-6);
+        for b in rotors() {
+            let sb = Rot3A::from(b);
+            assert_approx_eq!(sa.dot(sb), a.dot(b), epsilon = 1e-6);
+            assert_approx_eq!(Rot3::from(sa * sb), a * b, epsilon = 1e-6);
+            assert_approx_eq!(
+                Rot3::from(sa.rotated_by(sb)),
+                a.rotated_by(b),
+                epsilon = 1e-6
+            );
+            assert_approx_eq!(
+                Rot3::from(sa.nlerp(sb, 0.3)),
+                a.nlerp(b, 0.3),
+                epsilon = 1e-6
+            );
+            assert_approx_eq!(
+                Rot3::from(sa.slerp(sb, 0.3)),
+                a.slerp(b, 0.3),
+                epsilon = 1e-6
+            );
+            assert_approx_eq!(
+                Rot3::from(sa.interpolate(sb, 0.3)),
+                a.interpolate(b, 0.3),
+                epsilon = 1e-5
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rotor_constructors() {
+    let axis = Vec3::new(1.0, -1.0, 2.0);
+    assert_eq!(
+        Rot3::from(Rot3A::from_axis_angle(axis.into(), 0.4)),
+        Rot3::from_axis_angle(axis, 0.4)
+    );
+    assert_eq!(Rot3::from(Rot3A::identity()), Rot3::identity());
+    let v = Vec3A::new(1.0, 2.0, 3.0);
+    assert_eq!(Rot3A::identity() * v, v);
+}
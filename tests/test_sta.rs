@@ -0,0 +1,229 @@
+use cliffy::sta::*;
+use cliffy::*;
+
+algebra!(Cl13, 1, 3, 0);
+
+fn to_cl13(r: LorentzRotor<f64>) -> Cl13::Multivector<f64> {
+    let (e, b) = (r.bv.e, r.bv.b);
+    let mut c = [0.0; 16];
+    c[0] = r.s;
+    c[0b0011] = -e.x;
+    c[0b0101] = -e.y;
+    c[0b1001] = -e.z;
+    c[0b1100] = -b.x;
+    c[0b1010] = b.y;
+    c[0b0110] = -b.z;
+    c[0b1111] = r.ps;
+    Cl13::Multivector::new(c)
+}
+
+fn vec_to_cl13(v: FourVector<f64>) -> Cl13::Multivector<f64> {
+    let mut c = [0.0; 16];
+    c[0b0001] = v.t;
+    c[0b0010] = v.x;
+    c[0b0100] = v.y;
+    c[0b1000] = v.z;
+    Cl13::Multivector::new(c)
+}
+
+fn rotor() -> LorentzRotor<f64> {
+    LorentzRotor::from_boost(0.8, DVec3::new(1.0, -2.0, 0.5))
+        * LorentzRotor::from_rot3(DRot3::from_axis_angle(DVec3::new(0.0, 1.0, 1.0), 1.1))
+}
+
+#[test]
+fn test_products_match_cl13() {
+    let a = LorentzRotor::new(
+        0.5,
+        SpacetimeBivector::new(DVec3::new(1.0, 2.0, -1.0), DVec3::new(0.5, 0.0, 3.0)),
+        -1.5,
+    );
+    let b = LorentzRotor::new(
+        2.0,
+        SpacetimeBivector::new(DVec3::new(-0.5, 1.0, 1.0), DVec3::new(2.0, -1.0, 0.5)),
+        0.25,
+    );
+    assert_approx_eq!(
+        to_cl13(a * b).c,
+        (to_cl13(a) * to_cl13(b)).c,
+        epsilon = 1e-9
+    );
+
+    let u = FourVector::new(2.0, 1.0, -0.5, 0.25);
+    let v = FourVector::new(-1.0, 0.5, 2.0, 1.0);
+    assert_approx_eq!(
+        to_cl13(u * v).c,
+        (vec_to_cl13(u) * vec_to_cl13(v)).c,
+        epsilon = 1e-9
+    );
+    assert_eq!(u.dot(v), vec_to_cl13(u).scalar_product(vec_to_cl13(v)));
+
+    let r = rotor();
+    assert_approx_eq!(
+        vec_to_cl13(r * u).c,
+        to_cl13(r).transform(vec_to_cl13(u)).c,
+        epsilon = 1e-9
+    );
+    let f = SpacetimeBivector::from_field(DVec3::new(1.0, 0.0, 2.0), DVec3::new(0.0, -1.0, 0.5));
+    let rf = LorentzRotor::new(0.0, r * f, 0.0);
+    let f = LorentzRotor::new(0.0, f, 0.0);
+    assert_approx_eq!(
+        to_cl13(rf).c,
+        to_cl13(r).transform(to_cl13(f)).c,
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_four_vectors() {
+    let v = DVec3::new(0.3, -0.4, 0.5);
+    let u = FourVector::from_velocity(v);
+    assert_approx_eq!(u.mag_sq(), 1.0, epsilon = 1e-12);
+    assert_approx_eq!(u.velocity(), v, epsilon = 1e-12);
+    assert!(u.is_timelike());
+    assert!(FourVector::new(1.0, 2.0, 0.0, 0.0).is_spacelike());
+    assert_eq!(
+        FourVector::from(DVec4::new(1.0, 2.0, 3.0, 4.0)),
+        FourVector::new(4.0, 1.0, 2.0, 3.0)
+    );
+}
+
+#[test]
+fn test_boosts() {
+    let rest = FourVector::new(1.0, 0.0, 0.0, 0.0);
+    let dir = DVec3::new(1.0, 2.0, 2.0);
+    let boosted = LorentzRotor::from_boost(0.6, dir) * rest;
+    assert_approx_eq!(boosted.t, 0.6f64.cosh(), epsilon = 1e-12);
+    assert_approx_eq!(
+        boosted.space(),
+        dir.normalized() * 0.6f64.sinh(),
+        epsilon = 1e-12
+    );
+
+    let v = DVec3::new(0.2, 0.5, -0.3);
+    assert_approx_eq!(
+        LorentzRotor::from_velocity(v) * rest,
+        FourVector::from_velocity(v),
+        epsilon = 1e-9
+    );
+
+    // Collinear velocities add relativistically
+    let x = DVec3::new(1.0, 0.0, 0.0);
+    let r = LorentzRotor::from_velocity(x * 0.5) * LorentzRotor::from_velocity(x * 0.8);
+    let w = (r * rest).velocity();
+    assert_approx_eq!(w, x * (1.3 / 1.4), epsilon = 1e-12);
+
+    // Intervals are invariant
+    let p = FourVector::new(2.0, 1.0, -0.5, 0.25);
+    assert_approx_eq!((rotor() * p).mag_sq(), p.mag_sq(), epsilon = 1e-12);
+}
+
+#[test]
+fn test_speed_of_light() {
+    let x = DVec3::new(0.6, 0.0, 0.8);
+    // Just below c the boost is finite, if badly conditioned
+    let below = x * (1.0 - 1e-9);
+    let r = LorentzRotor::try_from_velocity(below).unwrap();
+    assert!(r.s.is_finite() && r.bv.e.mag().is_finite());
+    let u = r * FourVector::new(1.0, 0.0, 0.0, 0.0);
+    assert_approx_eq!(u.velocity(), below, epsilon = 1e-9);
+    assert_approx_eq!(
+        u.t / FourVector::try_from_velocity(below).unwrap().t,
+        1.0,
+        epsilon = 1e-6
+    );
+    assert_eq!(
+        LorentzRotor::try_from_velocity(DVec3::zero()),
+        Some(LorentzRotor::identity())
+    );
+
+    for &v in &[x, x * 1.5, DVec3::new(f64::NAN, 0.0, 0.0)] {
+        assert!(LorentzRotor::try_from_velocity(v).is_none());
+        assert!(FourVector::try_from_velocity(v).is_none());
+    }
+}
+
+#[test]
+#[should_panic(expected = "needs a speed below c")]
+fn test_from_velocity_at_c_panics() {
+    LorentzRotor::from_velocity(DVec3::new(0.0, 1.0, 0.0));
+}
+
+#[test]
+fn test_rotations() {
+    let r3 = DRot3::from_axis_angle(DVec3::new(1.0, -1.0, 2.0), 0.9);
+    let r = LorentzRotor::from(r3);
+    let p = FourVector::new(3.0, 1.0, 2.0, -1.0);
+    assert_approx_eq!(
+        r * p,
+        FourVector::from_time_space(3.0, r3 * p.space()),
+        epsilon = 1e-9
+    );
+
+    let bv = DBivec3::new(0.3, -0.2, 0.6);
+    assert_approx_eq!(
+        SpacetimeBivector::from(bv).exp(),
+        LorentzRotor::from_rot3(bv.exp()),
+        epsilon = 1e-9
+    );
+}
+
+#[test]
+fn test_exp() {
+    let dir = DVec3::new(0.0, 3.0, 4.0);
+    assert_approx_eq!(
+        SpacetimeBivector::from_rapidity(0.35, dir).exp(),
+        LorentzRotor::from_boost(0.7, dir),
+        epsilon = 1e-9
+    );
+    assert_approx_eq!(
+        SpacetimeBivector::zero().exp(),
+        LorentzRotor::identity(),
+        epsilon = 1e-9
+    );
+
+    // Against the power series for a general bivector
+    let f = SpacetimeBivector::new(DVec3::new(0.3, -0.2, 0.5), DVec3::new(0.1, 0.4, -0.6));
+    let f_cl = to_cl13(LorentzRotor::new(0.0, f, 0.0));
+    let mut term = Cl13::Multivector::scalar(1.0);
+    let mut sum = term;
+    for n in 1..30 {
+        term = term * f_cl / n as f64;
+        sum += term;
+    }
+    assert_approx_eq!(to_cl13(f.exp()).c, sum.c, epsilon = 1e-9);
+}
+
+#[test]
+fn test_split_and_normalize() {
+    let r = rotor();
+    let (boost, rot) = r.to_boost_rot3();
+    assert_approx_eq!(boost.bv.b, DVec3::zero(), epsilon = 1e-12);
+    assert_approx_eq!(boost.ps, 0.0, epsilon = 1e-12);
+    assert_approx_eq!(boost * LorentzRotor::from_rot3(rot), r, epsilon = 1e-9);
+
+    assert_approx_eq!((r * 3.0).normalized(), r, epsilon = 1e-9);
+    assert_approx_eq!(r * r.reversed(), LorentzRotor::identity(), epsilon = 1e-9);
+}
+
+#[test]
+fn test_fields() {
+    let f = SpacetimeBivector::from_field(DVec3::new(1.0, 2.0, 0.0), DVec3::new(0.5, 0.0, -1.0));
+    let (re, im) = f.invariants();
+    let g = rotor() * f;
+    let (re2, im2) = g.invariants();
+    assert_approx_eq!([re, im], [re2, im2], epsilon = 1e-12);
+
+    // F * F = E^2 - B^2 + 2I * E.B
+    let ff = f * f;
+    assert_approx_eq!([ff.s, ff.ps], [re, 2.0 * im], epsilon = 1e-12);
+
+    // Boosting a pure electric field along x by rapidity a
+    let a = 0.5f64;
+    let e = SpacetimeBivector::from_field(DVec3::new(0.0, 1.0, 0.0), DVec3::zero());
+    let g = LorentzRotor::from_boost(a, DVec3::new(1.0, 0.0, 0.0)) * e;
+    assert_approx_eq!(g.e, DVec3::new(0.0, a.cosh(), 0.0), epsilon = 1e-12);
+    assert_approx_eq!(g.b.mag(), a.sinh(), epsilon = 1e-12);
+    assert_approx_eq!([g.b.x, g.b.y], [0.0, 0.0], epsilon = 1e-12);
+    assert_eq!(f.dual().dual(), -f);
+}
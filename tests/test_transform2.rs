@@ -0,0 +1,119 @@
+use cliffy::*;
+use std::f32::consts::FRAC_PI_2;
+
+fn transform() -> Transform2 {
+    Transform2::new(Vec2::new(1.0, -2.0), Rot2::from_angle(0.8), 2.0)
+}
+
+#[test]
+fn test_transform_point_vector() {
+    let t = Transform2::new(Vec2::new(1.0, 0.0), Rot2::from_angle(FRAC_PI_2), 2.0);
+    assert_approx_eq!(
+        t.transform_point(Vec2::right()),
+        Vec2::new(1.0, 2.0),
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(
+        t.transform_vector(Vec2::right()),
+        Vec2::new(0.0, 2.0),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_composition_inverse() {
+    let a = transform();
+    let b = Transform2::new(Vec2::new(-0.5, 3.0), Rot2::from_angle(-1.3), 0.5);
+    let p = Vec2::new(0.5, 1.0);
+    assert_approx_eq!(
+        (a * b).transform_point(p),
+        a.transform_point(b.transform_point(p)),
+        epsilon = 1e-4
+    );
+
+    let inv = a.inverse();
+    assert_approx_eq!(inv.transform_point(a.transform_point(p)), p, epsilon = 1e-4);
+    assert_approx_eq!((a * inv).transform_point(p), p, epsilon = 1e-4);
+    assert!(Transform2::from_scale(0.0).try_inverse().is_none());
+}
+
+#[test]
+fn test_matrix() {
+    let t = transform();
+    let m = t.into_matrix();
+    let p = Vec2::new(0.5, 1.0);
+    let mp = m * Vec3::new(p.x, p.y, 1.0);
+    assert_approx_eq!(mp.into(), t.transform_point(p), epsilon = 1e-4);
+
+    let back = Transform2::from_matrix(&m);
+    assert_approx_eq!(back.translation, t.translation, epsilon = 1e-4);
+    assert_approx_eq!(back.scale, t.scale, epsilon = 1e-5);
+    assert_approx_eq!(
+        AsRotation(back.rotation),
+        AsRotation(t.rotation),
+        epsilon = 1e-4
+    );
+    assert_eq!(Transform2::try_from_matrix(&m), Some(back));
+}
+
+#[test]
+fn test_matrix_reflection() {
+    // Mirroring the x axis can't be a rotation with a uniform scale
+    let mut m = transform().into_matrix();
+    m.cols[0] = -m.cols[0];
+    assert!(Transform2::try_from_matrix(&m).is_none());
+    assert!(Transform2::try_from_matrix(&Mat3::zero()).is_none());
+
+    // Negating both axes is a half turn, not a reflection
+    let m = Transform2::from_scale(-2.0).into_matrix();
+    let t = Transform2::from_matrix(&m);
+    assert_approx_eq!(t.scale, 2.0, epsilon = 1e-5);
+    assert_approx_eq!(
+        t.transform_point(Vec2::new(1.0, 0.5)),
+        Vec2::new(-2.0, -1.0),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+#[should_panic(expected = "cannot represent a reflection")]
+fn test_from_matrix_reflection_panics() {
+    let mut m = transform().into_matrix();
+    m.cols[1] = -m.cols[1];
+    Transform2::from_matrix(&m);
+}
+
+#[test]
+fn test_look_at_interpolate() {
+    let position = Vec2::new(1.0, 1.0);
+    let t = Transform2::look_at(position, Vec2::new(1.0, 4.0));
+    assert_approx_eq!(
+        t.transform_vector(Vec2::right()),
+        Vec2::up(),
+        epsilon = 1e-4
+    );
+
+    let a = Transform2::identity();
+    let b = Transform2::new(Vec2::new(2.0, 0.0), Rot2::from_angle(1.0), 3.0);
+    let half = a.interpolate(b, 0.5);
+    assert_approx_eq!(half.translation, Vec2::new(1.0, 0.0), epsilon = 1e-4);
+    assert_approx_eq!(
+        half.transform_vector(Vec2::right()),
+        Rot2::from_angle(0.5) * Vec2::right() * 2.0,
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_transform_slices() {
+    let t = Transform2::new(Vec2::new(1.0, -2.0), Rot2::from_angle(0.7), 2.5);
+    let vecs = [Vec2::new(1.0, 2.0), Vec2::zero(), Vec2::new(-4.0, 0.5)];
+    let mut points = vecs;
+    let mut vectors = vecs;
+    t.transform_points(&mut points);
+    t.transform_vectors(&mut vectors);
+    for i in 0..vecs.len() {
+        assert_approx_eq!(points[i], t.transform_point(vecs[i]), epsilon = 1e-5);
+        assert_approx_eq!(vectors[i], t.transform_vector(vecs[i]), epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,177 @@
+use cliffy::*;
+
+fn transform() -> Transform3 {
+    Transform3::new(
+        Vec3::new(1.0, -2.0, 3.0),
+        Rot3::from_axis_angle(Vec3::new(1.0, 2.0, -1.0), 0.8),
+        2.0,
+    )
+}
+
+#[test]
+fn test_transform_point_vector() {
+    let t = transform();
+    let p = Vec3::new(0.5, 1.0, -1.5);
+    assert_approx_eq!(
+        t.transform_point(p),
+        t.rotation * p * 2.0 + t.translation,
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(t.transform_vector(p), t.rotation * p * 2.0, epsilon = 1e-4);
+    assert_eq!(Transform3::identity().transform_point(p), p);
+    assert_eq!(Transform3::default(), Transform3::identity());
+}
+
+#[test]
+fn test_composition_inverse() {
+    let a = transform();
+    let b = Transform3::new(
+        Vec3::new(-0.5, 0.0, 2.0),
+        Rot3::from_axis_angle(Vec3::up(), 1.3),
+        0.5,
+    );
+    let p = Vec3::new(0.5, 1.0, -1.5);
+    assert_approx_eq!(
+        (a * b).transform_point(p),
+        a.transform_point(b.transform_point(p)),
+        epsilon = 1e-4
+    );
+
+    let inv = a.inverse();
+    assert_approx_eq!(inv.transform_point(a.transform_point(p)), p, epsilon = 1e-4);
+    assert_approx_eq!(a * inv, Transform3::identity(), epsilon = 1e-4);
+    assert_approx_eq!(inv * a, Transform3::identity(), epsilon = 1e-4);
+    assert!(Transform3::from_scale(0.0).try_inverse().is_none());
+}
+
+#[test]
+fn test_matrix() {
+    let t = transform();
+    let m = t.into_matrix();
+    let p = Vec3::new(0.5, 1.0, -1.5);
+    let mp = m * Vec4::new(p.x, p.y, p.z, 1.0);
+    assert_approx_eq!(mp.into(), t.transform_point(p), epsilon = 1e-4);
+    assert_eq!(Mat4::from(t), m);
+    assert_approx_eq!(Transform3::from_matrix(&m), t, epsilon = 1e-4);
+
+    // A mirror decomposes into a negative scale and still maps points the same
+    let mut mirrored = m;
+    mirrored.cols[0] = -mirrored.cols[0];
+    let back = Transform3::from_matrix(&mirrored);
+    assert!(back.scale < 0.0);
+    let mp = mirrored * Vec4::new(p.x, p.y, p.z, 1.0);
+    assert_approx_eq!(back.transform_point(p), mp.into(), epsilon = 1e-4);
+
+    let b = Transform3::from_translation(Vec3::new(3.0, 0.0, 1.0));
+    assert_approx_eq!((t * b).into_matrix(), m * b.into_matrix(), epsilon = 1e-4);
+}
+
+#[test]
+fn test_matrix_singular() {
+    let m = transform().into_matrix();
+    assert_eq!(
+        Transform3::try_from_matrix(&m),
+        Some(Transform3::from_matrix(&m))
+    );
+    assert!(Transform3::try_from_matrix(&Mat4::zero()).is_none());
+
+    // Flattened onto a plane, with the translation kept
+    let mut flat = m;
+    flat.cols[2] = Vec4::zero();
+    assert!(Transform3::try_from_matrix(&flat).is_none());
+}
+
+#[test]
+#[should_panic(expected = "cannot represent a singular matrix")]
+fn test_from_matrix_singular_panics() {
+    Transform3::from_matrix(&Transform3::from_scale(0.0).into_matrix());
+}
+
+#[test]
+fn test_look_at() {
+    let eye = Vec3::new(1.0, 2.0, 3.0);
+    let target = Vec3::new(4.0, 2.0, -1.0);
+    let t = Transform3::look_at(eye, target, Vec3::up());
+    assert_eq!(t.translation, eye);
+    assert_approx_eq!(
+        t.transform_vector(Vec3::forward()),
+        (target - eye).normalized(),
+        epsilon = 1e-4
+    );
+    assert_approx_eq!(t.transform_vector(Vec3::up()), Vec3::up(), epsilon = 1e-4);
+
+    let t = Transform3::look_to(eye, Vec3::new(0.0, 1.0, 1.0), Vec3::up());
+    let up = t.transform_vector(Vec3::up());
+    assert_approx_eq!(up, Vec3::new(0.0, 1.0, -1.0).normalized(), epsilon = 1e-4);
+}
+
+#[test]
+fn test_look_at_parallel_up() {
+    let eye = Vec3::new(1.0, 2.0, 3.0);
+    for &direction in &[
+        Vec3::down(),
+        Vec3::up() * 3.0,
+        Vec3::forward(),
+        Vec3::back(),
+    ] {
+        let up = if direction.z == 0.0 {
+            Vec3::up()
+        } else {
+            Vec3::forward()
+        };
+        let t = Transform3::look_to(eye, direction, up);
+        assert!(t.rotation.mag().is_finite(), "{:?}", t);
+        assert_approx_eq!(
+            t.transform_vector(Vec3::forward()),
+            direction.normalized(),
+            epsilon = 1e-4
+        );
+        assert_approx_eq!(t.rotation.mag(), 1.0, epsilon = 1e-5);
+    }
+}
+
+#[test]
+fn test_interpolate() {
+    let a = Transform3::identity();
+    let b = transform();
+    assert_approx_eq!(a.interpolate(b, 0.0), a, epsilon = 1e-4);
+    assert_approx_eq!(a.interpolate(b, 1.0), b, epsilon = 1e-4);
+
+    let half = a.interpolate(b, 0.5);
+    assert_approx_eq!(half.translation, b.translation * 0.5, epsilon = 1e-4);
+    assert_approx_eq!(half.scale, 1.5, epsilon = 1e-6);
+    assert_approx_eq!(
+        Transform3::from_rotation(half.rotation * half.rotation),
+        Transform3::from_rotation(b.rotation),
+        epsilon = 1e-4
+    );
+}
+
+#[test]
+fn test_double() {
+    let t = transform();
+    let d = t.as_dtransform3();
+    assert_eq!(d.as_transform3(), t);
+}
+
+#[test]
+fn test_transform_slices() {
+    let t = Transform3::new(
+        Vec3::new(1.0, -2.0, 3.0),
+        Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7),
+        2.5,
+    );
+    let vecs = [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::zero(),
+        Vec3::new(-4.0, 0.5, 2.0),
+    ];
+    let mut points = vecs;
+    let mut vectors = vecs;
+    t.transform_points(&mut points);
+    t.transform_vectors(&mut vectors);
+    for i in 0..vecs.len() {
+        assert_approx_eq!(points[i], t.transform_point(vecs[i]), epsilon = 1e-5);
+        assert_approx_eq!(vectors[i], t.transform_vector(vecs[i]), epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,69 @@
+use cliffy::*;
+
+#[test]
+fn test_constructions() {
+    assert_eq!(Trivec3::new(2.0), Trivec3 { xyz: 2.0 });
+    assert_eq!(Trivec3::zero(), Trivec3 { xyz: 0.0 });
+    assert_eq!(Trivec3::one(), Trivec3 { xyz: 1.0 });
+}
+
+#[test]
+fn test_arithmetic() {
+    let t1 = Trivec3::new(2.0);
+    let t2 = Trivec3::new(-3.0);
+
+    assert_eq!(t1 + t2, Trivec3::new(-1.0));
+    assert_eq!(t1 - t2, Trivec3::new(5.0));
+    assert_eq!(-t1, Trivec3::new(-2.0));
+    assert_eq!(t1 * 2.0, Trivec3::new(4.0));
+    assert_eq!(2.0 * t1, Trivec3::new(4.0));
+    assert_eq!(t1 / 2.0, Trivec3::new(1.0));
+    assert_eq!(t2.mag(), 3.0);
+    assert_eq!(t2.mag_sq(), 9.0);
+}
+
+#[test]
+fn test_pseudoscalar_squares_to_minus_one() {
+    assert_eq!(Trivec3::one() * Trivec3::one(), -1.0);
+}
+
+#[test]
+fn test_products_with_vectors() {
+    let i = Trivec3::one();
+
+    assert_eq!(i * Vec3::right(), Bivec3::new(0.0, 0.0, 1.0));
+    assert_eq!(Vec3::right() * i, Bivec3::new(0.0, 0.0, 1.0));
+    assert_eq!(i * Vec3::forward(), Bivec3::new(1.0, 0.0, 0.0));
+    assert_eq!(i * Bivec3::new(1.0, 0.0, 0.0), -Vec3::forward());
+    assert_eq!(Bivec3::new(1.0, 0.0, 0.0) * i, -Vec3::forward());
+
+    // Multiplying by the pseudoscalar twice negates
+    let v = Vec3::new(1.0, -2.0, 3.0);
+    assert_eq!(i * (i * v), -v);
+}
+
+#[test]
+fn test_wedge() {
+    let a = Vec3::new(3.0, 4.0, 5.0);
+    let b = Vec3::new(2.0, 1.0, 6.0);
+    let c = Vec3::new(-1.0, 2.0, 0.5);
+
+    // Oriented volume of the parallelepiped spanned by a, b and c
+    let volume = a.wedge(b).wedge_vec(c);
+    assert_eq!(volume, Trivec3::new(a.cross(b).dot(c)));
+    assert_eq!(c.wedge_bivec(a.wedge(b)), volume);
+    assert_eq!(b.wedge(a).wedge_vec(c), -volume);
+
+    assert_eq!(
+        Vec3::right().wedge(Vec3::up()).wedge_vec(Vec3::forward()),
+        Trivec3::one()
+    );
+    assert_eq!(a.wedge(b).wedge_vec(a), Trivec3::zero());
+}
+
+#[test]
+fn test_rotation() {
+    let r = Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 1.2);
+
+    assert_eq!((r * Trivec3::new(2.0)).xyz.round(), 2.0);
+}
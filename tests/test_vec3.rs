@@ -125,7 +125,7 @@ fn test_div() {
     let mut v = Vec3::new(-1.0, 1.0, 5.0);
     let f = 5.0;
 
-    assert_eq!(v / f, Vec3::new(-0.2, 0.2, 1.0));
+    assert_approx_eq!(v / f, Vec3::new(-0.2, 0.2, 1.0));
     assert_eq!(f / v, Vec3::new(-5.0, 5.0, 1.0));
 
     v /= 1.0;
@@ -371,7 +371,7 @@ fn test_normalize() {
     let mag = 13.0;
     v.normalize();
 
-    assert_eq!(v, Vec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
+    assert_approx_eq!(v, Vec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
 }
 
 #[test]
@@ -379,7 +379,7 @@ fn test_normalized() {
     let v = Vec3::new(3.0, 4.0, 12.0);
     let mag = 13.0;
 
-    assert_eq!(v.normalized(), Vec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
+    assert_approx_eq!(v.normalized(), Vec3::new(3.0 / mag, 4.0 / mag, 12.0 / mag));
 }
 
 #[test]
@@ -463,29 +463,29 @@ fn test_distance() {
     let v1 = Vec3::new(5.0, 3.0, 1.0);
     let v2 = Vec3::new(-1.0, 7.0, 8.0);
 
-    assert_eq!(v1.distance(v2), Vec3::new(-6.0, 4.0, 7.0).mag());
+    assert_approx_eq!(v1.distance(v2), Vec3::new(-6.0, 4.0, 7.0).mag());
 }
 
 #[test]
 fn test_angle_between() {
     let mut v1 = Vec3::new(10.0, 10.0, 0.0);
     let mut v2 = Vec3::new(-5.0, 5.0, 0.0);
-    assert_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
+    assert_approx_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
 
     v1 = Vec3::new(-10.0, 0.0, 10.0);
     v2 = Vec3::new(-5.0, 0.0, -5.0);
-    assert_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
+    assert_approx_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
 
     v1 = Vec3::new(0.0, -10.0, 10.0);
     v2 = Vec3::new(0.0, -5.0, -5.0);
-    assert_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
+    assert_approx_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
 }
 
 #[test]
 fn test_angle_between_normal() {
     let mut v1 = Vec3::new(1.0, 0.0, 0.0);
     let mut v2 = Vec3::new(0.0, 1.0, 0.0);
-    assert_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
+    assert_approx_eq!(v1.angle_between(v2), std::f32::consts::PI / 2.0);
 
     v1 = Vec3::new(-1.0, 0.0, 0.0);
     v2 = Vec3::new(0.0, 0.0, -1.0);
@@ -526,3 +526,24 @@ fn test_nlerp() {
     assert_eq!(v1.nlerp(v2, 0.5), Vec3::up());
     assert_eq!(v1.nlerp(v2, 1.0), v2.normalized());
 }
+
+#[test]
+fn test_dual() {
+    assert_eq!(Vec3::right().dual(), Bivec3::new(0.0, 0.0, 1.0));
+    assert_eq!(Vec3::up().dual(), Bivec3::new(0.0, -1.0, 0.0));
+    assert_eq!(Vec3::forward().dual(), Bivec3::new(1.0, 0.0, 0.0));
+
+    let v1 = Vec3::new(3.0, 4.0, 5.0);
+    let v2 = Vec3::new(2.0, 1.0, 6.0);
+    assert_eq!(v1.dual().dual(), v1);
+    assert_eq!(v1.cross(v2).dual(), v1.wedge(v2));
+    assert_eq!(v1.wedge(v2).dual(), v1.cross(v2));
+}
+
+#[test]
+fn test_normalize_all() {
+    let mut vecs = [Vec3::new(3.0, 4.0, 12.0), Vec3::new(0.0, -2.0, 0.0)];
+    Vec3::normalize_all(&mut vecs);
+    assert_approx_eq!(vecs[0], Vec3::new(3.0, 4.0, 12.0).normalized());
+    assert_eq!(vecs[1], Vec3::down());
+}
@@ -312,3 +312,47 @@ fn test_lerp() {
     assert_eq!(v1.lerp(v2, -0.5), Vec4::new(-0.5, -0.5, -0.5, -0.5));
     assert_eq!(v1.lerp(v2, 1.5), Vec4::new(1.5, 1.5, 1.5, 1.5));
 }
+
+#[test]
+fn test_wedge_geom() {
+    let v1 = Vec4::new(1.0, 2.0, 3.0, 4.0);
+    let v2 = Vec4::new(2.0, 1.0, 0.0, -1.0);
+
+    assert_eq!(
+        v1.wedge(v2),
+        Bivec4::new(-3.0, -6.0, -9.0, -3.0, -6.0, -3.0)
+    );
+    assert_eq!(v2.wedge(v1), -v1.wedge(v2));
+    assert_eq!(v1.wedge(v1), Bivec4::zero());
+    assert_eq!(v1.geom(v2), Rot4::new(0.0, v1.wedge(v2), 0.0));
+
+    // The wedge of two vectors is always a simple plane
+    assert!(v1.wedge(v2).is_simple());
+}
+
+#[test]
+fn test_angle_between_slerp() {
+    let x = Vec4::new(1.0, 0.0, 0.0, 0.0);
+    let w = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    assert_eq!(x.angle_between(w), std::f32::consts::FRAC_PI_2);
+    assert_eq!(x.angle_between(w * 3.0), std::f32::consts::FRAC_PI_2);
+
+    let half = x.slerp(w, 0.5);
+    let expected = Vec4::new(1.0, 0.0, 0.0, 1.0).normalized();
+    assert_approx_eq!(half, expected, epsilon = 1e-6);
+    assert_approx_eq!(x.slerp(w, 1.0), w, epsilon = 1e-6);
+}
+
+fn generic_dist<V: Vector<Decimal = f32> + Copy>(a: V, b: V) -> f32 {
+    a.distance(b)
+}
+
+#[test]
+fn test_generic_vector() {
+    assert_eq!(
+        generic_dist(Vec4::new(1.0, 1.0, 1.0, 1.0), Vec4::new(2.0, 2.0, 2.0, 2.0)),
+        2.0
+    );
+    assert_eq!(generic_dist(Vec3::zero(), Vec3::new(0.0, 3.0, 4.0)), 5.0);
+}
@@ -0,0 +1,80 @@
+use cliffy::*;
+
+fn rotors() -> [Rot3; 4] {
+    [
+        Rot3::identity(),
+        Rot3::from_axis_angle(Vec3::new(1.0, 2.0, 3.0), 0.7),
+        Rot3::from_axis_angle(Vec3::up(), -2.5),
+        Rot3::from_angle_plane(1.2, Bivec3::new(0.3, -0.4, 0.5)),
+    ]
+}
+
+#[test]
+fn test_lanes() {
+    let r = rotors();
+    let w = Rot3x4::from(r);
+    assert_eq!(w.lane(1), r[1]);
+    assert_eq!(<[Rot3; 4]>::from(w), r);
+
+    let w = Rot3x8::gather(&r);
+    assert_eq!(w.lane(3), r[3]);
+    assert_eq!(w.lane(4), Rot3::identity());
+    let mut out = [Rot3::default(); 3];
+    w.scatter(&mut out);
+    assert_eq!(out, [r[0], r[1], r[2]]);
+}
+
+#[test]
+fn test_rotate_vec() {
+    let r = rotors();
+    let v = [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-0.5, 0.25, 4.0),
+        Vec3::new(0.0, -1.0, 0.0),
+        Vec3::new(3.5, -2.5, -0.75),
+    ];
+    let rotated = Rot3x4::from(r) * Vec3x4::from(v);
+    for i in 0..4 {
+        assert_eq!(rotated.lane(i), r[i] * v[i]);
+    }
+
+    let mut w = Vec3x8::splat(Vec3::right());
+    Rot3x8::splat(r[1]).rotate_vec(&mut w);
+    assert_eq!(w.lane(5), r[1] * Vec3::right());
+}
+
+#[test]
+fn test_rotor() {
+    let a = rotors();
+    let b = [a[3], a[2], a[1], a[0]];
+    let (wa, wb) = (Rot3x4::from(a), Rot3x4::from(b));
+    let t = [0.0, 0.25, 0.5, 1.0];
+    for i in 0..4 {
+        assert_eq!((wa * wb).lane(i), a[i] * b[i]);
+        assert_eq!(wa.rotated_by(wb).lane(i), a[i].rotated_by(b[i]));
+        assert_eq!(wa.reversed().lane(i), a[i].reversed());
+        assert_eq!(wa.dot(wb)[i], a[i].dot(b[i]));
+        assert_eq!(wa.into_matrix()[i], a[i].into_matrix());
+        assert_eq!(wa.slerp(wb, t).lane(i), a[i].slerp(b[i], t[i]));
+        assert_eq!(wa.log().exp().lane(i), a[i].log().exp());
+        assert_eq!((wa + wb - wb * 0.5).lane(i), a[i] + b[i] - b[i] * 0.5);
+    }
+}
+
+#[test]
+fn test_bivectors() {
+    let bv = [
+        Bivec3::new(1.0, 0.0, 0.0),
+        Bivec3::new(0.3, -0.4, 0.5),
+        Bivec3::zero(),
+        Bivec3::new(0.0, 2.0, -1.0),
+    ];
+    let w = Bivec3x4::from(bv);
+    let r = Rot3x4::from(rotors());
+    for (i, bv) in bv.iter().enumerate() {
+        assert_eq!(w.mag()[i], bv.mag());
+        assert_eq!(w.exp().lane(i), bv.exp());
+        assert_eq!((r * w).lane(i), r.lane(i) * *bv);
+    }
+    assert_approx_eq!((w * 2.0).exp(), Rot3x4::from(bv.map(|bv| (bv * 2.0).exp())));
+}
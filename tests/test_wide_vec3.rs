@@ -0,0 +1,83 @@
+use cliffy::*;
+
+fn vecs() -> [Vec3; 4] {
+    [
+        Vec3::new(1.0, 2.0, 3.0),
+        Vec3::new(-0.5, 0.25, 4.0),
+        Vec3::new(0.0, -1.0, 0.0),
+        Vec3::new(3.5, -2.5, -0.75),
+    ]
+}
+
+#[test]
+fn test_lanes() {
+    let w = Vec3x4::from(vecs());
+    assert_eq!(w.x, [1.0, -0.5, 0.0, 3.5]);
+    assert_eq!(w.lane(1), Vec3::new(-0.5, 0.25, 4.0));
+    assert_eq!(<[Vec3; 4]>::from(w), vecs());
+
+    let mut w = Vec3x8::splat(Vec3::up());
+    w.set_lane(7, Vec3::right());
+    assert_eq!(w.lane(0), Vec3::up());
+    assert_eq!(w.lane(7), Vec3::right());
+}
+
+#[test]
+fn test_gather_scatter() {
+    let v = vecs();
+    let w = Vec3x8::gather(&v[1..]);
+    assert_eq!(w.lane(0), v[1]);
+    assert_eq!(w.lane(2), v[3]);
+    assert_eq!(w.lane(3), Vec3::zero());
+
+    let mut out = [Vec3::one(); 2];
+    w.scatter(&mut out);
+    assert_eq!(out, [v[1], v[2]]);
+
+    let mut out = [Vec3::one(); 6];
+    Vec3x4::gather(&v).scatter(&mut out);
+    assert_eq!(out[..4], v);
+    assert_eq!(out[4], Vec3::one());
+}
+
+#[test]
+fn test_ops() {
+    let (a, b) = (
+        vecs(),
+        [Vec3::up(), Vec3::left(), Vec3::one(), Vec3::back()],
+    );
+    let (wa, wb) = (Vec3x4::from(a), Vec3x4::from(b));
+    let t = [0.0, 0.25, 0.5, 1.0];
+    let sum = wa + wb;
+    let diff = wa - wb;
+    let scaled = -wa * 2.0 / 4.0;
+    let per_lane = wa * t;
+    for i in 0..4 {
+        assert_eq!(sum.lane(i), a[i] + b[i]);
+        assert_eq!(diff.lane(i), a[i] - b[i]);
+        assert_eq!(scaled.lane(i), -a[i] * 2.0 / 4.0);
+        assert_eq!(per_lane.lane(i), a[i] * t[i]);
+    }
+}
+
+#[test]
+fn test_vector() {
+    let (a, b) = (
+        vecs(),
+        [Vec3::up(), Vec3::left(), Vec3::one(), Vec3::back()],
+    );
+    let (wa, wb) = (Vec3x4::from(a), Vec3x4::from(b));
+    let t = [0.1; 4];
+    for i in 0..4 {
+        assert_eq!(wa.mag()[i], a[i].mag());
+        assert_eq!(wa.dot(wb)[i], a[i].dot(b[i]));
+        assert_eq!(wa.cross(wb).lane(i), a[i].cross(b[i]));
+        assert_eq!(wa.wedge(wb).lane(i), a[i].wedge(b[i]));
+        assert_eq!(wa.geom(wb).lane(i), a[i].geom(b[i]));
+        assert_eq!(wa.normalized().lane(i), a[i].normalized());
+        assert_eq!(wa.reflected(wb).lane(i), a[i].reflected(b[i]));
+        assert_eq!(wa.nlerp(wb, t).lane(i), a[i].nlerp(b[i], 0.1));
+        assert_eq!(wa.dual().lane(i), a[i].dual());
+        assert_eq!(wa.dual().dual().lane(i), a[i]);
+    }
+}